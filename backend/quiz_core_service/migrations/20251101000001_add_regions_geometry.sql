@@ -0,0 +1,34 @@
+-- ============================================
+-- MIGRATION : Régions géographiques (carte cliquable)
+-- ============================================
+
+-- ============================================
+-- TABLE: regions
+-- ============================================
+CREATE TABLE regions (
+                         id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+
+    -- Identification
+                         code VARCHAR(50) UNIQUE NOT NULL,
+                         nom VARCHAR(255) NOT NULL,
+
+    -- Géométrie GeoJSON (Polygon ou MultiPolygon, coordonnées [longitude, latitude])
+                         geometry JSONB NOT NULL,
+
+    -- Métadonnées
+                         metadata JSONB NOT NULL DEFAULT '{}'::jsonb,
+
+                         created_at TIMESTAMPTZ DEFAULT NOW()
+);
+
+-- Index regions
+CREATE INDEX idx_regions_code ON regions(code);
+
+-- ============================================
+-- COMMENTAIRES
+-- ============================================
+COMMENT ON TABLE regions IS 'Régions géographiques cliquables (départements, régions, pays, massifs, etc.)';
+COMMENT ON COLUMN regions.geometry IS 'Géométrie GeoJSON (Polygon, MultiPolygon, ou Feature) en WGS84 [lng, lat]';
+COMMENT ON COLUMN reponses.coordinates_point IS 'Point cible (x = longitude, y = latitude)';
+COMMENT ON COLUMN reponses.region_id IS 'Région cible (regions.id) pour les questions carte_cliquable';
+COMMENT ON COLUMN reponses_utilisateur.coordinates_cliquees IS 'Point cliqué par le joueur (x = longitude, y = latitude)';
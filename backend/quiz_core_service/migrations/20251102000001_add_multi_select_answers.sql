@@ -0,0 +1,15 @@
+-- ============================================
+-- MIGRATION : QCM à choix multiples
+-- ============================================
+
+-- Toutes les réponses sélectionnées (QCM multiple) ou proposées dans l'ordre (ordre)
+ALTER TABLE reponses_utilisateur
+    ADD COLUMN reponse_ids UUID[] NOT NULL DEFAULT '{}';
+
+-- Reprise des réponses existantes (choix unique)
+UPDATE reponses_utilisateur
+SET reponse_ids = ARRAY[reponse_id]
+WHERE reponse_id IS NOT NULL;
+
+COMMENT ON COLUMN reponses_utilisateur.reponse_ids IS 'Réponses sélectionnées (QCM multiple) ou ordre proposé (ordre)';
+COMMENT ON COLUMN reponses_utilisateur.reponse_id IS 'Réponse sélectionnée (choix unique, conservé pour compatibilité)';
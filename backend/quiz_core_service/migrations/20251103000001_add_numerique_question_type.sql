@@ -0,0 +1,19 @@
+-- ============================================
+-- MIGRATION : Type de question "numerique"
+-- ============================================
+
+ALTER TABLE questions DROP CONSTRAINT IF EXISTS questions_type_question_check;
+
+ALTER TABLE questions ADD CONSTRAINT questions_type_question_check CHECK (
+    type_question IN (
+                      'qcm',
+                      'vrai_faux',
+                      'saisie_texte',
+                      'carte_cliquable',
+                      'ordre',
+                      'association',
+                      'numerique'
+        )
+    );
+
+COMMENT ON COLUMN questions.question_data IS 'Contenu de la question (text, ...) ; numerique : expected_value, unit, accepted_units, absolute_tolerance, relative_tolerance, partial_range';
@@ -0,0 +1,12 @@
+-- ============================================
+-- MIGRATION : Domaine "code_route" (Code de la Route)
+-- ============================================
+
+INSERT INTO domains (name, display_name, description, config) VALUES
+    ('code_route', 'Code de la Route', 'Préparation à l''examen du code de la route', '{"icon": "🚗", "color": "#FF5722"}'::jsonb)
+    ON CONFLICT (name) DO NOTHING;
+
+-- Convention media_url des panneaux : sign://<categorie>/<code>
+-- (ex: sign://danger/A1a, sign://interdiction/B14, sign://obligation/B21-1)
+-- Le client résout le code vers son propre jeu d'images (référentiel officiel des panneaux)
+COMMENT ON COLUMN questions.media_url IS 'URL du média (image, son) ; code_route : sign://<categorie>/<code> pour les panneaux';
@@ -0,0 +1,30 @@
+-- ============================================
+-- MIGRATION : Mode adaptatif (classement Elo)
+-- ============================================
+
+-- Difficulté de chaque question (échelle Elo, 1500 = moyenne)
+-- Valeur initiale selon le niveau du quiz, puis ajustée à chaque réponse
+ALTER TABLE questions ADD COLUMN difficulty_rating DOUBLE PRECISION NOT NULL DEFAULT 1500;
+
+UPDATE questions q
+SET difficulty_rating = CASE quiz.niveau_difficulte
+    WHEN 'facile' THEN 1300
+    WHEN 'difficile' THEN 1700
+    ELSE 1500
+END
+FROM quizzes quiz
+WHERE quiz.id = q.quiz_id;
+
+-- Niveau de chaque joueur par domaine (même échelle)
+CREATE TABLE user_skill_ratings (
+    user_id UUID NOT NULL,
+    domain VARCHAR(50) NOT NULL REFERENCES domains(name) ON DELETE CASCADE,
+    rating DOUBLE PRECISION NOT NULL DEFAULT 1500,
+    nb_answers INTEGER NOT NULL DEFAULT 0,
+    updated_at TIMESTAMPTZ DEFAULT NOW(),
+
+    PRIMARY KEY (user_id, domain)
+);
+
+COMMENT ON COLUMN questions.difficulty_rating IS 'Difficulté Elo de la question, mise à jour à chaque réponse';
+COMMENT ON TABLE user_skill_ratings IS 'Niveau Elo des joueurs par domaine (mode entrainement adaptatif)';
@@ -0,0 +1,28 @@
+-- ============================================
+-- MIGRATION : Révisions espacées (SM-2)
+-- ============================================
+
+-- Une carte par joueur et par question jouée en entraînement
+CREATE TABLE review_cards (
+    user_id UUID NOT NULL,
+    question_id UUID NOT NULL REFERENCES questions(id) ON DELETE CASCADE,
+
+    -- État SM-2
+    ease_factor DOUBLE PRECISION NOT NULL DEFAULT 2.5,
+    interval_days INTEGER NOT NULL DEFAULT 0,
+    repetitions INTEGER NOT NULL DEFAULT 0,
+    lapses INTEGER NOT NULL DEFAULT 0,
+
+    due_at TIMESTAMPTZ NOT NULL,
+    last_reviewed_at TIMESTAMPTZ NOT NULL,
+
+    PRIMARY KEY (user_id, question_id)
+);
+
+CREATE INDEX idx_review_cards_due ON review_cards(user_id, due_at);
+
+-- Quiz technique de révision : un par domaine, masqué des listes (is_active = false)
+-- Les sessions de révision y sont rattachées, leurs questions viennent de tous les quiz du domaine
+CREATE UNIQUE INDEX idx_quizzes_review_domain ON quizzes(domain) WHERE metadata->>'kind' = 'review';
+
+COMMENT ON TABLE review_cards IS 'Planification des révisions (SM-2) par joueur et question';
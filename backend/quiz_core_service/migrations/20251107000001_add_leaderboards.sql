@@ -0,0 +1,67 @@
+-- ============================================
+-- MIGRATION : Classements matérialisés
+-- ============================================
+
+-- Une ligne par joueur, classement et fenêtre de temps, mise à jour à chaque session terminée :
+-- - scope 'quiz'   (scope_key = id du quiz) : meilleure session de la fenêtre
+-- - scope 'domain' (scope_key = domaine)    : cumul des sessions de la fenêtre
+-- period_start : 1970-01-01 pour all_time, lundi (UTC) pour weekly, jour (UTC) pour daily
+CREATE TABLE leaderboard_entries (
+    scope TEXT NOT NULL CHECK (scope IN ('quiz', 'domain')),
+    scope_key TEXT NOT NULL,
+    period TEXT NOT NULL CHECK (period IN ('all_time', 'weekly', 'daily')),
+    period_start DATE NOT NULL,
+    user_id UUID NOT NULL,
+
+    score INTEGER NOT NULL,
+    temps_total_sec INTEGER NOT NULL,
+    sessions_count INTEGER NOT NULL DEFAULT 1,
+    session_id UUID,
+    achieved_at TIMESTAMPTZ NOT NULL,
+
+    PRIMARY KEY (scope, scope_key, period, period_start, user_id)
+);
+
+-- Ordre du classement : score décroissant, puis temps croissant
+CREATE INDEX idx_leaderboard_ranking
+    ON leaderboard_entries(scope, scope_key, period, period_start, score DESC, temps_total_sec ASC);
+
+-- Reprise de l'historique : sessions terminées, hors révisions
+CREATE TEMP TABLE leaderboard_backfill ON COMMIT DROP AS
+SELECT s.id AS session_id, s.user_id, s.quiz_id, q.domain, s.score,
+       COALESCE(s.temps_total_sec, 0) AS temps_total_sec,
+       COALESCE(s.date_fin, s.date_debut) AS achieved_at,
+       p.period, p.period_start
+FROM sessions_quiz s
+JOIN quizzes q ON q.id = s.quiz_id
+CROSS JOIN LATERAL (VALUES
+    ('all_time', DATE '1970-01-01'),
+    ('weekly', date_trunc('week', COALESCE(s.date_fin, s.date_debut) AT TIME ZONE 'UTC')::DATE),
+    ('daily', (COALESCE(s.date_fin, s.date_debut) AT TIME ZONE 'UTC')::DATE)
+) AS p(period, period_start)
+WHERE s.status = 'termine'
+  AND COALESCE(s.metadata->>'kind', '') <> 'review';
+
+INSERT INTO leaderboard_entries (
+    scope, scope_key, period, period_start, user_id,
+    score, temps_total_sec, sessions_count, session_id, achieved_at
+)
+SELECT DISTINCT ON (quiz_id, period, period_start, user_id)
+    'quiz', quiz_id::TEXT, period, period_start, user_id,
+    score, temps_total_sec,
+    COUNT(*) OVER (PARTITION BY quiz_id, period, period_start, user_id),
+    session_id, achieved_at
+FROM leaderboard_backfill
+ORDER BY quiz_id, period, period_start, user_id, score DESC, temps_total_sec ASC;
+
+INSERT INTO leaderboard_entries (
+    scope, scope_key, period, period_start, user_id,
+    score, temps_total_sec, sessions_count, session_id, achieved_at
+)
+SELECT 'domain', domain, period, period_start, user_id,
+       SUM(score), SUM(temps_total_sec), COUNT(*),
+       (ARRAY_AGG(session_id ORDER BY achieved_at DESC))[1], MAX(achieved_at)
+FROM leaderboard_backfill
+GROUP BY domain, period, period_start, user_id;
+
+COMMENT ON TABLE leaderboard_entries IS 'Classements par quiz et par domaine (all_time, weekly, daily), mis à jour à la finalisation';
@@ -0,0 +1,41 @@
+-- ============================================
+-- MIGRATION : Succès (achievements)
+-- ============================================
+
+-- Catalogue : succès généraux (domain NULL) et succès déclarés par les plugins de domaine
+CREATE TABLE achievements (
+    code VARCHAR(100) PRIMARY KEY,
+    domain VARCHAR(50), -- NULL : tous domaines confondus
+    titre VARCHAR(200) NOT NULL,
+    description TEXT,
+    icon VARCHAR(20),
+    rule JSONB NOT NULL, -- ex: {"type": "perfect_sessions", "count": 10}
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Succès débloqués, une seule fois par joueur
+CREATE TABLE user_achievements (
+    user_id UUID NOT NULL,
+    achievement_code VARCHAR(100) NOT NULL REFERENCES achievements(code) ON DELETE CASCADE,
+    session_id UUID REFERENCES sessions_quiz(id) ON DELETE SET NULL, -- Session du déblocage
+    unlocked_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (user_id, achievement_code)
+);
+
+CREATE INDEX idx_user_achievements_session ON user_achievements(session_id);
+
+INSERT INTO achievements (code, titre, description, icon, rule) VALUES
+    ('first_session', 'Premiers pas', 'Terminer une première session', '🎯',
+     '{"type": "sessions_completed", "count": 1}'),
+    ('perfectionist', 'Perfectionniste', 'Terminer 10 sessions avec 100 %', '💯',
+     '{"type": "perfect_sessions", "count": 10}'),
+    ('on_fire', 'En feu', '5 bonnes réponses d''affilée', '🔥',
+     '{"type": "answer_streak", "count": 5}'),
+    ('lightning', 'Éclair', 'Répondre juste en moins de 30 % du temps, 20 fois', '⚡',
+     '{"type": "fast_answers", "count": 20}');
+
+COMMENT ON TABLE achievements IS 'Catalogue des succès (règles évaluées à chaque réponse et à la finalisation)';
+COMMENT ON TABLE user_achievements IS 'Succès débloqués par joueur';
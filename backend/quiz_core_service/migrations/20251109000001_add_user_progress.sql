@@ -0,0 +1,35 @@
+-- ============================================
+-- MIGRATION : Progression des joueurs (XP, niveaux, maîtrise, série)
+-- ============================================
+
+-- Agrégats dérivés de sessions_quiz / reponses_utilisateur, mis à jour à chaque session terminée
+-- et recalculables à tout moment (POST /api/v1/admin/progress/rebuild)
+CREATE TABLE user_progress (
+    user_id UUID PRIMARY KEY,
+    xp BIGINT NOT NULL DEFAULT 0,
+    sessions_count INTEGER NOT NULL DEFAULT 0,
+
+    -- Série de jours joués, comptés dans le fuseau du joueur
+    timezone VARCHAR(64) NOT NULL DEFAULT 'UTC',
+    current_streak INTEGER NOT NULL DEFAULT 0,
+    best_streak INTEGER NOT NULL DEFAULT 0,
+    grace_tokens INTEGER NOT NULL DEFAULT 0,
+    last_played_on DATE,
+
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Maîtrise par domaine
+CREATE TABLE user_domain_progress (
+    user_id UUID NOT NULL,
+    domain VARCHAR(50) NOT NULL,
+    xp BIGINT NOT NULL DEFAULT 0,
+    answers_count INTEGER NOT NULL DEFAULT 0,
+    correct_count INTEGER NOT NULL DEFAULT 0,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    PRIMARY KEY (user_id, domain)
+);
+
+COMMENT ON TABLE user_progress IS 'XP, niveau et série quotidienne par joueur (dérivés des sessions terminées)';
+COMMENT ON TABLE user_domain_progress IS 'XP et précision par joueur et domaine (maîtrise)';
@@ -0,0 +1,20 @@
+-- ============================================
+-- MIGRATION : Défi du jour
+-- ============================================
+
+-- Nouveau mode de quiz
+ALTER TABLE quizzes DROP CONSTRAINT IF EXISTS quizzes_mode_check;
+ALTER TABLE quizzes ADD CONSTRAINT quizzes_mode_check CHECK (
+    mode IN ('decouverte', 'entrainement', 'examen', 'competition', 'defi_du_jour')
+);
+
+-- Un défi par domaine et par jour : quiz synthétique masqué des listes (is_active = false),
+-- questions tirées dans les quiz du domaine (metadata.question_set)
+CREATE UNIQUE INDEX idx_quizzes_daily_challenge
+    ON quizzes(domain, (metadata->>'date'))
+    WHERE metadata->>'kind' = 'daily_challenge';
+
+-- Une seule tentative par joueur et par défi
+CREATE UNIQUE INDEX idx_sessions_daily_challenge_attempt
+    ON sessions_quiz(user_id, quiz_id)
+    WHERE metadata->>'kind' = 'daily_challenge';
@@ -0,0 +1,19 @@
+-- ============================================
+-- MIGRATION : Version persistée du registry de plugins
+-- ============================================
+
+-- Numéro de version monotone, jamais réutilisé après un redémarrage :
+-- une session (metadata.plugin_version) ne peut pas désigner un autre registry que le sien
+CREATE SEQUENCE plugin_registry_version_seq;
+
+-- Reprendre après les versions déjà enregistrées par les sessions existantes
+SELECT setval(
+    'plugin_registry_version_seq',
+    COALESCE(
+        (SELECT MAX((metadata->>'plugin_version')::BIGINT)
+         FROM sessions_quiz
+         WHERE metadata ? 'plugin_version'),
+        0
+    ) + 1,
+    false
+);
@@ -0,0 +1,222 @@
+-- ============================================
+-- SEED DATA : Code de la Route V0
+-- ============================================
+-- Chaque question porte une explication (obligatoire pour ce domaine)
+-- Panneaux : media_url = sign://<categorie>/<code>
+
+-- 🚗 Quiz Code de la Route - Entraînement
+INSERT INTO quizzes (
+    id,
+    domain,
+    titre,
+    description,
+    niveau_difficulte,
+    version_app,
+    scope,
+    mode,
+    nb_questions,
+    is_active
+) VALUES (
+             '00000000-0000-0000-0000-000000000002'::uuid,
+             'code_route',
+             'Code de la Route - Panneaux et priorités',
+             'Entraînement sur les panneaux de signalisation, les priorités et les limitations de vitesse',
+             'facile',
+             '1.0.0',
+             'france',
+             'entrainement',
+             6,
+             true
+         ) ON CONFLICT (id) DO NOTHING;
+
+-- Question 1 : qcm
+INSERT INTO questions (
+    id,
+    quiz_id,
+    ordre,
+    type_question,
+    question_data,
+    category,
+    subcategory,
+    media_url,
+    points,
+    temps_limite_sec,
+    explanation
+) VALUES (
+             '00000000-0000-0000-0002-000000000001'::uuid,
+             '00000000-0000-0000-0000-000000000002'::uuid,
+             1,
+             'qcm',
+             '{"text": "Que signifie ce panneau ?"}'::jsonb,
+             'signalisation',
+             'danger',
+             'sign://danger/A1a',
+             10,
+             20,
+             'Le panneau A1a annonce un virage dangereux à droite'
+         ) ON CONFLICT (id) DO NOTHING;
+
+INSERT INTO reponses (question_id, valeur, is_correct, ordre) VALUES
+    ('00000000-0000-0000-0002-000000000001'::uuid, 'Virage à droite', true, 1),
+    ('00000000-0000-0000-0002-000000000001'::uuid, 'Virage à gauche', false, 2),
+    ('00000000-0000-0000-0002-000000000001'::uuid, 'Succession de virages', false, 3),
+    ('00000000-0000-0000-0002-000000000001'::uuid, 'Chaussée rétrécie', false, 4)
+    ON CONFLICT DO NOTHING;
+
+-- Question 2 : qcm
+INSERT INTO questions (
+    id,
+    quiz_id,
+    ordre,
+    type_question,
+    question_data,
+    category,
+    subcategory,
+    media_url,
+    points,
+    temps_limite_sec,
+    explanation
+) VALUES (
+             '00000000-0000-0000-0002-000000000002'::uuid,
+             '00000000-0000-0000-0000-000000000002'::uuid,
+             2,
+             'qcm',
+             '{"text": "Ce panneau vous indique :"}'::jsonb,
+             'signalisation',
+             'interdiction',
+             'sign://interdiction/B14',
+             10,
+             20,
+             'Le panneau B14 limite la vitesse à la valeur indiquée, ici 50 km/h, jusquà la prochaine intersection ou le panneau de fin'
+         ) ON CONFLICT (id) DO NOTHING;
+
+INSERT INTO reponses (question_id, valeur, is_correct, ordre) VALUES
+    ('00000000-0000-0000-0002-000000000002'::uuid, 'Une vitesse maximale autorisée', true, 1),
+    ('00000000-0000-0000-0002-000000000002'::uuid, 'Une vitesse minimale obligatoire', false, 2),
+    ('00000000-0000-0000-0002-000000000002'::uuid, 'Une vitesse conseillée', false, 3)
+    ON CONFLICT DO NOTHING;
+
+-- Question 3 : vrai_faux
+INSERT INTO questions (
+    id,
+    quiz_id,
+    ordre,
+    type_question,
+    question_data,
+    category,
+    subcategory,
+    media_url,
+    points,
+    temps_limite_sec,
+    explanation
+) VALUES (
+             '00000000-0000-0000-0002-000000000003'::uuid,
+             '00000000-0000-0000-0000-000000000002'::uuid,
+             3,
+             'vrai_faux',
+             '{"text": "Ce panneau moblige à céder le passage à droite à la prochaine intersection"}'::jsonb,
+             'priorites',
+             'intersection',
+             'sign://intersection/AB1',
+             10,
+             20,
+             'Le panneau AB1 signale une intersection où sapplique la priorité à droite'
+         ) ON CONFLICT (id) DO NOTHING;
+
+INSERT INTO reponses (question_id, valeur, is_correct, ordre) VALUES
+    ('00000000-0000-0000-0002-000000000003'::uuid, 'Vrai', true, 1),
+    ('00000000-0000-0000-0002-000000000003'::uuid, 'Faux', false, 2)
+    ON CONFLICT DO NOTHING;
+
+-- Question 4 : qcm
+INSERT INTO questions (
+    id,
+    quiz_id,
+    ordre,
+    type_question,
+    question_data,
+    category,
+    subcategory,
+    media_url,
+    points,
+    temps_limite_sec,
+    explanation
+) VALUES (
+             '00000000-0000-0000-0002-000000000004'::uuid,
+             '00000000-0000-0000-0000-000000000002'::uuid,
+             4,
+             'qcm',
+             '{"text": "Ce panneau mimpose de :", "multi_select": true, "scoring": "all_or_nothing"}'::jsonb,
+             'priorites',
+             'intersection',
+             'sign://intersection/AB4',
+             10,
+             20,
+             'Au panneau STOP (AB4), larrêt est obligatoire à la limite de la chaussée et il faut céder le passage à tous les usagers'
+         ) ON CONFLICT (id) DO NOTHING;
+
+INSERT INTO reponses (question_id, valeur, is_correct, ordre) VALUES
+    ('00000000-0000-0000-0002-000000000004'::uuid, 'Marquer un arrêt', true, 1),
+    ('00000000-0000-0000-0002-000000000004'::uuid, 'Céder le passage', true, 2),
+    ('00000000-0000-0000-0002-000000000004'::uuid, 'Ralentir seulement', false, 3),
+    ('00000000-0000-0000-0002-000000000004'::uuid, 'Klaxonner', false, 4)
+    ON CONFLICT DO NOTHING;
+
+-- Question 5 : numerique
+INSERT INTO questions (
+    id,
+    quiz_id,
+    ordre,
+    type_question,
+    question_data,
+    category,
+    subcategory,
+    media_url,
+    points,
+    temps_limite_sec,
+    explanation
+) VALUES (
+             '00000000-0000-0000-0002-000000000005'::uuid,
+             '00000000-0000-0000-0000-000000000002'::uuid,
+             5,
+             'numerique',
+             '{"text": "Hors agglomération, sur route bidirectionnelle sans séparateur central, quelle est la vitesse maximale autorisée (en km/h) ?", "expected_value": 80}'::jsonb,
+             'vitesse',
+             'limitations',
+             NULL,
+             10,
+             20,
+             'Depuis le 1er juillet 2018, la vitesse est limitée par défaut à 80 km/h sur ces routes'
+         ) ON CONFLICT (id) DO NOTHING;
+
+-- Question 6 : vrai_faux
+INSERT INTO questions (
+    id,
+    quiz_id,
+    ordre,
+    type_question,
+    question_data,
+    category,
+    subcategory,
+    media_url,
+    points,
+    temps_limite_sec,
+    explanation
+) VALUES (
+             '00000000-0000-0000-0002-000000000006'::uuid,
+             '00000000-0000-0000-0000-000000000002'::uuid,
+             6,
+             'vrai_faux',
+             '{"text": "Ce panneau interdit laccès à tous les véhicules"}'::jsonb,
+             'signalisation',
+             'interdiction',
+             'sign://interdiction/B1',
+             10,
+             20,
+             'Le panneau B1 (sens interdit) interdit laccès à tous les véhicules, dans ce sens uniquement'
+         ) ON CONFLICT (id) DO NOTHING;
+
+INSERT INTO reponses (question_id, valeur, is_correct, ordre) VALUES
+    ('00000000-0000-0000-0002-000000000006'::uuid, 'Vrai', true, 1),
+    ('00000000-0000-0000-0002-000000000006'::uuid, 'Faux', false, 2)
+    ON CONFLICT DO NOTHING;
@@ -5,6 +5,13 @@ pub struct Config {
     pub database_url: String,
     pub server_port: u16,
     pub server_host: String,
+    pub plugins_dir: String,                 // Modules WASM (<domaine>.wasm)
+    pub wasm_fuel: u64,                      // Carburant par appel
+    pub wasm_max_memory_mb: usize,           // Mémoire max par appel
+    pub wasm_timeout_ms: u64,                // Durée max par appel
+    pub session_inactivity_timeout_sec: u64, // Inactivité avant abandon automatique
+    pub session_expiry_interval_sec: u64,    // Période de la tâche d'expiration
+    pub certificate_secret: String,          // Signature des certificats d'examen
 }
 
 impl Config {
@@ -18,6 +25,32 @@ impl Config {
                 .parse()
                 .expect("SERVER_PORT must be a valid port number"),
             server_host: env::var("SERVER_HOST").unwrap_or_else(|_| "0.0.0.0".to_string()),
+            plugins_dir: env::var("PLUGINS_DIR").unwrap_or_else(|_| "plugins".to_string()),
+            wasm_fuel: env::var("WASM_FUEL")
+                .unwrap_or_else(|_| "50000000".to_string())
+                .parse()
+                .expect("WASM_FUEL must be a number"),
+            wasm_max_memory_mb: env::var("WASM_MAX_MEMORY_MB")
+                .unwrap_or_else(|_| "16".to_string())
+                .parse()
+                .expect("WASM_MAX_MEMORY_MB must be a number"),
+            wasm_timeout_ms: env::var("WASM_TIMEOUT_MS")
+                .unwrap_or_else(|_| "200".to_string())
+                .parse()
+                .expect("WASM_TIMEOUT_MS must be a number"),
+            session_inactivity_timeout_sec: env::var("SESSION_INACTIVITY_TIMEOUT_SEC")
+                .unwrap_or_else(|_| "1800".to_string())
+                .parse()
+                .expect("SESSION_INACTIVITY_TIMEOUT_SEC must be a number"),
+            session_expiry_interval_sec: env::var("SESSION_EXPIRY_INTERVAL_SEC")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()
+                .expect("SESSION_EXPIRY_INTERVAL_SEC must be a number"),
+            // Pas de secret par défaut : un secret connu permettrait de forger des certificats
+            certificate_secret: env::var("CERTIFICATE_SECRET")
+                .ok()
+                .filter(|secret| !secret.trim().is_empty())
+                .expect("CERTIFICATE_SECRET must be set in .env file"),
         }
     }
 }
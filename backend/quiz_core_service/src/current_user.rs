@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use axum::{extract::FromRequestParts, http::request::Parts};
+use shared::AppError;
+use uuid::Uuid;
+
+/// En-tête posé par l'api_gateway après validation du JWT (`claims.sub`)
+const USER_ID_HEADER: &str = "X-User-Id";
+
+/// Joueur authentifié ("me"), identifié par l'api_gateway
+#[derive(Debug, Clone, Copy)]
+pub struct CurrentUser(pub Uuid);
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for CurrentUser {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let user_id = parts
+            .headers
+            .get(USER_ID_HEADER)
+            .ok_or_else(|| AppError::Unauthorized("Utilisateur non authentifié".to_string()))?
+            .to_str()
+            .ok()
+            .and_then(|value| Uuid::parse_str(value).ok())
+            .ok_or_else(|| {
+                AppError::Unauthorized(format!("En-tête {} invalide", USER_ID_HEADER))
+            })?;
+
+        Ok(Self(user_id))
+    }
+}
@@ -0,0 +1,19 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::models::Quiz;
+
+/// Paramètres du défi du jour (`?date=2025-11-12`, défaut : aujourd'hui, UTC)
+#[derive(Debug, Default, Deserialize)]
+pub struct DailyChallengeQuery {
+    pub date: Option<NaiveDate>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+/// Défi du jour : quiz synthétique (mode `defi_du_jour`) et sa date
+#[derive(Debug, Serialize)]
+pub struct DailyChallengeResponse {
+    pub date: NaiveDate,
+    pub quiz: Quiz,
+}
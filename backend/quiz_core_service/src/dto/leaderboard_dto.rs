@@ -0,0 +1,35 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::models::LeaderboardEntry;
+
+/// Paramètres d'un classement (`?period=weekly&date=2025-11-12&limit=20&offset=0`)
+#[derive(Debug, Default, Deserialize)]
+pub struct LeaderboardQuery {
+    pub period: Option<String>,  // all_time (défaut), weekly, daily
+    pub date: Option<NaiveDate>, // Jour contenu dans la fenêtre (défaut : aujourd'hui, UTC)
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LeaderboardResponse {
+    pub scope: String,
+    pub key: String,
+    pub period: String,
+    pub period_start: NaiveDate,
+    pub total_players: i64,
+    pub entries: Vec<LeaderboardEntry>,
+}
+
+/// Rang du joueur et ses voisins immédiats
+#[derive(Debug, Serialize)]
+pub struct MyRankResponse {
+    pub scope: String,
+    pub key: String,
+    pub period: String,
+    pub period_start: NaiveDate,
+    pub total_players: i64,
+    pub rank: Option<i64>, // None : pas encore classé dans cette fenêtre
+    pub entries: Vec<LeaderboardEntry>,
+}
@@ -1,6 +1,12 @@
+pub(crate) mod daily_challenge_dto;
+pub(crate) mod leaderboard_dto;
+pub(crate) mod plugin_dto;
+pub(crate) mod progress_dto;
 pub(crate) mod question_dto;
 pub(crate) mod quiz_dto;
 pub(crate) mod reponse_dto;
+pub(crate) mod review_dto;
+pub(crate) mod room_dto;
 pub mod session_dto;
 pub use question_dto::*;
 pub use reponse_dto::*;
@@ -0,0 +1,17 @@
+use serde::Serialize;
+
+/// Plugin de domaine tel qu'exposé par l'API d'administration
+#[derive(Debug, Serialize)]
+pub struct PluginInfo {
+    pub domain: String,
+    pub display_name: String,
+    pub description: String,
+    pub is_active: bool, // domains.is_active
+    pub loaded: bool,    // Présent dans le registry courant
+}
+
+#[derive(Debug, Serialize)]
+pub struct PluginListResponse {
+    pub registry_version: u64,
+    pub plugins: Vec<PluginInfo>,
+}
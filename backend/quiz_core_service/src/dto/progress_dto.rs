@@ -0,0 +1,41 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::services::progression::{LevelProgress, MasteryTier};
+
+/// Fuseau horaire du joueur (IANA, ex: "Europe/Paris")
+#[derive(Debug, Deserialize)]
+pub struct SetTimezoneRequest {
+    pub timezone: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StreakProgress {
+    /// Série en cours (0 si interrompue)
+    pub current: i32,
+    pub best: i32,
+    pub grace_tokens: i32,
+    pub last_played_on: Option<NaiveDate>,
+    pub played_today: bool,
+    pub timezone: String,
+}
+
+/// Maîtrise d'un domaine
+#[derive(Debug, Serialize)]
+pub struct DomainMastery {
+    pub domain: String,
+    pub xp: i64,
+    pub mastery: MasteryTier,
+    pub answers_count: i32,
+    pub accuracy: f64, // 0.0 à 1.0
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProgressResponse {
+    pub user_id: Uuid,
+    pub level: LevelProgress,
+    pub sessions_count: i32,
+    pub streak: StreakProgress,
+    pub domains: Vec<DomainMastery>,
+}
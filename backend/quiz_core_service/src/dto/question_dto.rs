@@ -45,7 +45,7 @@ pub struct QuestionWithReponses {
     pub target_id: Option<Uuid>,
     pub points: i32,
     pub temps_limite_sec: Option<i32>,
-    pub hint: Option<String>,
+    pub has_hint: bool, // 🆕 L'indice lui-même est servi par POST .../hint
     pub explanation: Option<String>,
     pub metadata: serde_json::Value,
     pub total_attempts: i32,
@@ -63,4 +63,6 @@ pub struct ReponseDto {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_correct: Option<bool>, // Seulement pour debug, pas pour le client
     pub ordre: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub colonne: Option<String>, // Pour association : "gauche" ou "droite"
 }
@@ -11,6 +11,7 @@ pub struct CreateReponseRequest {
     pub is_correct: bool,
     pub ordre: Option<i32>,
     pub tolerance_meters: Option<i32>,
+    pub metadata: Option<serde_json::Value>, // Ex: association {"colonne", "paire"}
 }
 
 #[derive(Debug, Deserialize)]
@@ -20,6 +21,7 @@ pub struct UpdateReponseRequest {
     pub is_correct: bool,
     pub ordre: Option<i32>,
     pub tolerance_meters: Option<i32>,
+    pub metadata: Option<serde_json::Value>, // Ex: association {"colonne", "paire"}
 }
 
 // 🆕 DTO pour création en masse
@@ -35,4 +37,5 @@ pub struct BulkReponseItem {
     pub is_correct: bool,
     pub ordre: Option<i32>,
     pub tolerance_meters: Option<i32>,
+    pub metadata: Option<serde_json::Value>, // Ex: association {"colonne", "paire"}
 }
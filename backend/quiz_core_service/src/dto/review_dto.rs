@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Démarrer une session de révision (questions à réviser d'un domaine)
+#[derive(Debug, Deserialize)]
+pub struct StartReviewRequest {
+    pub user_id: Uuid,
+    pub limit: Option<i64>, // Par défaut : 20 cartes
+}
+
+/// Nombre de cartes à réviser dans un domaine
+#[derive(Debug, Serialize)]
+pub struct DueCount {
+    pub domain: String,
+    pub due_count: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DueCountsResponse {
+    pub user_id: Uuid,
+    pub total: i64,
+    pub domains: Vec<DueCount>,
+}
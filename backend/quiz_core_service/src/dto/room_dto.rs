@@ -0,0 +1,10 @@
+use serde::Deserialize;
+
+/// Longueur maximale d'un pseudo affiché dans une salle
+pub const MAX_PSEUDO_LEN: usize = 32;
+
+/// Paramètres de connexion WebSocket à une salle (`?pseudo=...`)
+#[derive(Debug, Deserialize)]
+pub struct JoinRoomQuery {
+    pub pseudo: Option<String>,
+}
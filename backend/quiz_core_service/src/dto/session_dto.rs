@@ -1,17 +1,148 @@
-use serde::Deserialize;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::QuestionWithReponses;
+use crate::models::{ReponseUtilisateur, SessionQuiz, UserAchievement};
+use crate::services::certificate::CertificatePayload;
+use crate::services::session_report::{CategoryAccuracy, QuestionReport};
+
 #[derive(Debug, Deserialize)]
 pub struct StartSessionRequest {
     pub user_id: Uuid,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubmitAnswerRequest {
     pub question_id: Uuid,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub reponse_id: Option<Uuid>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub reponse_ids: Option<Vec<Uuid>>, // QCM multiple : sélection, ordre : ordre proposé
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paires: Option<Vec<PaireAssociation>>, // Pour association
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub valeur_saisie: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coordinates_cliquees: Option<Coordinates>, // Pour carte_cliquable
+    #[serde(default)]
+    pub temps_reponse_sec: i32, // Indicatif : le temps retenu est mesuré par le serveur
+}
+
+/// Présentation d'une question (départ du chrono côté serveur)
+#[derive(Debug, Serialize)]
+pub struct QuestionPresentedResponse {
+    pub question_id: Uuid,
+    pub presented_at: DateTime<Utc>,
+    pub temps_limite_sec: Option<i32>,
+}
+
+/// Indice d'une question (sa consultation pénalise le score de la réponse)
+#[derive(Debug, Serialize)]
+pub struct HintResponse {
+    pub question_id: Uuid,
+    pub hint: String,
+    pub used_at: DateTime<Utc>,
+}
+
+/// Coordonnées cliquées sur la carte (WGS84, degrés décimaux)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Coordinates {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+/// Paire proposée pour une question "association" (reponse_id de chaque colonne)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct PaireAssociation {
+    pub gauche: Uuid,
+    pub droite: Uuid,
+}
+
+/// Question suivante du tirage de la session (None quand toutes sont répondues)
+#[derive(Debug, Serialize)]
+pub struct NextQuestionResponse {
+    pub answered: usize,
+    pub total: usize,
+    pub question: Option<QuestionWithReponses>,
+    pub presented_at: Option<DateTime<Utc>>,
+}
+
+/// Filtre des sessions d'un joueur (`?status=en_cours`)
+#[derive(Debug, Deserialize)]
+pub struct SessionListQuery {
+    pub status: Option<String>,
+}
+
+/// Question déjà répondue, avec son résultat
+#[derive(Debug, Serialize)]
+pub struct AnsweredQuestion {
+    pub question_id: Uuid,
+    pub reponse_id: Option<Uuid>,
+    pub reponse_ids: Vec<Uuid>,
+    pub valeur_saisie: Option<String>,
+    pub is_correct: Option<bool>, // None en examen (révélé à la fin)
+    pub points_obtenus: Option<i32>,
+    pub temps_reponse_sec: i32,
+    pub feedback_message: Option<String>,
+    pub explanation: Option<String>,
+}
+
+/// Reprise d'une session en cours (autre appareil, app relancée)
+#[derive(Debug, Serialize)]
+pub struct SessionResumeResponse {
+    pub session: SessionQuiz,
+    pub answered: Vec<AnsweredQuestion>,
+    /// Questions restantes du tirage, dans l'ordre (la première est la suivante)
+    pub remaining: Vec<QuestionWithReponses>,
+    pub total: usize,
+    pub score: Option<i32>, // None en examen
+    pub streak: Option<i32>,
+    /// Question suivante déjà présentée : son chrono continue
+    pub current_presented_at: Option<DateTime<Utc>>,
+    /// Temps restant du quiz (`quizzes.temps_limite_sec`), None si illimité
+    pub remaining_time_sec: Option<i64>,
+}
+
+/// Rapport de fin de session (écran de résultats)
+#[derive(Debug, Serialize)]
+pub struct SessionReportResponse {
+    pub session_id: Uuid,
+    pub quiz_id: Uuid,
+    pub status: String,
+    pub score: i32,
+    pub score_max: i32,
+    pub pourcentage: Option<f64>,
+    pub temps_total_sec: Option<i32>,
+    pub verdict: Option<serde_json::Value>,
+    pub questions: Vec<QuestionReport>,
+    pub categories: Vec<CategoryAccuracy>,
+    /// 🆕 Succès débloqués pendant la session
+    pub achievements: Vec<UserAchievement>,
+}
+
+/// Réponse enregistrée sans correction (mode examen : résultat révélé à la fin)
+#[derive(Debug, Serialize)]
+pub struct RecordedAnswer {
+    pub id: Uuid,
+    pub session_id: Uuid,
+    pub question_id: Uuid,
     pub temps_reponse_sec: i32,
+    pub created_at: DateTime<Utc>,
+    pub recorded: bool,
+}
+
+/// Réponse à une soumission : correction immédiate, ou simple accusé en examen
+#[derive(Debug, Serialize)]
+#[serde(untagged)]
+pub enum SubmitAnswerResponse {
+    Feedback(ReponseUtilisateur),
+    Recorded(RecordedAnswer),
+}
+
+/// Résultat de la vérification d'un certificat
+#[derive(Debug, Serialize)]
+pub struct CertificateVerification {
+    pub valid: bool,
+    pub payload: CertificatePayload,
 }
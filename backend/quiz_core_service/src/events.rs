@@ -0,0 +1,118 @@
+use serde::Serialize;
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+/// Capacité du canal : un abonné trop lent perd les événements les plus anciens
+const EVENT_CHANNEL_CAPACITY: usize = 1024;
+
+/// Raison de l'abandon d'une session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AbandonReason {
+    /// Abandon explicite du joueur
+    User,
+    /// Aucune activité depuis le délai d'inactivité
+    Inactivity,
+    /// `quizzes.temps_limite_sec` écoulé
+    TimeLimit,
+}
+
+impl AbandonReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::User => "user",
+            Self::Inactivity => "inactivity",
+            Self::TimeLimit => "time_limit",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "user" => Some(Self::User),
+            "inactivity" => Some(Self::Inactivity),
+            "time_limit" => Some(Self::TimeLimit),
+            _ => None,
+        }
+    }
+}
+
+/// Événements du cycle de vie des sessions
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum SessionEvent {
+    SessionAbandoned {
+        session_id: Uuid,
+        user_id: Uuid,
+        quiz_id: Uuid,
+        score: i32,
+        score_max: i32,
+        reason: AbandonReason,
+    },
+}
+
+/// Bus d'événements en mémoire (diffusion à tous les abonnés du processus)
+#[derive(Debug, Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<SessionEvent>,
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventBus {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        Self { sender }
+    }
+
+    /// Publier un événement (sans abonné, il est simplement journalisé)
+    pub fn publish(&self, event: SessionEvent) {
+        tracing::info!(event = ?event, "Session event");
+        let _ = self.sender.send(event);
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<SessionEvent> {
+        self.sender.subscribe()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_publish_to_subscribers() {
+        let bus = EventBus::new();
+        let mut receiver = bus.subscribe();
+
+        bus.publish(SessionEvent::SessionAbandoned {
+            session_id: Uuid::nil(),
+            user_id: Uuid::nil(),
+            quiz_id: Uuid::nil(),
+            score: 30,
+            score_max: 100,
+            reason: AbandonReason::Inactivity,
+        });
+
+        let event = receiver.recv().await.unwrap();
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["type"], "session_abandoned");
+        assert_eq!(json["reason"], "inactivity");
+    }
+
+    #[test]
+    fn test_publish_without_subscriber() {
+        let bus = EventBus::new();
+        bus.publish(SessionEvent::SessionAbandoned {
+            session_id: Uuid::nil(),
+            user_id: Uuid::nil(),
+            quiz_id: Uuid::nil(),
+            score: 0,
+            score_max: 0,
+            reason: AbandonReason::User,
+        });
+    }
+}
@@ -0,0 +1,17 @@
+use axum::{
+    extract::{Path, State},
+    response::Json,
+};
+use shared::AppError;
+use uuid::Uuid;
+
+use crate::{AppState, models::UserAchievement, services::AchievementService};
+
+/// Catalogue des succès avec ceux débloqués par le joueur
+pub async fn get_user_achievements_handler(
+    State(app_state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<Vec<UserAchievement>>, AppError> {
+    let achievements = AchievementService::list_user_achievements(&app_state.pool, user_id).await?;
+    Ok(Json(achievements))
+}
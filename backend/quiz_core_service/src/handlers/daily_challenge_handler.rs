@@ -0,0 +1,88 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::Json,
+};
+use shared::AppError;
+
+use crate::{
+    AppState,
+    current_user::CurrentUser,
+    dto::{
+        daily_challenge_dto::{DailyChallengeQuery, DailyChallengeResponse},
+        leaderboard_dto::{LeaderboardResponse, MyRankResponse},
+    },
+    models::{DailyChallengeSummary, SessionQuiz},
+    services::DailyChallengeService,
+};
+
+/// Défi du jour d'un domaine (généré au premier appel), ou défi passé (`?date=`)
+pub async fn get_daily_challenge_handler(
+    State(app_state): State<AppState>,
+    Path(domain): Path<String>,
+    Query(query): Query<DailyChallengeQuery>,
+) -> Result<Json<DailyChallengeResponse>, AppError> {
+    let challenge = DailyChallengeService::get_challenge(
+        &app_state.pool,
+        &app_state.plugin_manager,
+        &domain,
+        query.date,
+    )
+    .await?;
+    Ok(Json(challenge))
+}
+
+/// Démarrer le défi du jour (une seule tentative par joueur)
+pub async fn start_daily_challenge_handler(
+    State(app_state): State<AppState>,
+    Path(domain): Path<String>,
+    CurrentUser(user_id): CurrentUser,
+) -> Result<Json<SessionQuiz>, AppError> {
+    let session = DailyChallengeService::start_challenge(
+        &app_state.pool,
+        &app_state.plugin_manager,
+        &domain,
+        user_id,
+    )
+    .await?;
+    Ok(Json(session))
+}
+
+/// Classement d'un défi du jour
+pub async fn get_daily_challenge_leaderboard_handler(
+    State(app_state): State<AppState>,
+    Path(domain): Path<String>,
+    Query(query): Query<DailyChallengeQuery>,
+) -> Result<Json<LeaderboardResponse>, AppError> {
+    let leaderboard =
+        DailyChallengeService::get_leaderboard(&app_state.pool, &domain, &query).await?;
+    Ok(Json(leaderboard))
+}
+
+/// Mon rang dans le classement d'un défi du jour
+pub async fn get_my_daily_challenge_rank_handler(
+    State(app_state): State<AppState>,
+    Path(domain): Path<String>,
+    CurrentUser(user_id): CurrentUser,
+    Query(query): Query<DailyChallengeQuery>,
+) -> Result<Json<MyRankResponse>, AppError> {
+    let rank =
+        DailyChallengeService::get_my_rank(&app_state.pool, &domain, user_id, &query).await?;
+    Ok(Json(rank))
+}
+
+/// Historique des défis d'un domaine (résultat du joueur si `X-User-Id` est fourni)
+pub async fn get_daily_challenge_history_handler(
+    State(app_state): State<AppState>,
+    Path(domain): Path<String>,
+    user: Option<CurrentUser>,
+    Query(query): Query<DailyChallengeQuery>,
+) -> Result<Json<Vec<DailyChallengeSummary>>, AppError> {
+    let history = DailyChallengeService::history(
+        &app_state.pool,
+        &domain,
+        user.map(|CurrentUser(user_id)| user_id),
+        &query,
+    )
+    .await?;
+    Ok(Json(history))
+}
@@ -0,0 +1,81 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::Json,
+};
+use shared::AppError;
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    current_user::CurrentUser,
+    dto::leaderboard_dto::{LeaderboardQuery, LeaderboardResponse, MyRankResponse},
+    services::{LeaderboardService, leaderboard::LeaderboardScope},
+};
+
+/// Classement d'un quiz (meilleure session de chaque joueur)
+pub async fn get_quiz_leaderboard_handler(
+    State(app_state): State<AppState>,
+    Path(quiz_id): Path<Uuid>,
+    Query(query): Query<LeaderboardQuery>,
+) -> Result<Json<LeaderboardResponse>, AppError> {
+    let leaderboard = LeaderboardService::get_leaderboard(
+        &app_state.pool,
+        LeaderboardScope::Quiz,
+        &quiz_id.to_string(),
+        &query,
+    )
+    .await?;
+    Ok(Json(leaderboard))
+}
+
+/// Mon rang dans le classement d'un quiz
+pub async fn get_my_quiz_rank_handler(
+    State(app_state): State<AppState>,
+    Path(quiz_id): Path<Uuid>,
+    CurrentUser(user_id): CurrentUser,
+    Query(query): Query<LeaderboardQuery>,
+) -> Result<Json<MyRankResponse>, AppError> {
+    let rank = LeaderboardService::get_my_rank(
+        &app_state.pool,
+        LeaderboardScope::Quiz,
+        &quiz_id.to_string(),
+        user_id,
+        &query,
+    )
+    .await?;
+    Ok(Json(rank))
+}
+
+/// Classement d'un domaine (cumul des sessions de chaque joueur)
+pub async fn get_domain_leaderboard_handler(
+    State(app_state): State<AppState>,
+    Path(domain): Path<String>,
+    Query(query): Query<LeaderboardQuery>,
+) -> Result<Json<LeaderboardResponse>, AppError> {
+    let leaderboard = LeaderboardService::get_leaderboard(
+        &app_state.pool,
+        LeaderboardScope::Domain,
+        &domain,
+        &query,
+    )
+    .await?;
+    Ok(Json(leaderboard))
+}
+
+/// Mon rang dans le classement d'un domaine
+pub async fn get_my_domain_rank_handler(
+    State(app_state): State<AppState>,
+    Path(domain): Path<String>,
+    CurrentUser(user_id): CurrentUser,
+    Query(query): Query<LeaderboardQuery>,
+) -> Result<Json<MyRankResponse>, AppError> {
+    let rank = LeaderboardService::get_my_rank(
+        &app_state.pool,
+        LeaderboardScope::Domain,
+        &domain,
+        user_id,
+        &query,
+    )
+    .await?;
+    Ok(Json(rank))
+}
@@ -1,4 +1,11 @@
+pub mod achievement_handler;
+pub mod daily_challenge_handler;
+pub mod leaderboard_handler;
+pub mod plugin_handler;
+pub mod progress_handler;
 pub mod question_handler;
 pub mod quiz_handler;
 pub mod reponse_handler; // ← Ajouter
+pub mod review_handler;
+pub mod room_handler;
 pub mod session_handler;
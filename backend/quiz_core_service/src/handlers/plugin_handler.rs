@@ -0,0 +1,72 @@
+use std::collections::BTreeMap;
+
+use axum::{
+    extract::{Path, State},
+    response::Json,
+};
+use shared::AppError;
+
+use crate::{AppState, dto::plugin_dto::PluginListResponse, services::PluginService};
+
+pub async fn list_plugins_handler(
+    State(app_state): State<AppState>,
+) -> Result<Json<PluginListResponse>, AppError> {
+    let plugins = PluginService::list(&app_state.pool, &app_state.plugin_manager).await?;
+    Ok(Json(plugins))
+}
+
+pub async fn reload_plugins_handler(
+    State(app_state): State<AppState>,
+) -> Result<Json<PluginListResponse>, AppError> {
+    let plugins = PluginService::reload(&app_state.pool, &app_state.plugin_manager).await?;
+    Ok(Json(plugins))
+}
+
+pub async fn enable_domain_handler(
+    State(app_state): State<AppState>,
+    Path(domain): Path<String>,
+) -> Result<Json<PluginListResponse>, AppError> {
+    let plugins =
+        PluginService::set_domain_active(&app_state.pool, &app_state.plugin_manager, &domain, true)
+            .await?;
+    Ok(Json(plugins))
+}
+
+pub async fn disable_domain_handler(
+    State(app_state): State<AppState>,
+    Path(domain): Path<String>,
+) -> Result<Json<PluginListResponse>, AppError> {
+    let plugins = PluginService::set_domain_active(
+        &app_state.pool,
+        &app_state.plugin_manager,
+        &domain,
+        false,
+    )
+    .await?;
+    Ok(Json(plugins))
+}
+
+/// Remplacer les règles d'un domaine déclaratif (`domains.config`)
+pub async fn update_domain_config_handler(
+    State(app_state): State<AppState>,
+    Path(domain): Path<String>,
+    Json(payload): Json<serde_json::Value>,
+) -> Result<Json<PluginListResponse>, AppError> {
+    let plugins = PluginService::update_domain_config(
+        &app_state.pool,
+        &app_state.plugin_manager,
+        &domain,
+        payload,
+    )
+    .await?;
+    Ok(Json(plugins))
+}
+
+/// Schémas de `question_data` (formulaires de l'outil d'édition)
+pub async fn get_question_schemas_handler(
+    State(app_state): State<AppState>,
+    Path(domain): Path<String>,
+) -> Result<Json<BTreeMap<String, serde_json::Value>>, AppError> {
+    let schemas = PluginService::question_schemas(&app_state.plugin_manager, &domain)?;
+    Ok(Json(schemas))
+}
@@ -0,0 +1,50 @@
+use axum::{
+    extract::{Path, State},
+    response::Json,
+};
+use serde_json::{Value, json};
+use shared::AppError;
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    dto::progress_dto::{ProgressResponse, SetTimezoneRequest},
+    services::ProgressService,
+};
+
+/// XP, niveau, série quotidienne et maîtrise par domaine
+pub async fn get_progress_handler(
+    State(app_state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<ProgressResponse>, AppError> {
+    let progress = ProgressService::get_progress(&app_state.pool, user_id).await?;
+    Ok(Json(progress))
+}
+
+/// Fuseau horaire utilisé pour compter les jours de la série
+pub async fn set_timezone_handler(
+    State(app_state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+    Json(payload): Json<SetTimezoneRequest>,
+) -> Result<Json<ProgressResponse>, AppError> {
+    let progress =
+        ProgressService::set_timezone(&app_state.pool, user_id, &payload.timezone).await?;
+    Ok(Json(progress))
+}
+
+/// Recalculer la progression d'un joueur depuis son historique
+pub async fn rebuild_progress_handler(
+    State(app_state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<Value>, AppError> {
+    let xp = ProgressService::rebuild_user(&app_state.pool, user_id).await?;
+    Ok(Json(json!({ "user_id": user_id, "xp": xp })))
+}
+
+/// Recalculer la progression de tous les joueurs
+pub async fn rebuild_all_progress_handler(
+    State(app_state): State<AppState>,
+) -> Result<Json<Value>, AppError> {
+    let users = ProgressService::rebuild_all(&app_state.pool).await?;
+    Ok(Json(json!({ "users": users })))
+}
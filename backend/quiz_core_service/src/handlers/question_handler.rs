@@ -20,8 +20,9 @@ pub async fn get_questions_by_quiz_handler(
 pub async fn get_question_by_id_handler(
     State(app_state): State<AppState>,
     Path(id): Path<Uuid>,
-) -> Result<Json<Question>, AppError> {
-    let question = QuestionService::get_by_id(&app_state.pool, id).await?;
+) -> Result<Json<QuestionWithReponses>, AppError> {
+    // Route publique : même DTO que la liste du quiz (ni solution, ni indice)
+    let question = QuestionService::get_for_client(&app_state.pool, id).await?;
     Ok(Json(question))
 }
 
@@ -29,7 +30,8 @@ pub async fn create_question_handler(
     State(app_state): State<AppState>,
     Json(payload): Json<CreateQuestionRequest>,
 ) -> Result<Json<Question>, AppError> {
-    let question = QuestionService::create(&app_state.pool, payload).await?;
+    let question =
+        QuestionService::create(&app_state.pool, &app_state.plugin_manager, payload).await?;
     Ok(Json(question))
 }
 
@@ -38,7 +40,8 @@ pub async fn update_question_handler(
     Path(id): Path<Uuid>,
     Json(payload): Json<UpdateQuestionRequest>,
 ) -> Result<Json<Question>, AppError> {
-    let question = QuestionService::update(&app_state.pool, id, payload).await?;
+    let question =
+        QuestionService::update(&app_state.pool, &app_state.plugin_manager, id, payload).await?;
     Ok(Json(question))
 }
 
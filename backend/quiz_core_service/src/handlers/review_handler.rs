@@ -0,0 +1,48 @@
+use axum::{
+    extract::{Path, State},
+    response::Json,
+};
+use serde_json::{Value, json};
+use shared::AppError;
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    dto::review_dto::{DueCountsResponse, StartReviewRequest},
+    models::SessionQuiz,
+    services::ReviewService,
+};
+
+/// Démarrer une session de révision sur les cartes échues d'un domaine
+pub async fn start_review_session_handler(
+    State(app_state): State<AppState>,
+    Path(domain): Path<String>,
+    Json(payload): Json<StartReviewRequest>,
+) -> Result<Json<SessionQuiz>, AppError> {
+    let session = ReviewService::start_review_session(
+        &app_state.pool,
+        &app_state.plugin_manager,
+        &domain,
+        payload,
+    )
+    .await?;
+    Ok(Json(session))
+}
+
+/// Nombre de cartes à réviser par domaine
+pub async fn get_due_counts_handler(
+    State(app_state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<DueCountsResponse>, AppError> {
+    let counts = ReviewService::due_counts(&app_state.pool, user_id).await?;
+    Ok(Json(counts))
+}
+
+/// Reconstruire les cartes d'un joueur depuis son historique
+pub async fn rebuild_reviews_handler(
+    State(app_state): State<AppState>,
+    Path(user_id): Path<Uuid>,
+) -> Result<Json<Value>, AppError> {
+    let cards = ReviewService::rebuild_user(&app_state.pool, user_id).await?;
+    Ok(Json(json!({ "user_id": user_id, "cards": cards })))
+}
@@ -0,0 +1,62 @@
+use axum::{
+    extract::{Path, Query, State, WebSocketUpgrade},
+    http::StatusCode,
+    response::{Json, Response},
+};
+use shared::AppError;
+use uuid::Uuid;
+
+use crate::{
+    AppState,
+    current_user::CurrentUser,
+    dto::room_dto::{JoinRoomQuery, MAX_PSEUDO_LEN},
+    rooms::{self, RoomInfo},
+    services::RoomService,
+};
+
+/// Ouvrir une salle de compétition (l'appelant en est l'hôte)
+pub async fn create_room_handler(
+    State(app_state): State<AppState>,
+    Path(quiz_id): Path<Uuid>,
+    CurrentUser(host_id): CurrentUser,
+) -> Result<(StatusCode, Json<RoomInfo>), AppError> {
+    let room = RoomService::create_room(
+        &app_state.pool,
+        &app_state.plugin_manager,
+        &app_state.rooms,
+        quiz_id,
+        host_id,
+    )
+    .await?;
+    Ok((StatusCode::CREATED, Json(room)))
+}
+
+/// Salle ouverte correspondant à un code
+pub async fn get_room_handler(
+    State(app_state): State<AppState>,
+    Path(code): Path<String>,
+) -> Result<Json<RoomInfo>, AppError> {
+    let room = app_state
+        .rooms
+        .info(&code)
+        .ok_or_else(|| AppError::NotFound(format!("Salle {} introuvable", code)))?;
+    Ok(Json(room))
+}
+
+/// Rejoindre une salle en WebSocket (reconnexion : même utilisateur, même URL)
+pub async fn room_socket_handler(
+    ws: WebSocketUpgrade,
+    State(app_state): State<AppState>,
+    Path(code): Path<String>,
+    CurrentUser(user_id): CurrentUser,
+    Query(query): Query<JoinRoomQuery>,
+) -> Result<Response, AppError> {
+    let pseudo = query
+        .pseudo
+        .map(|p| p.trim().chars().take(MAX_PSEUDO_LEN).collect::<String>())
+        .filter(|p| !p.is_empty())
+        .unwrap_or_else(|| format!("Joueur {}", &user_id.simple().to_string()[..6]));
+
+    let connection = app_state.rooms.connect(&code, user_id, pseudo).await?;
+    Ok(ws.on_upgrade(move |socket| rooms::serve_socket(socket, connection)))
+}
@@ -1,5 +1,5 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     response::Json,
 };
 use shared::AppError;
@@ -7,18 +7,26 @@ use uuid::Uuid;
 
 use crate::{
     AppState,
-    dto::{StartSessionRequest, SubmitAnswerRequest},
-    models::{ReponseUtilisateur, SessionQuiz},
-    services::SessionService,
+    current_user::CurrentUser,
+    dto::{
+        CertificateVerification, HintResponse, NextQuestionResponse, QuestionPresentedResponse,
+        SessionListQuery, SessionReportResponse, SessionResumeResponse, StartSessionRequest,
+        SubmitAnswerRequest, SubmitAnswerResponse,
+    },
+    events::AbandonReason,
+    models::SessionQuiz,
+    services::{SessionService, certificate::ResultCertificate},
 };
 
-// ✅ INCHANGÉ
+// ✅ MODIFIÉ : Passer plugin_manager (version des plugins)
 pub async fn start_session_handler(
     State(app_state): State<AppState>,
     Path(quiz_id): Path<Uuid>,
     Json(payload): Json<StartSessionRequest>,
 ) -> Result<Json<SessionQuiz>, AppError> {
-    let session = SessionService::start_session(&app_state.pool, quiz_id, payload).await?;
+    let session =
+        SessionService::start_session(&app_state.pool, &app_state.plugin_manager, quiz_id, payload)
+            .await?;
     Ok(Json(session))
 }
 
@@ -31,15 +39,44 @@ pub async fn get_session_handler(
     Ok(Json(session))
 }
 
-// ✅ MODIFIÉ : Passer plugin_registry
+/// Question suivante du tirage de la session
+pub async fn next_question_handler(
+    State(app_state): State<AppState>,
+    Path(session_id): Path<Uuid>,
+) -> Result<Json<NextQuestionResponse>, AppError> {
+    let next = SessionService::next_question(&app_state.pool, session_id).await?;
+    Ok(Json(next))
+}
+
+/// Démarrer le chrono d'une question (à appeler à l'affichage)
+pub async fn present_question_handler(
+    State(app_state): State<AppState>,
+    Path((session_id, question_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<QuestionPresentedResponse>, AppError> {
+    let presented =
+        SessionService::present_question(&app_state.pool, session_id, question_id).await?;
+    Ok(Json(presented))
+}
+
+/// Consulter l'indice d'une question (pénalise le score de la réponse)
+pub async fn use_hint_handler(
+    State(app_state): State<AppState>,
+    Path((session_id, question_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<HintResponse>, AppError> {
+    let hint = SessionService::use_hint(&app_state.pool, session_id, question_id).await?;
+    Ok(Json(hint))
+}
+
+// ✅ MODIFIÉ : Passer plugin_manager
 pub async fn submit_answer_handler(
     State(app_state): State<AppState>,
     Path(session_id): Path<Uuid>,
     Json(payload): Json<SubmitAnswerRequest>,
-) -> Result<Json<ReponseUtilisateur>, AppError> {
+) -> Result<Json<SubmitAnswerResponse>, AppError> {
     let reponse = SessionService::submit_answer(
         &app_state.pool,
-        &app_state.plugin_registry, // ✅ AJOUTÉ
+        &app_state.plugin_manager, // ✅ AJOUTÉ
+        &app_state.events,
         session_id,
         payload,
     )
@@ -47,11 +84,84 @@ pub async fn submit_answer_handler(
     Ok(Json(reponse))
 }
 
-// ✅ INCHANGÉ
+// ✅ MODIFIÉ : Passer plugin_manager (verdict de fin de session)
 pub async fn finalize_session_handler(
     State(app_state): State<AppState>,
     Path(session_id): Path<Uuid>,
 ) -> Result<Json<SessionQuiz>, AppError> {
-    let session = SessionService::finalize_session(&app_state.pool, session_id).await?;
+    let session = SessionService::finalize_session(
+        &app_state.pool,
+        &app_state.plugin_manager,
+        &app_state.certificates,
+        session_id,
+    )
+    .await?;
+    Ok(Json(session))
+}
+
+/// Abandon explicite de la session par le joueur
+pub async fn abandon_session_handler(
+    State(app_state): State<AppState>,
+    Path(session_id): Path<Uuid>,
+) -> Result<Json<SessionQuiz>, AppError> {
+    let session = SessionService::abandon_session(
+        &app_state.pool,
+        &app_state.events,
+        session_id,
+        AbandonReason::User,
+    )
+    .await?;
     Ok(Json(session))
 }
+
+/// Sessions du joueur connecté (`?status=en_cours` pour les sessions à reprendre)
+pub async fn get_my_sessions_handler(
+    State(app_state): State<AppState>,
+    CurrentUser(user_id): CurrentUser,
+    Query(query): Query<SessionListQuery>,
+) -> Result<Json<Vec<SessionQuiz>>, AppError> {
+    let sessions =
+        SessionService::list_user_sessions(&app_state.pool, user_id, query.status.as_deref())
+            .await?;
+    Ok(Json(sessions))
+}
+
+/// État complet d'une session en cours pour la reprendre
+pub async fn resume_session_handler(
+    State(app_state): State<AppState>,
+    CurrentUser(user_id): CurrentUser,
+    Path(session_id): Path<Uuid>,
+) -> Result<Json<SessionResumeResponse>, AppError> {
+    let resume = SessionService::resume_session(&app_state.pool, user_id, session_id).await?;
+    Ok(Json(resume))
+}
+
+/// Rapport détaillé de la session (réponses, solutions, réussite par catégorie)
+pub async fn get_session_report_handler(
+    State(app_state): State<AppState>,
+    Path(session_id): Path<Uuid>,
+) -> Result<Json<SessionReportResponse>, AppError> {
+    let report =
+        SessionService::get_report(&app_state.pool, &app_state.plugin_manager, session_id).await?;
+    Ok(Json(report))
+}
+
+/// Certificat signé d'un examen terminé
+pub async fn get_session_certificate_handler(
+    State(app_state): State<AppState>,
+    Path(session_id): Path<Uuid>,
+) -> Result<Json<ResultCertificate>, AppError> {
+    let certificate = SessionService::get_certificate(&app_state.pool, session_id).await?;
+    Ok(Json(certificate))
+}
+
+/// Vérifier l'authenticité d'un certificat d'examen
+pub async fn verify_certificate_handler(
+    State(app_state): State<AppState>,
+    Json(certificate): Json<ResultCertificate>,
+) -> Result<Json<CertificateVerification>, AppError> {
+    let verification =
+        SessionService::verify_certificate(&app_state.pool, &app_state.certificates, certificate)
+            .await?;
+    Ok(Json(verification))
+}
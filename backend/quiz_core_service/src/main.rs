@@ -1,19 +1,27 @@
 mod config;
+mod current_user;
 mod dto;
+mod events;
 mod handlers;
 mod json_utf8;
 mod models;
 mod plugins; // 🆕 Plugin system
 mod repositories;
+mod rooms; // Salles de compétition temps réel
 mod routes;
 mod services;
 
 use axum::http::header;
 use config::Config;
-use plugins::{GeographyPlugin, PluginRegistry}; // 🆕
+use events::EventBus;
+use plugins::{CodeRoutePlugin, GeographyPlugin, PluginManager, WasmLimits, WasmRuntime}; // 🆕
+use rooms::RoomManager;
+use services::certificate::CertificateSigner;
 use sqlx::PgPool;
 use std::net::SocketAddr;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 use tower_http::{cors::CorsLayer, set_header::SetResponseHeaderLayer};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
@@ -21,7 +29,10 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 #[derive(Clone)]
 pub struct AppState {
     pub pool: PgPool,
-    pub plugin_registry: Arc<PluginRegistry>,
+    pub plugin_manager: Arc<PluginManager>,
+    pub events: EventBus,                // Événements de session (abandon...)
+    pub certificates: CertificateSigner, // Certificats de résultat d'examen
+    pub rooms: RoomManager,              // Salles de compétition ouvertes
 }
 
 #[tokio::main]
@@ -43,21 +54,51 @@ async fn main() -> anyhow::Result<()> {
     let pool = PgPool::connect(&config.database_url).await?;
     tracing::info!("✅ Connected to database");
 
-    // 🆕 Plugin Registry
+    // 🆕 Plugin Registry (rechargeable à chaud)
     tracing::info!("🔌 Initializing plugin registry...");
-    let mut plugin_registry = PluginRegistry::new();
-    // 🆕 Enregistrer Geography Plugin
-    plugin_registry.register(Arc::new(GeographyPlugin));
+    let wasm_runtime = WasmRuntime::new(WasmLimits {
+        fuel: config.wasm_fuel,
+        max_memory_bytes: config.wasm_max_memory_mb * 1024 * 1024,
+        timeout: Duration::from_millis(config.wasm_timeout_ms),
+    })?;
+    let plugin_manager = PluginManager::new(
+        vec![
+            // 🆕 Geography Plugin
+            Arc::new(GeographyPlugin),
+            // 🆕 Code de la Route Plugin
+            Arc::new(CodeRoutePlugin),
+        ],
+        // 🆕 Plugins WASM (bac à sable) depuis PLUGINS_DIR, puis domaines déclaratifs
+        wasm_runtime,
+        PathBuf::from(&config.plugins_dir),
+    );
+    let plugin_registry = plugin_manager.reload(&pool).await?;
 
     tracing::info!(
         "✅ Plugin registry initialized with {} plugins",
         plugin_registry.count()
     );
 
+    // Expiration des sessions abandonnées (tâche de fond)
+    let events = EventBus::new();
+    services::session_expiry::spawn(
+        pool.clone(),
+        events.clone(),
+        Duration::from_secs(config.session_expiry_interval_sec),
+        Duration::from_secs(config.session_inactivity_timeout_sec),
+    );
+
+    // Rechargements de plugins publiés par les autres instances (LISTEN/NOTIFY)
+    let plugin_manager = Arc::new(plugin_manager);
+    plugins::spawn_reload_listener(plugin_manager.clone(), pool.clone());
+
     // App State
     let app_state = AppState {
+        rooms: RoomManager::new(pool.clone()),
         pool,
-        plugin_registry: Arc::new(plugin_registry),
+        plugin_manager,
+        events,
+        certificates: CertificateSigner::new(config.certificate_secret.as_bytes()),
     };
 
     // Routes avec CORS
@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Succès du catalogue
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Achievement {
+    pub code: String,
+    pub domain: Option<String>,
+    pub titre: String,
+    pub description: Option<String>,
+    pub icon: Option<String>,
+    pub rule: serde_json::Value,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Succès vu par un joueur (`unlocked_at` absent : pas encore débloqué)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct UserAchievement {
+    pub code: String,
+    pub domain: Option<String>,
+    pub titre: String,
+    pub description: Option<String>,
+    pub icon: Option<String>,
+    pub unlocked_at: Option<DateTime<Utc>>,
+    pub session_id: Option<Uuid>,
+}
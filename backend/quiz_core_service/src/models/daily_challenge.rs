@@ -0,0 +1,16 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Défi du jour passé : participation et résultat du joueur
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct DailyChallengeSummary {
+    pub quiz_id: Uuid,
+    pub date: NaiveDate,
+    pub nb_questions: i32,
+    pub players: i64,
+    pub best_score: Option<i32>,
+    pub played: bool,
+    pub my_score: Option<i32>,
+}
@@ -0,0 +1,92 @@
+//! Modèles de test partagés
+//! Un seul endroit à compléter quand un modèle gagne un champ :
+//! les tests surchargent les champs utiles avec `..fixtures::quiz(...)`
+
+use chrono::Utc;
+use serde_json::json;
+use uuid::Uuid;
+
+use super::{Question, Quiz, Reponse, ReponseUtilisateur};
+
+/// Quiz public actif de 10 questions, seuil de réussite 50 %
+pub fn quiz(domain: &str, mode: &str) -> Quiz {
+    Quiz {
+        id: Uuid::new_v4(),
+        domain: domain.to_string(),
+        titre: "Quiz de test".to_string(),
+        description: None,
+        niveau_difficulte: "moyen".to_string(),
+        version_app: "1.0.0".to_string(),
+        scope: "europe".to_string(),
+        mode: mode.to_string(),
+        collection_id: None,
+        nb_questions: 10,
+        temps_limite_sec: None,
+        score_minimum_success: 50,
+        is_active: true,
+        is_public: true,
+        metadata: json!({}),
+        total_attempts: 0,
+        average_score: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+        created_by: None,
+    }
+}
+
+/// Question de 10 points, 20 secondes, sans indice ni explication
+pub fn question(category: &str, type_question: &str) -> Question {
+    Question {
+        id: Uuid::new_v4(),
+        quiz_id: Uuid::nil(),
+        ordre: 1,
+        category: Some(category.to_string()),
+        subcategory: None,
+        type_question: type_question.to_string(),
+        question_data: json!({"text": "?"}),
+        media_url: None,
+        target_id: None,
+        points: 10,
+        temps_limite_sec: Some(20),
+        hint: None,
+        explanation: None,
+        metadata: json!({}),
+        total_attempts: 0,
+        correct_attempts: 0,
+        difficulty_rating: 1500.0,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
+
+/// Réponse proposée pour une question
+pub fn reponse(question_id: Uuid, valeur: &str, is_correct: bool, ordre: i32) -> Reponse {
+    Reponse {
+        id: Uuid::new_v4(),
+        question_id,
+        valeur: Some(valeur.to_string()),
+        region_id: None,
+        is_correct,
+        ordre,
+        tolerance_meters: None,
+        metadata: json!({}),
+        created_at: Utc::now(),
+    }
+}
+
+/// Réponse d'un joueur (10 points si juste)
+pub fn answer(is_correct: bool, metadata: serde_json::Value) -> ReponseUtilisateur {
+    ReponseUtilisateur {
+        id: Uuid::new_v4(),
+        session_id: Uuid::nil(),
+        question_id: Uuid::new_v4(),
+        reponse_id: None,
+        reponse_ids: Vec::new(),
+        valeur_saisie: None,
+        is_correct,
+        points_obtenus: if is_correct { 10 } else { 0 },
+        temps_reponse_sec: 3,
+        metadata,
+        created_at: Utc::now(),
+    }
+}
@@ -0,0 +1,15 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Ligne d'un classement (ex aequo : même score et même temps)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct LeaderboardEntry {
+    pub rank: i64,
+    pub user_id: Uuid,
+    pub score: i32,
+    pub temps_total_sec: i32,
+    pub sessions_count: i32,
+    pub achieved_at: DateTime<Utc>,
+}
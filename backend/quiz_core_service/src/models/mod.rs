@@ -1,9 +1,25 @@
+mod achievement;
+mod daily_challenge;
+mod domain;
+#[cfg(test)]
+pub mod fixtures;
+mod leaderboard;
+mod progress;
 mod question;
 mod quiz;
 mod reponse;
+mod review_card;
 mod session;
+mod skill;
 
+pub use achievement::{Achievement, UserAchievement};
+pub use daily_challenge::DailyChallengeSummary;
+pub use domain::Domain;
+pub use leaderboard::LeaderboardEntry;
+pub use progress::{UserDomainProgress, UserProgress};
 pub use question::Question;
 pub use quiz::Quiz;
 pub use reponse::Reponse;
+pub use review_card::ReviewCard;
 pub use session::{ReponseUtilisateur, SessionQuiz};
+pub use skill::UserSkillRating;
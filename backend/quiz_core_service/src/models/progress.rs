@@ -0,0 +1,29 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Progression globale d'un joueur
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct UserProgress {
+    pub user_id: Uuid,
+    pub xp: i64,
+    pub sessions_count: i32,
+    pub timezone: String,
+    pub current_streak: i32,
+    pub best_streak: i32,
+    pub grace_tokens: i32,
+    pub last_played_on: Option<NaiveDate>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Progression d'un joueur dans un domaine
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct UserDomainProgress {
+    pub user_id: Uuid,
+    pub domain: String,
+    pub xp: i64,
+    pub answers_count: i32,
+    pub correct_count: i32,
+    pub updated_at: DateTime<Utc>,
+}
@@ -21,6 +21,7 @@ pub struct Question {
     pub metadata: serde_json::Value,
     pub total_attempts: i32,
     pub correct_attempts: i32,
+    pub difficulty_rating: f64, // 🆕 Difficulté Elo (mode adaptatif)
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -0,0 +1,17 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Carte de révision espacée (joueur × question)
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ReviewCard {
+    pub user_id: Uuid,
+    pub question_id: Uuid,
+    pub ease_factor: f64,
+    pub interval_days: i32,
+    pub repetitions: i32,
+    pub lapses: i32,
+    pub due_at: DateTime<Utc>,
+    pub last_reviewed_at: DateTime<Utc>,
+}
@@ -26,6 +26,7 @@ pub struct ReponseUtilisateur {
     pub session_id: Uuid,
     pub question_id: Uuid,
     pub reponse_id: Option<Uuid>,
+    pub reponse_ids: Vec<Uuid>, // 🆕 QCM multiple / ordre
     pub valeur_saisie: Option<String>,
     pub is_correct: bool,
     pub points_obtenus: i32,
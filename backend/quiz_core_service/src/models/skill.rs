@@ -0,0 +1,14 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+/// Niveau Elo d'un joueur dans un domaine
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct UserSkillRating {
+    pub user_id: Uuid,
+    pub domain: String,
+    pub rating: f64,
+    pub nb_answers: i32,
+    pub updated_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,206 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+/// Colonne d'un élément de question "association"
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Colonne {
+    Gauche,
+    Droite,
+}
+
+/// Élément attendu d'une question "association"
+/// Les deux éléments d'une même paire partagent la même clé `paire`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssociationItem {
+    pub colonne: Colonne,
+    pub paire: String,
+}
+
+impl AssociationItem {
+    /// Lire un élément depuis `reponses.metadata` (`colonne` et `paire`)
+    pub fn from_metadata(metadata: &serde_json::Value) -> Option<Self> {
+        let colonne = match metadata.get("colonne")?.as_str()? {
+            "gauche" => Colonne::Gauche,
+            "droite" => Colonne::Droite,
+            _ => return None,
+        };
+        let paire = match metadata.get("paire")? {
+            serde_json::Value::String(key) => key.clone(),
+            other => other.to_string(),
+        };
+        Some(Self { colonne, paire })
+    }
+}
+
+/// Paire soumise refusée
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PairError {
+    /// Élément inconnu, ou gauche/droite pris dans la mauvaise colonne
+    InvalidPair,
+    /// Élément utilisé dans plusieurs paires
+    DuplicateItem,
+}
+
+impl PairError {
+    pub fn message(&self) -> &'static str {
+        match self {
+            Self::InvalidPair => {
+                "Paire invalide : gauche/droite ne correspondent pas à cette question"
+            }
+            Self::DuplicateItem => "Chaque élément ne peut être associé qu'une seule fois",
+        }
+    }
+}
+
+/// Décompte des paires correctes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PairCount {
+    pub correct: usize,
+    pub total: usize,
+}
+
+impl PairCount {
+    /// Score (0.0 à 1.0) : paires correctes / paires attendues
+    pub fn score(&self) -> f32 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        self.correct as f32 / self.total as f32
+    }
+}
+
+/// Compter les paires correctes d'une soumission `(gauche, droite)`
+/// Les paires manquantes comptent simplement comme fausses
+pub fn score_pairs<I: Eq + Hash + Copy>(
+    expected: &HashMap<I, AssociationItem>,
+    submitted: &[(I, I)],
+) -> Result<PairCount, PairError> {
+    let total = expected
+        .values()
+        .filter(|item| item.colonne == Colonne::Gauche)
+        .count();
+
+    // Chaque élément ne peut être utilisé qu'une fois
+    let mut used = HashSet::new();
+    let mut correct = 0;
+    for (gauche, droite) in submitted {
+        let (g, d) = match (expected.get(gauche), expected.get(droite)) {
+            (Some(g), Some(d)) if g.colonne == Colonne::Gauche && d.colonne == Colonne::Droite => {
+                (g, d)
+            }
+            _ => return Err(PairError::InvalidPair),
+        };
+
+        if !used.insert(*gauche) || !used.insert(*droite) {
+            return Err(PairError::DuplicateItem);
+        }
+
+        if g.paire == d.paire {
+            correct += 1;
+        }
+    }
+
+    Ok(PairCount { correct, total })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 1/2 : France/Paris, 3/4 : Italie/Rome
+    fn expected() -> HashMap<u8, AssociationItem> {
+        let item = |colonne, paire: &str| AssociationItem {
+            colonne,
+            paire: paire.to_string(),
+        };
+        HashMap::from([
+            (1, item(Colonne::Gauche, "fr")),
+            (2, item(Colonne::Droite, "fr")),
+            (3, item(Colonne::Gauche, "it")),
+            (4, item(Colonne::Droite, "it")),
+        ])
+    }
+
+    #[test]
+    fn test_all_pairs_correct() {
+        let count = score_pairs(&expected(), &[(1, 2), (3, 4)]).unwrap();
+        assert_eq!(
+            count,
+            PairCount {
+                correct: 2,
+                total: 2
+            }
+        );
+        assert_eq!(count.score(), 1.0);
+    }
+
+    #[test]
+    fn test_swapped_pairs() {
+        let count = score_pairs(&expected(), &[(1, 4), (3, 2)]).unwrap();
+        assert_eq!(count.score(), 0.0);
+    }
+
+    #[test]
+    fn test_missing_pair() {
+        let count = score_pairs(&expected(), &[(1, 2)]).unwrap();
+        assert_eq!(
+            count,
+            PairCount {
+                correct: 1,
+                total: 2
+            }
+        );
+        assert_eq!(count.score(), 0.5);
+    }
+
+    #[test]
+    fn test_duplicate_item() {
+        assert_eq!(
+            score_pairs(&expected(), &[(1, 2), (3, 2)]),
+            Err(PairError::DuplicateItem)
+        );
+        assert_eq!(
+            score_pairs(&expected(), &[(1, 2), (1, 2)]),
+            Err(PairError::DuplicateItem)
+        );
+    }
+
+    #[test]
+    fn test_extra_item() {
+        assert_eq!(
+            score_pairs(&expected(), &[(1, 2), (3, 4), (5, 6)]),
+            Err(PairError::InvalidPair)
+        );
+    }
+
+    #[test]
+    fn test_cross_column_pair() {
+        assert_eq!(
+            score_pairs(&expected(), &[(1, 3)]),
+            Err(PairError::InvalidPair)
+        );
+        assert_eq!(
+            score_pairs(&expected(), &[(2, 1)]),
+            Err(PairError::InvalidPair)
+        );
+    }
+
+    #[test]
+    fn test_item_from_metadata() {
+        let item = AssociationItem::from_metadata(&serde_json::json!({
+            "colonne": "droite",
+            "paire": 3
+        }));
+        assert_eq!(
+            item,
+            Some(AssociationItem {
+                colonne: Colonne::Droite,
+                paire: "3".to_string()
+            })
+        );
+        assert_eq!(
+            AssociationItem::from_metadata(&serde_json::json!({"colonne": "haut", "paire": "a"})),
+            None
+        );
+    }
+}
@@ -0,0 +1,209 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use serde_json::json;
+use shared::AppError;
+use sqlx::PgPool;
+
+use crate::{
+    dto::session_dto::SubmitAnswerRequest,
+    models::{Question, Quiz, ReponseUtilisateur},
+    plugins::{
+        QuizPlugin, SessionVerdict, ValidationResult, question_schemas,
+        scoring::{DEFAULT_SCORING, default_score},
+    },
+};
+
+/// Types de question du code de la route
+const QUESTION_TYPES: &[&str] = &["qcm", "vrai_faux", "numerique"];
+
+/// Examen officiel : 40 questions, échec à partir de 5 fautes
+const EXAM_MAX_ERRORS: usize = 4;
+
+/// Plugin pour le domaine Code de la Route
+pub struct CodeRoutePlugin;
+
+#[async_trait]
+impl QuizPlugin for CodeRoutePlugin {
+    fn domain_name(&self) -> &str {
+        "code_route"
+    }
+
+    fn display_name(&self) -> &str {
+        "Code de la Route"
+    }
+
+    fn description(&self) -> &str {
+        "Préparation à l'examen du code : panneaux, priorités, signalisation"
+    }
+
+    /// Validation des réponses, explication jointe à chaque correction
+    async fn validate_answer(
+        &self,
+        pool: &PgPool,
+        question: &Question,
+        answer: &SubmitAnswerRequest,
+    ) -> Result<ValidationResult, AppError> {
+        let result = match question.type_question.as_str() {
+            "qcm" => self.validate_qcm(pool, question, answer).await?,
+            "vrai_faux" => self.validate_vrai_faux(pool, question, answer).await?,
+            "numerique" => self.validate_numerique(pool, question, answer).await?,
+            _ => {
+                return Err(AppError::BadRequest(format!(
+                    "Type de question '{}' non supporté pour le code de la route",
+                    question.type_question
+                )));
+            }
+        };
+
+        Ok(result.with_explanation(question.explanation.clone().unwrap_or_default()))
+    }
+
+    fn question_schemas(&self) -> BTreeMap<String, serde_json::Value> {
+        question_schemas::default_schemas(QUESTION_TYPES)
+    }
+
+    /// L'explication est obligatoire pour chaque question (refusée à la création sinon)
+    fn explanation_required(&self) -> bool {
+        true
+    }
+
+    /// Pas de bonus vitesse : seul compte le fait de répondre juste
+    async fn calculate_score(
+        &self,
+        base_points: i32,
+        validation: &ValidationResult,
+        time_spent: i32,
+        _time_limit: Option<i32>,
+        streak_count: i32,
+        hint_used: bool,
+    ) -> i32 {
+        default_score(
+            &DEFAULT_SCORING,
+            base_points,
+            validation,
+            time_spent,
+            None,
+            streak_count,
+            hint_used,
+        )
+    }
+
+    /// Pas de badge de vitesse pour le code
+    fn speed_badge(&self, _time_spent: i32, _time_limit: Option<i32>) -> Option<String> {
+        None
+    }
+
+    /// Mode examen : réussite si moins de 5 fautes (questions sans réponse comprises)
+    /// Toute réponse sans la totalité des points est une faute (QCM multiple partiel...)
+    fn session_verdict(
+        &self,
+        quiz: &Quiz,
+        nb_questions: usize,
+        answers: &[ReponseUtilisateur],
+    ) -> Option<SessionVerdict> {
+        if quiz.mode != "examen" {
+            return None;
+        }
+
+        let wrong = answers.iter().filter(|a| !is_fully_correct(a)).count();
+        let unanswered = nb_questions.saturating_sub(answers.len());
+        let errors = wrong + unanswered;
+        let passed = errors <= EXAM_MAX_ERRORS;
+
+        let message = if passed {
+            format!("Examen réussi avec {} faute(s)", errors)
+        } else {
+            format!(
+                "Examen échoué : {} fautes (maximum autorisé : {})",
+                errors, EXAM_MAX_ERRORS
+            )
+        };
+
+        Some(SessionVerdict {
+            passed,
+            message,
+            details: json!({
+                "errors": errors,
+                "unanswered": unanswered,
+                "max_errors": EXAM_MAX_ERRORS,
+                "nb_questions": nb_questions,
+            }),
+        })
+    }
+
+    /// Seed des données du code de la route (via script SQL)
+    async fn seed_data(&self, _pool: &PgPool) -> Result<(), AppError> {
+        tracing::info!("🚗 Code de la Route plugin: seed data will be done via SQL script");
+        Ok(())
+    }
+}
+
+/// Réponse juste avec la totalité des points (`metadata.partial_score` absent ou à 1)
+fn is_fully_correct(answer: &ReponseUtilisateur) -> bool {
+    answer.is_correct
+        && answer
+            .metadata
+            .get("partial_score")
+            .and_then(|s| s.as_f64())
+            .is_none_or(|score| score >= 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures::{self, answer};
+
+    fn exam() -> Quiz {
+        fixtures::quiz("code_route", "examen")
+    }
+
+    #[test]
+    fn test_unanswered_counted_against_drawn_set() {
+        // Tirage de 10 questions (pool plus petit que nb_questions) : 8 réponses justes
+        let answers: Vec<_> = (0..8).map(|_| answer(true, json!({}))).collect();
+        let verdict = CodeRoutePlugin
+            .session_verdict(&exam(), 10, &answers)
+            .unwrap();
+        assert!(verdict.passed);
+        assert_eq!(verdict.details["unanswered"], 2);
+        assert_eq!(verdict.details["nb_questions"], 10);
+    }
+
+    #[test]
+    fn test_partial_answer_is_a_fault() {
+        let mut answers: Vec<_> = (0..36).map(|_| answer(true, json!({}))).collect();
+        answers.extend((0..4).map(|_| answer(true, json!({"partial_score": 0.75}))));
+        assert!(
+            CodeRoutePlugin
+                .session_verdict(&exam(), 40, &answers)
+                .unwrap()
+                .passed
+        );
+
+        answers[0] = answer(true, json!({"partial_score": 0.9}));
+        let verdict = CodeRoutePlugin
+            .session_verdict(&exam(), 40, &answers)
+            .unwrap();
+        assert!(!verdict.passed);
+        assert_eq!(verdict.details["errors"], 5);
+    }
+
+    #[tokio::test]
+    async fn test_score_without_speed_bonus() {
+        let correct = ValidationResult::correct("Bonne réponse !");
+        // Ni bonus de rapidité ni malus de lenteur ; pénalité d'indice du barème par défaut
+        assert_eq!(
+            CodeRoutePlugin
+                .calculate_score(10, &correct, 1, Some(30), 0, false)
+                .await,
+            10
+        );
+        assert_eq!(
+            CodeRoutePlugin
+                .calculate_score(10, &correct, 29, Some(30), 0, true)
+                .await,
+            8
+        );
+    }
+}
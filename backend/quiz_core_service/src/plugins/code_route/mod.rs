@@ -0,0 +1,3 @@
+mod code_route_plugin;
+
+pub use code_route_plugin::CodeRoutePlugin;
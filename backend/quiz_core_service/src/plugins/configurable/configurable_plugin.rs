@@ -0,0 +1,130 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use shared::AppError;
+use sqlx::PgPool;
+
+use super::domain_config::DomainConfig;
+use crate::{
+    dto::session_dto::SubmitAnswerRequest,
+    models::{Domain, Question},
+    plugins::{
+        QuizPlugin, ValidationResult,
+        question_schemas::{self, DEFAULT_QUESTION_TYPES},
+        scoring::{ScoringRules, default_score},
+        text_matching::TextNormalizer,
+    },
+    services::achievements::AchievementDefinition,
+};
+
+/// Plugin générique, décrit entièrement par une ligne de `domains`
+/// Permet d'ouvrir un domaine par simple INSERT SQL, sans code Rust
+pub struct ConfigurablePlugin {
+    name: String,
+    display_name: String,
+    description: String,
+    config: DomainConfig,
+    scoring: ScoringRules,
+}
+
+impl ConfigurablePlugin {
+    /// Construire le plugin depuis une ligne `domains`
+    pub fn from_domain(domain: &Domain) -> Result<Self, String> {
+        let config = match &domain.config {
+            Some(config) => DomainConfig::from_json(config)?,
+            None => DomainConfig::default(),
+        };
+
+        Ok(Self {
+            name: domain.name.clone(),
+            display_name: domain.display_name.clone(),
+            description: domain.description.clone().unwrap_or_default(),
+            scoring: config.scoring_rules(),
+            config,
+        })
+    }
+}
+
+#[async_trait]
+impl QuizPlugin for ConfigurablePlugin {
+    fn domain_name(&self) -> &str {
+        &self.name
+    }
+
+    fn display_name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    /// Schémas des seuls types autorisés
+    fn question_schemas(&self) -> BTreeMap<String, serde_json::Value> {
+        let mut schemas = question_schemas::default_schemas(DEFAULT_QUESTION_TYPES);
+        schemas.retain(|type_question, _| self.config.allows(type_question));
+        schemas
+    }
+
+    /// Validation via les implémentations par défaut, limitée aux types autorisés
+    async fn validate_answer(
+        &self,
+        pool: &PgPool,
+        question: &Question,
+        answer: &SubmitAnswerRequest,
+    ) -> Result<ValidationResult, AppError> {
+        if !self.config.allows(&question.type_question) {
+            return Err(AppError::BadRequest(format!(
+                "Type de question '{}' non supporté pour le domaine {}",
+                question.type_question, self.name
+            )));
+        }
+
+        match question.type_question.as_str() {
+            "qcm" => self.validate_qcm(pool, question, answer).await,
+            "vrai_faux" => self.validate_vrai_faux(pool, question, answer).await,
+            "saisie_texte" => self.validate_saisie_texte(pool, question, answer).await,
+            "ordre" => self.validate_ordre(pool, question, answer).await,
+            "association" => self.validate_association(pool, question, answer).await,
+            "numerique" => self.validate_numerique(pool, question, answer).await,
+            _ => Err(AppError::BadRequest(format!(
+                "Type de question '{}' sans validation générique",
+                question.type_question
+            ))),
+        }
+    }
+
+    /// Calcul du score selon les paliers et la courbe de série configurés
+    async fn calculate_score(
+        &self,
+        base_points: i32,
+        validation: &ValidationResult,
+        time_spent: i32,
+        time_limit: Option<i32>,
+        streak_count: i32,
+        hint_used: bool,
+    ) -> i32 {
+        default_score(
+            &self.scoring,
+            base_points,
+            validation,
+            time_spent,
+            time_limit,
+            streak_count,
+            hint_used,
+        )
+    }
+
+    fn speed_badge(&self, time_spent: i32, time_limit: Option<i32>) -> Option<String> {
+        let (_, badge) = self.scoring.speed_bonus.apply(time_spent, time_limit);
+        badge.map(str::to_string)
+    }
+
+    fn achievements(&self) -> Vec<AchievementDefinition> {
+        self.config.achievements.clone()
+    }
+
+    fn text_normalizer(&self) -> TextNormalizer {
+        self.config.text_normalization.clone()
+    }
+}
@@ -0,0 +1,215 @@
+use serde::Deserialize;
+
+use crate::{
+    plugins::{
+        question_schemas::DEFAULT_QUESTION_TYPES,
+        scoring::{DEFAULT_HINT_PENALTY, ScoringRules, SpeedBonus, StreakBonus},
+        text_matching::TextNormalizer,
+    },
+    services::achievements::AchievementDefinition,
+};
+
+/// Règles d'un domaine déclaratif, lues depuis `domains.config`
+///
+/// ```json
+/// {
+///   "icon": "🎵", "color": "#9C27B0",
+///   "speed_bonus": {
+///     "tiers": [{"max_ratio": 0.3, "multiplier": 1.5, "badge": "⚡ Éclair !"}],
+///     "slow": {"min_ratio": 0.9, "multiplier": 0.75, "badge": "🐢 Prends ton temps"}
+///   },
+///   "streak_bonus": {"min_streak": 3, "curve": [0.1, 0.2, 0.3, 0.4, 0.5]},
+///   "allowed_question_types": ["qcm", "vrai_faux", "saisie_texte"],
+///   "text_normalization": {"fold_accents": true, "articles": ["le", "la"], "abbreviations": {}},
+///   "hint_penalty": 0.3,
+///   "achievements": [{"code": "musique_maestro", "titre": "Maestro", "description": "...",
+///                     "icon": "🎼", "rule": {"type": "perfect_sessions", "count": 5}}]
+/// }
+/// ```
+/// Toute clé absente reprend le comportement par défaut du trait `QuizPlugin`
+/// (`"slow": null` désactive la pénalité de lenteur)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct DomainConfig {
+    pub speed_bonus: SpeedBonus,
+    pub streak_bonus: StreakBonus,
+    pub allowed_question_types: Option<Vec<String>>,
+    pub text_normalization: TextNormalizer,
+    /// Part des points retirée si l'indice a été consulté (0.0..=1.0)
+    pub hint_penalty: Option<f32>,
+    /// Succès propres au domaine
+    pub achievements: Vec<AchievementDefinition>,
+}
+
+impl DomainConfig {
+    /// Lire la configuration (JSON invalide ou hors bornes → erreur, clés absentes → défauts)
+    pub fn from_json(config: &serde_json::Value) -> Result<Self, String> {
+        let config: Self = serde_json::from_value(config.clone()).map_err(|e| e.to_string())?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Bornes du barème : pénalité d'indice dans 0..=1, multiplicateurs et bonus ≥ 0
+    fn validate(&self) -> Result<(), String> {
+        if let Some(penalty) = self.hint_penalty
+            && !(0.0..=1.0).contains(&penalty)
+        {
+            return Err(format!(
+                "hint_penalty doit être compris entre 0 et 1 (reçu : {})",
+                penalty
+            ));
+        }
+        for (i, tier) in self.speed_bonus.tiers.iter().enumerate() {
+            if tier.multiplier < 0.0 {
+                return Err(format!(
+                    "speed_bonus.tiers[{}].multiplier doit être positif (reçu : {})",
+                    i, tier.multiplier
+                ));
+            }
+        }
+        if let Some(slow) = &self.speed_bonus.slow
+            && slow.multiplier < 0.0
+        {
+            return Err(format!(
+                "speed_bonus.slow.multiplier doit être positif (reçu : {})",
+                slow.multiplier
+            ));
+        }
+        if let Some(bonus) = self.streak_bonus.curve.iter().find(|b| **b < 0.0) {
+            return Err(format!(
+                "streak_bonus.curve doit contenir des bonus positifs (reçu : {})",
+                bonus
+            ));
+        }
+        Ok(())
+    }
+
+    /// Barème du domaine (clés absentes → barème par défaut)
+    pub fn scoring_rules(&self) -> ScoringRules {
+        ScoringRules {
+            speed_bonus: self.speed_bonus.clone(),
+            streak_bonus: self.streak_bonus.clone(),
+            hint_penalty: self.hint_penalty.unwrap_or(DEFAULT_HINT_PENALTY),
+        }
+    }
+
+    /// Type de question autorisé pour ce domaine
+    pub fn allows(&self, type_question: &str) -> bool {
+        match &self.allowed_question_types {
+            Some(types) => types.iter().any(|t| t == type_question),
+            None => DEFAULT_QUESTION_TYPES.contains(&type_question),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::achievements::AchievementRule;
+    use serde_json::json;
+
+    #[test]
+    fn test_defaults_match_trait_behaviour() {
+        let config = DomainConfig::from_json(&json!({"icon": "🌍", "color": "#2196F3"})).unwrap();
+
+        assert_eq!(
+            config.speed_bonus.apply(2, Some(10)),
+            (1.5, Some("⚡ Éclair !"))
+        );
+        assert_eq!(config.speed_bonus.apply(7, Some(10)), (1.0, None));
+        assert_eq!(config.speed_bonus.apply(10, Some(10)).0, 0.75);
+        assert_eq!(config.speed_bonus.apply(2, None), (1.0, None));
+
+        assert_eq!(config.streak_bonus.bonus(2), 0.0);
+        assert_eq!(config.streak_bonus.bonus(3), 0.1);
+        assert_eq!(config.streak_bonus.bonus(20), 0.5);
+
+        assert!(config.allows("ordre"));
+        assert!(!config.allows("carte_cliquable"));
+        assert_eq!(config.hint_penalty, None);
+        assert!(config.achievements.is_empty());
+    }
+
+    #[test]
+    fn test_custom_rules() {
+        let config = DomainConfig::from_json(&json!({
+            "speed_bonus": {
+                "tiers": [{"max_ratio": 0.5, "multiplier": 2.0, "badge": "🎵 Oreille absolue"}],
+                "slow": null
+            },
+            "streak_bonus": {"min_streak": 2, "curve": [0.25, 1.0]},
+            "allowed_question_types": ["qcm"],
+            "text_normalization": {"fold_accents": false, "articles": ["the"]},
+            "hint_penalty": 0.5,
+            "achievements": [{
+                "code": "musique_maestro",
+                "titre": "Maestro",
+                "description": "5 sessions parfaites en musique",
+                "icon": "🎼",
+                "rule": {"type": "perfect_sessions", "count": 5}
+            }]
+        }))
+        .unwrap();
+
+        assert_eq!(
+            config.speed_bonus.apply(4, Some(10)),
+            (2.0, Some("🎵 Oreille absolue"))
+        );
+        // Pénalité de lenteur désactivée ("slow": null)
+        assert_eq!(config.speed_bonus.apply(10, Some(10)), (1.0, None));
+
+        assert_eq!(config.streak_bonus.bonus(2), 0.25);
+        assert_eq!(config.streak_bonus.bonus(5), 1.0);
+
+        assert!(config.allows("qcm"));
+        assert!(!config.allows("vrai_faux"));
+        assert_eq!(config.hint_penalty, Some(0.5));
+        assert_eq!(
+            config.achievements[0].rule,
+            AchievementRule::PerfectSessions { count: 5 }
+        );
+
+        assert_eq!(
+            config.text_normalization.normalize("The Beatles"),
+            "beatles"
+        );
+        // Abréviations par défaut conservées
+        assert_eq!(
+            config.text_normalization.normalize("St Louis"),
+            "saint louis"
+        );
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        assert!(DomainConfig::from_json(&json!({"streak_bonus": {"curve": "fort"}})).is_err());
+    }
+
+    #[test]
+    fn test_out_of_range_rules() {
+        let error = DomainConfig::from_json(&json!({"hint_penalty": 1.5})).unwrap_err();
+        assert!(error.contains("hint_penalty"));
+        assert!(DomainConfig::from_json(&json!({"hint_penalty": -0.1})).is_err());
+        assert!(DomainConfig::from_json(&json!({"hint_penalty": 1.0})).is_ok());
+
+        let error = DomainConfig::from_json(&json!({
+            "speed_bonus": {"tiers": [{"max_ratio": 0.3, "multiplier": -2.0}]}
+        }))
+        .unwrap_err();
+        assert!(error.contains("speed_bonus.tiers[0].multiplier"));
+        assert!(
+            DomainConfig::from_json(&json!({
+                "speed_bonus": {"slow": {"min_ratio": 0.9, "multiplier": -0.5}}
+            }))
+            .is_err()
+        );
+        assert!(DomainConfig::from_json(&json!({"streak_bonus": {"curve": [0.1, -0.2]}})).is_err());
+        // Multiplicateur nul : pas de points pour une réponse trop lente
+        assert!(
+            DomainConfig::from_json(&json!({
+                "speed_bonus": {"slow": {"min_ratio": 0.9, "multiplier": 0.0}}
+            }))
+            .is_ok()
+        );
+    }
+}
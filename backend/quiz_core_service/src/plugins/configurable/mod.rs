@@ -0,0 +1,5 @@
+mod configurable_plugin;
+mod domain_config;
+
+pub use configurable_plugin::ConfigurablePlugin;
+pub use domain_config::DomainConfig;
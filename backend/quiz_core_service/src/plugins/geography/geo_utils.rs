@@ -0,0 +1,227 @@
+use serde_json::Value;
+
+/// Rayon moyen de la Terre (en mètres)
+const EARTH_RADIUS_METERS: f64 = 6_371_008.8;
+
+/// Point géographique en degrés décimaux (WGS84)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeoPoint {
+    pub lng: f64,
+    pub lat: f64,
+}
+
+impl GeoPoint {
+    pub fn new(lng: f64, lat: f64) -> Self {
+        Self { lng, lat }
+    }
+}
+
+/// Anneau d'un polygone (liste de points, fermé ou non)
+type Ring = Vec<GeoPoint>;
+
+/// Polygone GeoJSON : premier anneau = contour, suivants = trous
+type Polygon = Vec<Ring>;
+
+/// Distance orthodromique entre deux points (formule de haversine), en mètres
+pub fn haversine_distance(a: GeoPoint, b: GeoPoint) -> f64 {
+    let lat1 = a.lat.to_radians();
+    let lat2 = b.lat.to_radians();
+    let dlat = (b.lat - a.lat).to_radians();
+    let dlng = (b.lng - a.lng).to_radians();
+
+    let h = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlng / 2.0).sin().powi(2);
+
+    2.0 * EARTH_RADIUS_METERS * h.sqrt().asin()
+}
+
+/// Distance entre un point et une géométrie GeoJSON, en mètres
+/// Retourne 0 si le point est à l'intérieur, None si la géométrie est invalide
+pub fn distance_to_geometry(point: GeoPoint, geometry: &Value) -> Option<f64> {
+    let polygons = parse_polygons(geometry)?;
+
+    if polygons
+        .iter()
+        .any(|polygon| polygon_contains(polygon, point))
+    {
+        return Some(0.0);
+    }
+
+    polygons
+        .iter()
+        .flatten()
+        .map(|ring| distance_to_ring(point, ring))
+        .min_by(|a, b| a.total_cmp(b))
+}
+
+/// Extraire les polygones d'un GeoJSON (Feature, Polygon ou MultiPolygon)
+fn parse_polygons(geometry: &Value) -> Option<Vec<Polygon>> {
+    match geometry.get("type")?.as_str()? {
+        "Feature" => parse_polygons(geometry.get("geometry")?),
+        "Polygon" => Some(vec![parse_polygon(geometry.get("coordinates")?)?]),
+        "MultiPolygon" => geometry
+            .get("coordinates")?
+            .as_array()?
+            .iter()
+            .map(parse_polygon)
+            .collect(),
+        _ => None,
+    }
+}
+
+fn parse_polygon(coordinates: &Value) -> Option<Polygon> {
+    coordinates
+        .as_array()?
+        .iter()
+        .map(|ring| {
+            ring.as_array()?
+                .iter()
+                .map(|position| {
+                    let position = position.as_array()?;
+                    Some(GeoPoint::new(
+                        position.first()?.as_f64()?,
+                        position.get(1)?.as_f64()?,
+                    ))
+                })
+                .collect::<Option<Ring>>()
+        })
+        .collect()
+}
+
+/// Point dans un polygone (contour moins les trous)
+fn polygon_contains(polygon: &Polygon, point: GeoPoint) -> bool {
+    match polygon.split_first() {
+        Some((outer, holes)) => {
+            ring_contains(outer, point) && !holes.iter().any(|hole| ring_contains(hole, point))
+        }
+        None => false,
+    }
+}
+
+/// Algorithme du lancer de rayon (ray casting) sur le plan lng/lat
+fn ring_contains(ring: &Ring, point: GeoPoint) -> bool {
+    if ring.len() < 3 {
+        return false;
+    }
+
+    let mut inside = false;
+    let mut j = ring.len() - 1;
+    for i in 0..ring.len() {
+        let (a, b) = (ring[i], ring[j]);
+        if (a.lat > point.lat) != (b.lat > point.lat)
+            && point.lng < (b.lng - a.lng) * (point.lat - a.lat) / (b.lat - a.lat) + a.lng
+        {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+/// Distance minimale entre un point et les segments d'un anneau, en mètres
+fn distance_to_ring(point: GeoPoint, ring: &Ring) -> f64 {
+    match ring.len() {
+        0 => f64::INFINITY,
+        1 => haversine_distance(point, ring[0]),
+        _ => ring
+            .iter()
+            .zip(ring.iter().cycle().skip(1))
+            .map(|(a, b)| distance_to_segment(point, *a, *b))
+            .fold(f64::INFINITY, f64::min),
+    }
+}
+
+/// Distance point-segment via une projection équirectangulaire locale
+/// (suffisamment précise à l'échelle d'une région)
+fn distance_to_segment(point: GeoPoint, a: GeoPoint, b: GeoPoint) -> f64 {
+    let cos_lat = point.lat.to_radians().cos();
+    let project = |p: GeoPoint| {
+        (
+            (p.lng - point.lng).to_radians() * cos_lat * EARTH_RADIUS_METERS,
+            (p.lat - point.lat).to_radians() * EARTH_RADIUS_METERS,
+        )
+    };
+
+    let (ax, ay) = project(a);
+    let (bx, by) = project(b);
+    let (dx, dy) = (bx - ax, by - ay);
+    let length_sq = dx * dx + dy * dy;
+
+    let t = if length_sq == 0.0 {
+        0.0
+    } else {
+        (-(ax * dx + ay * dy) / length_sq).clamp(0.0, 1.0)
+    };
+
+    (ax + t * dx).hypot(ay + t * dy)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const PARIS: GeoPoint = GeoPoint {
+        lng: 2.3522,
+        lat: 48.8566,
+    };
+    const LYON: GeoPoint = GeoPoint {
+        lng: 4.8357,
+        lat: 45.7640,
+    };
+
+    fn square() -> Value {
+        json!({
+            "type": "Polygon",
+            "coordinates": [[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0], [0.0, 0.0]]]
+        })
+    }
+
+    #[test]
+    fn test_haversine_paris_lyon() {
+        let distance = haversine_distance(PARIS, LYON);
+        // ~392 km à vol d'oiseau
+        assert!((distance - 392_000.0).abs() < 2_000.0);
+        assert_eq!(haversine_distance(PARIS, PARIS), 0.0);
+    }
+
+    #[test]
+    fn test_point_inside_polygon() {
+        let distance = distance_to_geometry(GeoPoint::new(0.5, 0.5), &square());
+        assert_eq!(distance, Some(0.0));
+    }
+
+    #[test]
+    fn test_point_outside_polygon() {
+        // 0.1° de longitude à l'équateur ≈ 11.1 km
+        let distance = distance_to_geometry(GeoPoint::new(1.1, 0.5), &square()).unwrap();
+        assert!((distance - 11_120.0).abs() < 100.0);
+    }
+
+    #[test]
+    fn test_polygon_hole_and_multipolygon() {
+        let with_hole = json!({
+            "type": "Feature",
+            "geometry": {
+                "type": "MultiPolygon",
+                "coordinates": [[
+                    [[0.0, 0.0], [4.0, 0.0], [4.0, 4.0], [0.0, 4.0], [0.0, 0.0]],
+                    [[1.0, 1.0], [3.0, 1.0], [3.0, 3.0], [1.0, 3.0], [1.0, 1.0]]
+                ]]
+            }
+        });
+
+        assert_eq!(
+            distance_to_geometry(GeoPoint::new(0.5, 0.5), &with_hole),
+            Some(0.0)
+        );
+        assert!(distance_to_geometry(GeoPoint::new(2.0, 2.0), &with_hole).unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_invalid_geometry() {
+        assert_eq!(
+            distance_to_geometry(PARIS, &json!({"type": "Point", "coordinates": [0, 0]})),
+            None
+        );
+    }
+}
@@ -1,13 +1,37 @@
+use std::collections::BTreeMap;
+
 use async_trait::async_trait;
 use shared::AppError;
 use sqlx::PgPool;
+use uuid::Uuid;
 
+use super::geo_utils::{self, GeoPoint};
 use crate::{
     dto::session_dto::SubmitAnswerRequest,
     models::Question,
-    plugins::{QuizPlugin, ValidationResult},
+    plugins::{
+        QuizPlugin, ValidationResult,
+        question_schemas::{self, DEFAULT_QUESTION_TYPES},
+        text_matching::{self, TextMatch, TypoTolerance},
+    },
+    services::achievements::{AchievementDefinition, AchievementRule},
 };
 
+/// Tolérance par défaut pour la carte cliquable (si `reponses.tolerance_meters` est NULL)
+const DEFAULT_TOLERANCE_METERS: f64 = 50_000.0;
+
+/// Au-delà de `tolerance × PARTIAL_CREDIT_FACTOR`, le clic ne rapporte plus rien
+const PARTIAL_CREDIT_FACTOR: f64 = 3.0;
+
+/// Cible correcte d'une question carte_cliquable
+#[derive(sqlx::FromRow)]
+struct MapTarget {
+    lng: Option<f64>,
+    lat: Option<f64>,
+    region_id: Option<Uuid>,
+    tolerance_meters: Option<i32>,
+}
+
 /// Plugin pour le domaine Géographie
 pub struct GeographyPlugin;
 
@@ -39,10 +63,10 @@ impl QuizPlugin for GeographyPlugin {
             "vrai_faux" => self.validate_vrai_faux(pool, question, answer).await,
             "saisie_texte" => self.validate_saisie_texte_geo(pool, question, answer).await,
 
-            // V1 : Carte cliquable (pas encore implémenté)
-            "carte_cliquable" => Err(AppError::BadRequest(
-                "Type 'carte_cliquable' pas encore implémenté (prévu V1)".to_string(),
-            )),
+            "carte_cliquable" => self.validate_carte_cliquable(pool, question, answer).await,
+            "ordre" => self.validate_ordre(pool, question, answer).await,
+            "association" => self.validate_association(pool, question, answer).await,
+            "numerique" => self.validate_numerique(pool, question, answer).await,
 
             _ => Err(AppError::BadRequest(format!(
                 "Type de question '{}' non supporté pour la géographie",
@@ -51,14 +75,22 @@ impl QuizPlugin for GeographyPlugin {
         }
     }
 
+    /// Types par défaut + carte cliquable
+    fn question_schemas(&self) -> BTreeMap<String, serde_json::Value> {
+        let mut schemas = question_schemas::default_schemas(DEFAULT_QUESTION_TYPES);
+        schemas.extend(question_schemas::default_schemas(&["carte_cliquable"]));
+        schemas
+    }
+
     /// Calcul du score avec bonus géographiques
-    fn calculate_score(
+    async fn calculate_score(
         &self,
         base_points: i32,
         validation: &ValidationResult,
         time_spent: i32,
         time_limit: Option<i32>,
         streak_count: i32,
+        hint_used: bool,
     ) -> i32 {
         if !validation.is_correct && validation.partial_score.is_none() {
             return 0;
@@ -89,6 +121,11 @@ impl QuizPlugin for GeographyPlugin {
             points += base_points as f32 * streak_bonus;
         }
 
+        // 🆕 Indice consulté : -30%
+        if hint_used {
+            points *= 0.7;
+        }
+
         points.round() as i32
     }
 
@@ -109,6 +146,29 @@ impl QuizPlugin for GeographyPlugin {
     }
 
     /// Seed des données géographiques (on le fera plus tard)
+    /// 🆕 Succès de géographie
+    fn achievements(&self) -> Vec<AchievementDefinition> {
+        vec![
+            AchievementDefinition {
+                code: "geography_tour_de_france".to_string(),
+                titre: "Tour de France".to_string(),
+                description: "Terminer tous les quiz de géographie sur la France".to_string(),
+                icon: Some("🇫🇷".to_string()),
+                rule: AchievementRule::CompleteQuizzes {
+                    scope: Some("france".to_string()),
+                },
+            },
+            AchievementDefinition {
+                code: "geography_expert".to_string(),
+                titre: "Expert géographe".to_string(),
+                description: "Répondre juste en moins de 30 % du temps, 20 fois en géographie"
+                    .to_string(),
+                icon: Some("🌍".to_string()),
+                rule: AchievementRule::FastAnswers { count: 20 },
+            },
+        ]
+    }
+
     async fn seed_data(&self, _pool: &PgPool) -> Result<(), AppError> {
         tracing::info!("🌍 Geography plugin: seed data will be done via SQL script");
         Ok(())
@@ -118,7 +178,7 @@ impl QuizPlugin for GeographyPlugin {
 // Méthodes privées spécifiques à la géographie
 impl GeographyPlugin {
     /// Validation saisie texte avec variations acceptées et normalisation
-    /// Ex: "Paris", "paris", "PARIS" sont toutes acceptées
+    /// Ex: "Rhône", "rhone", "RHONE" sont toutes acceptées, "Rhnoe" est "presque" correct
     async fn validate_saisie_texte_geo(
         &self,
         pool: &PgPool,
@@ -130,13 +190,10 @@ impl GeographyPlugin {
             .as_ref()
             .ok_or_else(|| AppError::BadRequest("valeur_saisie requise".to_string()))?;
 
-        // Normaliser la saisie : lowercase + trim
-        let normalized = valeur_saisie.trim().to_lowercase();
-
         // Chercher toutes les réponses correctes (peut y avoir des variantes)
         let correct_answers: Vec<String> = sqlx::query_scalar(
             r#"
-            SELECT LOWER(valeur)
+            SELECT valeur
             FROM reponses
             WHERE question_id = $1
             AND is_correct = true
@@ -147,20 +204,145 @@ impl GeographyPlugin {
         .fetch_all(pool)
         .await?;
 
-        // Vérifier si la réponse normalisée correspond à l'une des variantes
-        let is_correct = correct_answers.iter().any(|answer| answer == &normalized);
+        // Normalisation (accents, tirets, articles, abréviations) + tolérance aux fautes
+        let tolerance = TypoTolerance::from_question_data(&question.question_data);
+        let text_match = text_matching::match_answer(
+            &self.text_normalizer(),
+            &tolerance,
+            valeur_saisie,
+            &correct_answers,
+        );
 
-        if is_correct {
-            Ok(ValidationResult::correct("Bonne réponse !")
-                .with_explanation(question.explanation.clone().unwrap_or_default()))
+        let result = match text_match {
+            TextMatch::Exact(_) => ValidationResult::correct("Bonne réponse !"),
+            TextMatch::NearMiss { expected, distance } => {
+                ValidationResult::near_miss(&expected, distance, tolerance.partial_score)
+            }
+            TextMatch::NoMatch => {
+                // Récupérer la bonne réponse pour l'afficher
+                let correct = correct_answers.first().cloned().unwrap_or_default();
+                ValidationResult::incorrect(format!(
+                    "Mauvaise réponse. La bonne réponse était : {}",
+                    correct
+                ))
+            }
+        };
+
+        Ok(result.with_explanation(question.explanation.clone().unwrap_or_default()))
+    }
+
+    /// Validation carte cliquable : distance au point cible (haversine) ou
+    /// appartenance à la région cible (GeoJSON), avec score partiel dégressif
+    async fn validate_carte_cliquable(
+        &self,
+        pool: &PgPool,
+        question: &Question,
+        answer: &SubmitAnswerRequest,
+    ) -> Result<ValidationResult, AppError> {
+        let clicked = match &answer.coordinates_cliquees {
+            Some(coords) => GeoPoint::new(coords.longitude, coords.latitude),
+            None => {
+                // Timeout : aucun clic
+                return Ok(ValidationResult::incorrect("Temps écoulé - Aucune réponse")
+                    .with_explanation(question.explanation.clone().unwrap_or_default()));
+            }
+        };
+
+        // Cibles correctes : point (x = lng, y = lat) et/ou région
+        let targets: Vec<MapTarget> = sqlx::query_as(
+            r#"
+            SELECT coordinates_point[0] AS lng, coordinates_point[1] AS lat,
+                   region_id, tolerance_meters
+            FROM reponses
+            WHERE question_id = $1
+            AND is_correct = true
+            "#,
+        )
+        .bind(question.id)
+        .fetch_all(pool)
+        .await?;
+
+        let mut best: Option<(f32, f64)> = None; // (score, distance en mètres)
+
+        for target in targets {
+            let tolerance = target
+                .tolerance_meters
+                .map(f64::from)
+                .unwrap_or(DEFAULT_TOLERANCE_METERS);
+
+            let mut distances = Vec::new();
+
+            if let (Some(lng), Some(lat)) = (target.lng, target.lat) {
+                distances.push(geo_utils::haversine_distance(
+                    clicked,
+                    GeoPoint::new(lng, lat),
+                ));
+            }
+
+            if let Some(region_id) = target.region_id {
+                let geometry: Option<serde_json::Value> =
+                    sqlx::query_scalar("SELECT geometry FROM regions WHERE id = $1")
+                        .bind(region_id)
+                        .fetch_optional(pool)
+                        .await?;
+
+                match geometry.and_then(|g| geo_utils::distance_to_geometry(clicked, &g)) {
+                    Some(distance) => distances.push(distance),
+                    None => tracing::warn!(
+                        question_id = %question.id,
+                        region_id = %region_id,
+                        "Missing or invalid region geometry"
+                    ),
+                }
+            }
+
+            for distance in distances {
+                let score = Self::distance_score(distance, tolerance);
+                if best.is_none_or(|(best_score, best_distance)| {
+                    score > best_score || (score == best_score && distance < best_distance)
+                }) {
+                    best = Some((score, distance));
+                }
+            }
+        }
+
+        let (score, distance) = best.ok_or_else(|| {
+            AppError::InternalServerError(format!(
+                "Aucune cible définie pour la question carte_cliquable {}",
+                question.id
+            ))
+        })?;
+
+        let distance_km = distance / 1000.0;
+        let result = if score >= 1.0 {
+            ValidationResult::correct("Bonne réponse !")
+        } else if score > 0.0 {
+            ValidationResult::partial(
+                score,
+                format!("Presque ! Vous êtes à {:.0} km de la cible", distance_km),
+            )
         } else {
-            // Récupérer la bonne réponse pour l'afficher
-            let correct = correct_answers.first().cloned().unwrap_or_default();
-            Ok(ValidationResult::incorrect(format!(
-                "Mauvaise réponse. La bonne réponse était : {}",
-                correct
+            ValidationResult::incorrect(format!(
+                "Mauvaise réponse. Vous êtes à {:.0} km de la cible",
+                distance_km
             ))
-            .with_explanation(question.explanation.clone().unwrap_or_default()))
+        };
+
+        Ok(result.with_explanation(question.explanation.clone().unwrap_or_default()))
+    }
+
+    /// Score selon la distance : 1.0 dans la tolérance, puis décroissance
+    /// linéaire jusqu'à 0 à `tolerance × PARTIAL_CREDIT_FACTOR`
+    fn distance_score(distance: f64, tolerance: f64) -> f32 {
+        if distance <= tolerance {
+            return 1.0;
         }
+
+        let max_distance = tolerance * PARTIAL_CREDIT_FACTOR;
+        if distance >= max_distance {
+            return 0.0;
+        }
+
+        (1.0 - (distance - tolerance) / (max_distance - tolerance)) as f32
     }
 }
@@ -1,3 +1,4 @@
+mod geo_utils;
 mod geography_plugin;
 
 pub use geography_plugin::GeographyPlugin;
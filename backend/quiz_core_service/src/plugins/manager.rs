@@ -0,0 +1,225 @@
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use arc_swap::ArcSwap;
+use shared::AppError;
+use sqlx::{PgPool, postgres::PgListener};
+use tokio::task::JoinHandle;
+
+use super::{ConfigurablePlugin, PluginRegistry, QuizPlugin, WasmRuntime};
+use crate::{
+    models::SessionQuiz,
+    repositories::{DomainRepository, SessionRepository},
+    services::AchievementService,
+};
+
+/// Canal LISTEN/NOTIFY des rechargements (charge utile : version du registry)
+pub const RELOAD_CHANNEL: &str = "plugin_registry_reload";
+
+/// Délai avant de réécouter le canal après une erreur
+const LISTENER_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+/// Registry rechargeable à chaud
+///
+/// Chaque rechargement produit une nouvelle version du registry, tirée d'une séquence
+/// PostgreSQL : une version n'est jamais réutilisée, même après un redémarrage. Les
+/// sessions enregistrent la version active à leur démarrage (`metadata.plugin_version`)
+/// et continuent d'utiliser celle-ci tant qu'elles sont en cours.
+///
+/// Les registries précédents ne vivent qu'en mémoire : après un redémarrage, une session
+/// démarrée avant celui-ci ne retrouve pas sa version et termine avec le registry courant.
+///
+/// Avec plusieurs instances, celle qui recharge publie la nouvelle version sur le canal
+/// PostgreSQL `RELOAD_CHANNEL` ; les autres reconstruisent leur registry avec ce même
+/// numéro (`spawn_reload_listener`). Les modules WASM sont lus dans le `PLUGINS_DIR` de
+/// chaque instance, qui doit donc être identique partout.
+pub struct PluginManager {
+    native: Vec<Arc<dyn QuizPlugin>>,
+    wasm_runtime: Arc<WasmRuntime>,
+    plugins_dir: PathBuf,
+    current: ArcSwap<PluginRegistry>,
+    /// Versions précédentes encore utilisées par des sessions en cours
+    pinned: RwLock<HashMap<u64, Arc<PluginRegistry>>>,
+    reload_lock: tokio::sync::Mutex<()>,
+}
+
+impl PluginManager {
+    pub fn new(
+        native: Vec<Arc<dyn QuizPlugin>>,
+        wasm_runtime: Arc<WasmRuntime>,
+        plugins_dir: PathBuf,
+    ) -> Self {
+        Self {
+            native,
+            wasm_runtime,
+            plugins_dir,
+            current: ArcSwap::from_pointee(PluginRegistry::new()),
+            pinned: RwLock::new(HashMap::new()),
+            reload_lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    /// Registry courant (nouvelles sessions)
+    pub fn current(&self) -> Arc<PluginRegistry> {
+        self.current.load_full()
+    }
+
+    /// Registry avec lequel la session a démarré (registry courant si inconnu,
+    /// ex: session démarrée avant un redémarrage du service)
+    pub fn for_session(&self, session: &SessionQuiz) -> Arc<PluginRegistry> {
+        let current = self.current();
+        let version = session
+            .metadata
+            .get("plugin_version")
+            .and_then(|v| v.as_u64());
+
+        match version {
+            Some(version) if version != current.version() => {
+                let pinned = self
+                    .pinned
+                    .read()
+                    .expect("plugin registry lock poisoned")
+                    .get(&version)
+                    .cloned();
+                pinned.unwrap_or_else(|| {
+                    tracing::warn!(
+                        session_id = %session.id,
+                        plugin_version = version,
+                        current_version = current.version(),
+                        "Plugin registry version no longer loaded, current registry used"
+                    );
+                    current
+                })
+            }
+            _ => current,
+        }
+    }
+
+    /// Reconstruire le registry sous une nouvelle version, puis prévenir les autres instances
+    pub async fn reload(&self, pool: &PgPool) -> Result<Arc<PluginRegistry>, AppError> {
+        let _guard = self.reload_lock.lock().await;
+
+        let version = DomainRepository::next_registry_version(pool).await?;
+        let registry = self.rebuild(pool, version as u64).await?;
+
+        // Succès déclarés par les plugins
+        let achievements = AchievementService::sync_plugin_catalog(pool, &registry).await?;
+
+        DomainRepository::notify_registry_reload(pool, RELOAD_CHANNEL, version).await?;
+
+        tracing::info!(
+            version = registry.version(),
+            plugins = registry.count(),
+            achievements,
+            "Plugin registry reloaded"
+        );
+
+        Ok(registry)
+    }
+
+    /// Adopter une version rechargée par une autre instance (ignorée si déjà à jour)
+    pub async fn reload_to(&self, pool: &PgPool, version: u64) -> Result<(), AppError> {
+        let _guard = self.reload_lock.lock().await;
+
+        if version <= self.current().version() {
+            return Ok(());
+        }
+
+        let registry = self.rebuild(pool, version).await?;
+        tracing::info!(
+            version = registry.version(),
+            plugins = registry.count(),
+            "Plugin registry reloaded by another instance"
+        );
+        Ok(())
+    }
+
+    /// Reconstruire le registry : plugins natifs, modules WASM et domaines déclaratifs,
+    /// limités aux domaines actifs de la table `domains`
+    async fn rebuild(&self, pool: &PgPool, version: u64) -> Result<Arc<PluginRegistry>, AppError> {
+        let domains = DomainRepository::find_all_active(pool).await?;
+        let active: HashSet<&str> = domains.iter().map(|d| d.name.as_str()).collect();
+
+        let previous = self.current();
+        let mut registry = PluginRegistry::with_version(version);
+
+        for plugin in &self.native {
+            registry.register(plugin.clone());
+        }
+
+        registry.load_wasm_dir(self.wasm_runtime.clone(), &self.plugins_dir);
+
+        for domain in &domains {
+            if registry.has_domain(&domain.name) {
+                continue;
+            }
+            match ConfigurablePlugin::from_domain(domain) {
+                Ok(plugin) => registry.register(Arc::new(plugin)),
+                Err(e) => tracing::error!(
+                    domain = %domain.name,
+                    error = %e,
+                    "Invalid domains.config, domain skipped"
+                ),
+            }
+        }
+
+        registry.retain(|domain| active.contains(domain));
+
+        let registry = Arc::new(registry);
+        self.current.store(registry.clone());
+
+        // Conserver les versions encore utilisées par des sessions en cours
+        let in_use: HashSet<u64> = SessionRepository::find_active_plugin_versions(pool)
+            .await?
+            .into_iter()
+            .map(|v| v as u64)
+            .collect();
+        {
+            let mut pinned = self.pinned.write().expect("plugin registry lock poisoned");
+            pinned.insert(previous.version(), previous);
+            pinned.retain(|version, _| in_use.contains(version));
+        }
+
+        Ok(registry)
+    }
+}
+
+/// Tâche de fond : applique les rechargements publiés par les autres instances
+pub fn spawn_reload_listener(manager: Arc<PluginManager>, pool: PgPool) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            if let Err(e) = listen_for_reloads(&manager, &pool).await {
+                tracing::error!(error = ?e, "Plugin reload listener failed");
+            }
+            tokio::time::sleep(LISTENER_RETRY_DELAY).await;
+        }
+    })
+}
+
+async fn listen_for_reloads(manager: &PluginManager, pool: &PgPool) -> Result<(), AppError> {
+    let mut listener = PgListener::connect_with(pool).await?;
+    listener.listen(RELOAD_CHANNEL).await?;
+
+    // Rechargements publiés avant l'écoute (ou pendant une coupure)
+    let latest = DomainRepository::last_registry_version(pool).await?;
+    manager.reload_to(pool, latest as u64).await?;
+
+    loop {
+        match listener.try_recv().await? {
+            Some(notification) => match notification.payload().parse::<u64>() {
+                Ok(version) => manager.reload_to(pool, version).await?,
+                Err(_) => tracing::warn!(
+                    payload = notification.payload(),
+                    "Invalid plugin reload notification ignored"
+                ),
+            },
+            None => {
+                // Connexion rétablie : les notifications perdues ne sont pas rejouées
+                let latest = DomainRepository::last_registry_version(pool).await?;
+                manager.reload_to(pool, latest as u64).await?;
+            }
+        }
+    }
+}
@@ -1,7 +1,25 @@
+mod association;
+mod code_route;
+mod configurable;
 mod geography;
+mod manager;
+mod multi_select;
+mod numeric;
+mod ordering;
 mod plugin_trait;
+mod question_schemas;
 mod registry;
+mod scoring;
+mod text_matching;
+mod wasm;
 
+pub use code_route::CodeRoutePlugin;
+pub use configurable::{ConfigurablePlugin, DomainConfig};
 pub use geography::GeographyPlugin;
-pub use plugin_trait::{QuizPlugin, ValidationResult};
+pub use manager::{PluginManager, spawn_reload_listener};
+pub use plugin_trait::{QuizPlugin, SessionVerdict, ValidationResult};
+pub use question_schemas::{
+    ReponseSpec, validate_question_data, validate_reponse, validate_reponses,
+};
 pub use registry::PluginRegistry;
+pub use wasm::{WasmLimits, WasmPlugin, WasmRuntime};
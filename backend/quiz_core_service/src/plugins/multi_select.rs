@@ -0,0 +1,114 @@
+/// Barème d'un QCM à choix multiples
+/// Configurable via `question_data.scoring`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultiSelectScoring {
+    /// Tout ou rien : toutes les bonnes réponses, aucune mauvaise
+    AllOrNothing,
+    /// Part des options correctement cochées / décochées
+    Proportional,
+    /// Chaque mauvaise réponse cochée annule une bonne réponse
+    Penalized,
+}
+
+impl MultiSelectScoring {
+    /// Lire le barème depuis `question_data` (tout ou rien par défaut)
+    pub fn from_question_data(question_data: &serde_json::Value) -> Self {
+        match question_data.get("scoring").and_then(|v| v.as_str()) {
+            Some("proportional") => Self::Proportional,
+            Some("penalized") => Self::Penalized,
+            _ => Self::AllOrNothing,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::AllOrNothing => "all_or_nothing",
+            Self::Proportional => "proportional",
+            Self::Penalized => "penalized",
+        }
+    }
+}
+
+/// Décompte d'une sélection par rapport aux options de la question
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectionCount {
+    pub total_correct: usize,
+    pub total_wrong: usize,
+    pub correct_selected: usize,
+    pub wrong_selected: usize,
+}
+
+impl SelectionCount {
+    /// Score (0.0 à 1.0) selon le barème
+    pub fn score(&self, scoring: MultiSelectScoring) -> f32 {
+        if self.total_correct == 0 {
+            return if self.wrong_selected == 0 { 1.0 } else { 0.0 };
+        }
+
+        match scoring {
+            MultiSelectScoring::AllOrNothing => {
+                if self.correct_selected == self.total_correct && self.wrong_selected == 0 {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            MultiSelectScoring::Proportional => {
+                let well_classified =
+                    self.correct_selected + (self.total_wrong - self.wrong_selected);
+                well_classified as f32 / (self.total_correct + self.total_wrong) as f32
+            }
+            MultiSelectScoring::Penalized => {
+                self.correct_selected.saturating_sub(self.wrong_selected) as f32
+                    / self.total_correct as f32
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2 bonnes réponses sur 5 options, 1 bonne et 1 mauvaise cochées
+    const HALF_RIGHT: SelectionCount = SelectionCount {
+        total_correct: 2,
+        total_wrong: 3,
+        correct_selected: 1,
+        wrong_selected: 1,
+    };
+
+    #[test]
+    fn test_perfect_selection() {
+        let perfect = SelectionCount {
+            correct_selected: 2,
+            wrong_selected: 0,
+            ..HALF_RIGHT
+        };
+        assert_eq!(perfect.score(MultiSelectScoring::AllOrNothing), 1.0);
+        assert_eq!(perfect.score(MultiSelectScoring::Proportional), 1.0);
+        assert_eq!(perfect.score(MultiSelectScoring::Penalized), 1.0);
+    }
+
+    #[test]
+    fn test_partial_selection() {
+        assert_eq!(HALF_RIGHT.score(MultiSelectScoring::AllOrNothing), 0.0);
+        // 1 bonne cochée + 2 mauvaises non cochées sur 5
+        assert_eq!(HALF_RIGHT.score(MultiSelectScoring::Proportional), 0.6);
+        assert_eq!(HALF_RIGHT.score(MultiSelectScoring::Penalized), 0.0);
+    }
+
+    #[test]
+    fn test_scoring_from_question_data() {
+        let data = serde_json::json!({"multi_select": true, "scoring": "penalized"});
+        assert_eq!(
+            MultiSelectScoring::from_question_data(&data),
+            MultiSelectScoring::Penalized
+        );
+        let data = serde_json::json!({"multi_select": true});
+        assert_eq!(
+            MultiSelectScoring::from_question_data(&data),
+            MultiSelectScoring::AllOrNothing
+        );
+    }
+}
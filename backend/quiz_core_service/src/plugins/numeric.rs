@@ -0,0 +1,277 @@
+/// Facteur de décroissance par défaut : plus aucun point à `tolérance × 3`
+const DEFAULT_PARTIAL_RANGE_FACTOR: f64 = 3.0;
+
+/// Unités reconnues : (alias, dimension, facteur vers l'unité de base)
+const UNITS: &[(&str, &str, f64)] = &[
+    // Longueurs (base : mètre)
+    ("m", "longueur", 1.0),
+    ("metre", "longueur", 1.0),
+    ("metres", "longueur", 1.0),
+    ("mètre", "longueur", 1.0),
+    ("mètres", "longueur", 1.0),
+    ("cm", "longueur", 0.01),
+    ("km", "longueur", 1000.0),
+    ("kilometre", "longueur", 1000.0),
+    ("kilometres", "longueur", 1000.0),
+    ("kilomètre", "longueur", 1000.0),
+    ("kilomètres", "longueur", 1000.0),
+    ("ft", "longueur", 0.3048),
+    ("pied", "longueur", 0.3048),
+    ("pieds", "longueur", 0.3048),
+    ("mi", "longueur", 1609.344),
+    // Surfaces (base : m²)
+    ("m2", "surface", 1.0),
+    ("m²", "surface", 1.0),
+    ("ha", "surface", 10_000.0),
+    ("km2", "surface", 1_000_000.0),
+    ("km²", "surface", 1_000_000.0),
+];
+
+/// Retrouver une unité par alias (insensible à la casse)
+fn find_unit(alias: &str) -> Option<(&'static str, f64)> {
+    let alias = alias.trim().to_lowercase();
+    UNITS
+        .iter()
+        .find(|(name, _, _)| *name == alias)
+        .map(|(_, dimension, factor)| (*dimension, *factor))
+}
+
+/// Saisie numérique analysée : "4 808 m" → (4808.0, Some("m"))
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedNumber {
+    pub value: f64,
+    pub unit: Option<String>,
+}
+
+/// Analyser une saisie : séparateurs de milliers (espaces), virgule décimale, unité en suffixe
+pub fn parse_number(input: &str) -> Option<ParsedNumber> {
+    let input = input.trim();
+    let split = input
+        .char_indices()
+        .find(|(_, c)| {
+            !(c.is_ascii_digit() || c.is_whitespace() || matches!(c, ',' | '.' | '-' | '+'))
+        })
+        .map(|(i, _)| i)
+        .unwrap_or(input.len());
+
+    let (number, unit) = input.split_at(split);
+    let number: String = number
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| if c == ',' { '.' } else { c })
+        .collect();
+
+    let value = number.parse::<f64>().ok()?;
+    let unit = unit.trim();
+
+    Some(ParsedNumber {
+        value,
+        unit: (!unit.is_empty()).then(|| unit.to_string()),
+    })
+}
+
+/// Paramètres d'une question "numerique", lus depuis `question_data` :
+/// - `expected_value` : valeur attendue (dans `unit`)
+/// - `unit` : unité de la valeur attendue (optionnelle, ex: "m")
+/// - `accepted_units` : unités acceptées (par défaut : toutes celles de la même dimension)
+/// - `absolute_tolerance` / `relative_tolerance` : écart accepté (le plus large l'emporte)
+/// - `partial_range` : écart au-delà duquel plus aucun point (par défaut tolérance × 3)
+#[derive(Debug, Clone)]
+pub struct NumericSpec {
+    pub expected_value: f64,
+    pub unit: Option<String>,
+    pub accepted_units: Option<Vec<String>>,
+    pub absolute_tolerance: f64,
+    pub relative_tolerance: f64,
+    pub partial_range: Option<f64>,
+}
+
+/// Résultat de l'évaluation d'une saisie numérique
+#[derive(Debug, Clone, PartialEq)]
+pub enum NumericOutcome {
+    /// Saisie évaluée : valeur convertie dans l'unité de la question, écart et score
+    Scored { value: f64, error: f64, score: f32 },
+    /// Saisie illisible
+    InvalidNumber,
+    /// Unité inconnue, non acceptée ou incompatible
+    InvalidUnit(String),
+}
+
+impl NumericSpec {
+    pub fn from_question_data(question_data: &serde_json::Value) -> Option<Self> {
+        let number = |key: &str| question_data.get(key).and_then(|v| v.as_f64());
+
+        Some(Self {
+            expected_value: number("expected_value")?,
+            unit: question_data
+                .get("unit")
+                .and_then(|v| v.as_str())
+                .map(str::to_string),
+            accepted_units: question_data
+                .get("accepted_units")
+                .and_then(|v| v.as_array())
+                .map(|units| {
+                    units
+                        .iter()
+                        .filter_map(|u| u.as_str().map(str::to_lowercase))
+                        .collect()
+                }),
+            absolute_tolerance: number("absolute_tolerance").unwrap_or(0.0).abs(),
+            relative_tolerance: number("relative_tolerance").unwrap_or(0.0).abs(),
+            partial_range: number("partial_range").map(f64::abs),
+        })
+    }
+
+    /// Écart accepté pour un crédit total
+    pub fn tolerance(&self) -> f64 {
+        self.absolute_tolerance
+            .max(self.relative_tolerance * self.expected_value.abs())
+    }
+
+    /// Évaluer une saisie brute
+    pub fn evaluate(&self, input: &str) -> NumericOutcome {
+        let parsed = match parse_number(input) {
+            Some(parsed) => parsed,
+            None => return NumericOutcome::InvalidNumber,
+        };
+
+        let value = match self.convert(&parsed) {
+            Ok(value) => value,
+            Err(unit) => return NumericOutcome::InvalidUnit(unit),
+        };
+
+        let error = (value - self.expected_value).abs();
+        NumericOutcome::Scored {
+            value,
+            error,
+            score: self.score_for_error(error),
+        }
+    }
+
+    /// Convertir la saisie dans l'unité de la question
+    fn convert(&self, parsed: &ParsedNumber) -> Result<f64, String> {
+        let input_unit = match &parsed.unit {
+            // Pas d'unité saisie : on suppose celle de la question
+            None => return Ok(parsed.value),
+            Some(unit) => unit,
+        };
+
+        if let Some(accepted) = &self.accepted_units
+            && !accepted.contains(&input_unit.to_lowercase())
+        {
+            return Err(input_unit.clone());
+        }
+
+        let expected_unit = self.unit.as_deref().and_then(find_unit);
+        match (find_unit(input_unit), expected_unit) {
+            (Some((dimension, factor)), Some((expected_dimension, expected_factor)))
+                if dimension == expected_dimension =>
+            {
+                Ok(parsed.value * factor / expected_factor)
+            }
+            _ => Err(input_unit.clone()),
+        }
+    }
+
+    /// Score : 1.0 dans la tolérance, puis décroissance linéaire jusqu'à `partial_range`
+    fn score_for_error(&self, error: f64) -> f32 {
+        let tolerance = self.tolerance();
+        if error <= tolerance {
+            return 1.0;
+        }
+
+        let range = self
+            .partial_range
+            .unwrap_or(tolerance * DEFAULT_PARTIAL_RANGE_FACTOR);
+        if error >= range {
+            return 0.0;
+        }
+
+        (1.0 - (error - tolerance) / (range - tolerance)) as f32
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn mont_blanc() -> NumericSpec {
+        NumericSpec::from_question_data(&json!({
+            "text": "Altitude du Mont Blanc ?",
+            "expected_value": 4808,
+            "unit": "m",
+            "absolute_tolerance": 10,
+            "partial_range": 200
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_number() {
+        assert_eq!(
+            parse_number("4 808 m"),
+            Some(ParsedNumber {
+                value: 4808.0,
+                unit: Some("m".to_string())
+            })
+        );
+        assert_eq!(parse_number("843").unwrap().value, 843.0);
+        assert_eq!(parse_number("4,808 km").unwrap().value, 4.808);
+        assert_eq!(parse_number("environ"), None);
+    }
+
+    #[test]
+    fn test_exact_and_tolerance() {
+        let spec = mont_blanc();
+        assert!(
+            matches!(spec.evaluate("4808"), NumericOutcome::Scored { score, .. } if score == 1.0)
+        );
+        assert!(
+            matches!(spec.evaluate("4 800 m"), NumericOutcome::Scored { score, .. } if score == 1.0)
+        );
+    }
+
+    #[test]
+    fn test_unit_conversion() {
+        let spec = mont_blanc();
+        assert!(
+            matches!(spec.evaluate("4,808 km"), NumericOutcome::Scored { score, .. } if score == 1.0)
+        );
+        // 15 774 ft ≈ 4 808 m
+        assert!(
+            matches!(spec.evaluate("15774 ft"), NumericOutcome::Scored { score, .. } if score == 1.0)
+        );
+        assert_eq!(
+            spec.evaluate("4808 kg"),
+            NumericOutcome::InvalidUnit("kg".to_string())
+        );
+    }
+
+    #[test]
+    fn test_partial_credit_decay() {
+        let spec = mont_blanc();
+        match spec.evaluate("4713") {
+            NumericOutcome::Scored { score, .. } => assert!((score - 0.55).abs() < 0.01),
+            other => panic!("unexpected outcome: {:?}", other),
+        }
+        assert!(
+            matches!(spec.evaluate("4000"), NumericOutcome::Scored { score, .. } if score == 0.0)
+        );
+    }
+
+    #[test]
+    fn test_relative_tolerance_without_unit() {
+        let spec = NumericSpec::from_question_data(&json!({
+            "expected_value": 843,
+            "relative_tolerance": 0.0
+        }))
+        .unwrap();
+        assert!(
+            matches!(spec.evaluate("843"), NumericOutcome::Scored { score, .. } if score == 1.0)
+        );
+        assert!(
+            matches!(spec.evaluate("842"), NumericOutcome::Scored { score, .. } if score == 0.0)
+        );
+    }
+}
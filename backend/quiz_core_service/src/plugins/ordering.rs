@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Métrique de notation d'une question "ordre"
+/// Configurable via `question_data.scoring`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderingMetric {
+    /// Tout ou rien : l'ordre doit être exactement le bon
+    Exact,
+    /// Plus longue sous-séquence dans le bon ordre / nombre d'éléments
+    LongestSubsequence,
+    /// 1 - (paires inversées / nombre total de paires)
+    KendallTau,
+}
+
+impl OrderingMetric {
+    /// Lire la métrique depuis `question_data` (Kendall tau par défaut)
+    pub fn from_question_data(question_data: &serde_json::Value) -> Self {
+        match question_data.get("scoring").and_then(|v| v.as_str()) {
+            Some("exact") => Self::Exact,
+            Some("longest_subsequence") => Self::LongestSubsequence,
+            _ => Self::KendallTau,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Exact => "exact",
+            Self::LongestSubsequence => "longest_subsequence",
+            Self::KendallTau => "kendall_tau",
+        }
+    }
+}
+
+/// Score (0.0 à 1.0) d'un ordre soumis par rapport à l'ordre attendu
+/// Les deux listes doivent contenir les mêmes éléments
+pub fn score_ordering<T: Eq + Hash>(
+    expected: &[T],
+    submitted: &[T],
+    metric: OrderingMetric,
+) -> f32 {
+    if expected.len() <= 1 {
+        return if expected == submitted { 1.0 } else { 0.0 };
+    }
+
+    // Position attendue de chaque élément soumis
+    let positions: HashMap<&T, usize> = expected.iter().enumerate().map(|(i, e)| (e, i)).collect();
+    let ranks: Vec<usize> = submitted
+        .iter()
+        .filter_map(|s| positions.get(s).copied())
+        .collect();
+
+    match metric {
+        OrderingMetric::Exact => {
+            if expected == submitted {
+                1.0
+            } else {
+                0.0
+            }
+        }
+        OrderingMetric::LongestSubsequence => {
+            longest_increasing_subsequence(&ranks) as f32 / expected.len() as f32
+        }
+        OrderingMetric::KendallTau => {
+            let total_pairs = expected.len() * (expected.len() - 1) / 2;
+            let mut discordant = 0;
+            for i in 0..ranks.len() {
+                for j in (i + 1)..ranks.len() {
+                    if ranks[i] > ranks[j] {
+                        discordant += 1;
+                    }
+                }
+            }
+            1.0 - discordant as f32 / total_pairs as f32
+        }
+    }
+}
+
+/// Longueur de la plus longue sous-séquence strictement croissante (patience sorting)
+fn longest_increasing_subsequence(values: &[usize]) -> usize {
+    let mut tails: Vec<usize> = Vec::new();
+    for &value in values {
+        match tails.binary_search(&value) {
+            Ok(_) => {}
+            Err(index) if index == tails.len() => tails.push(value),
+            Err(index) => tails[index] = value,
+        }
+    }
+    tails.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPECTED: [char; 4] = ['a', 'b', 'c', 'd'];
+
+    #[test]
+    fn test_perfect_order() {
+        for metric in [
+            OrderingMetric::Exact,
+            OrderingMetric::LongestSubsequence,
+            OrderingMetric::KendallTau,
+        ] {
+            assert_eq!(score_ordering(&EXPECTED, &EXPECTED, metric), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_one_swap() {
+        let submitted = ['a', 'c', 'b', 'd'];
+        assert_eq!(
+            score_ordering(&EXPECTED, &submitted, OrderingMetric::Exact),
+            0.0
+        );
+        assert_eq!(
+            score_ordering(&EXPECTED, &submitted, OrderingMetric::LongestSubsequence),
+            0.75
+        );
+        // 1 paire inversée sur 6
+        let tau = score_ordering(&EXPECTED, &submitted, OrderingMetric::KendallTau);
+        assert!((tau - 5.0 / 6.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_reversed_order() {
+        let submitted = ['d', 'c', 'b', 'a'];
+        assert_eq!(
+            score_ordering(&EXPECTED, &submitted, OrderingMetric::KendallTau),
+            0.0
+        );
+        assert_eq!(
+            score_ordering(&EXPECTED, &submitted, OrderingMetric::LongestSubsequence),
+            0.25
+        );
+    }
+
+    #[test]
+    fn test_metric_from_question_data() {
+        let data = serde_json::json!({"text": "Classez", "scoring": "exact"});
+        assert_eq!(
+            OrderingMetric::from_question_data(&data),
+            OrderingMetric::Exact
+        );
+        let data = serde_json::json!({"text": "Classez"});
+        assert_eq!(
+            OrderingMetric::from_question_data(&data),
+            OrderingMetric::KendallTau
+        );
+    }
+}
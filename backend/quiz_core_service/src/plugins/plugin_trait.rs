@@ -1,8 +1,24 @@
+use std::collections::{BTreeMap, HashMap};
+
 use async_trait::async_trait;
+use serde::Serialize;
+use serde_json::json;
 use shared::AppError;
 use sqlx::PgPool;
+use uuid::Uuid;
 
-use crate::{dto::session_dto::SubmitAnswerRequest, models::Question};
+use super::association::{self, AssociationItem, Colonne};
+use super::multi_select::{MultiSelectScoring, SelectionCount};
+use super::numeric::{NumericOutcome, NumericSpec};
+use super::ordering::{self, OrderingMetric};
+use super::question_schemas::{self, DEFAULT_QUESTION_TYPES};
+use super::scoring::{DEFAULT_SCORING, default_score};
+use super::text_matching::{self, TextMatch, TextNormalizer, TypoTolerance};
+use crate::{
+    dto::session_dto::SubmitAnswerRequest,
+    models::{Question, Quiz, ReponseUtilisateur},
+    services::achievements::AchievementDefinition,
+};
 
 /// Résultat de validation d'une réponse
 #[derive(Debug, Clone)]
@@ -11,6 +27,7 @@ pub struct ValidationResult {
     pub feedback_message: String,
     pub explanation: Option<String>,
     pub partial_score: Option<f32>, // Pour ordre/association (0.0 à 1.0)
+    pub metadata: serde_json::Value, // Détails stockés dans reponses_utilisateur.metadata
 }
 
 impl ValidationResult {
@@ -20,6 +37,7 @@ impl ValidationResult {
             feedback_message: message.into(),
             explanation: None,
             partial_score: None,
+            metadata: json!({}),
         }
     }
 
@@ -29,6 +47,7 @@ impl ValidationResult {
             feedback_message: message.into(),
             explanation: None,
             partial_score: None,
+            metadata: json!({}),
         }
     }
 
@@ -38,13 +57,43 @@ impl ValidationResult {
             feedback_message: message.into(),
             explanation: None,
             partial_score: Some(score),
+            metadata: json!({}),
         }
     }
 
+    /// Saisie correcte à quelques fautes près ("presque !")
+    /// Crédit total, sauf si un score partiel est configuré pour la question
+    pub fn near_miss(expected: &str, distance: usize, partial_score: Option<f32>) -> Self {
+        let message = format!("Presque ! La bonne orthographe est : {}", expected);
+        let result = match partial_score {
+            Some(score) => Self::partial(score, message),
+            None => Self::correct(message),
+        };
+        result.with_metadata(
+            "saisie_texte",
+            json!({ "near_miss": true, "expected": expected, "distance": distance }),
+        )
+    }
+
     pub fn with_explanation(mut self, explanation: impl Into<String>) -> Self {
         self.explanation = Some(explanation.into());
         self
     }
+
+    pub fn with_metadata(mut self, key: &str, value: serde_json::Value) -> Self {
+        if let Some(map) = self.metadata.as_object_mut() {
+            map.insert(key.to_string(), value);
+        }
+        self
+    }
+}
+
+/// Verdict de fin de session (réussite/échec), stocké dans `sessions_quiz.metadata`
+#[derive(Debug, Clone, Serialize)]
+pub struct SessionVerdict {
+    pub passed: bool,
+    pub message: String,
+    pub details: serde_json::Value,
 }
 
 /// Trait que chaque plugin de domaine doit implémenter
@@ -74,60 +123,64 @@ pub trait QuizPlugin: Send + Sync {
 
     /// Calculer le score final avec bonifications/pénalités
     /// Implémentation par défaut fournie, peut être override
-    fn calculate_score(
+    /// `hint_used` : l'indice de la question a été consulté (chaque domaine applique sa pénalité)
+    async fn calculate_score(
         &self,
         base_points: i32,
         validation: &ValidationResult,
         time_spent: i32,
         time_limit: Option<i32>,
         streak_count: i32,
+        hint_used: bool,
     ) -> i32 {
-        if !validation.is_correct && validation.partial_score.is_none() {
-            return 0;
-        }
+        default_score(
+            &DEFAULT_SCORING,
+            base_points,
+            validation,
+            time_spent,
+            time_limit,
+            streak_count,
+            hint_used,
+        )
+    }
 
-        let mut points = base_points as f32;
+    /// Badge de vitesse (optionnel, override si logique spécifique)
+    fn speed_badge(&self, time_spent: i32, time_limit: Option<i32>) -> Option<String> {
+        let (_, badge) = DEFAULT_SCORING.speed_bonus.apply(time_spent, time_limit);
+        badge.map(str::to_string)
+    }
 
-        // Score partiel (pour ordre/association)
-        if let Some(partial) = validation.partial_score {
-            points *= partial;
-        }
+    /// Schémas JSON de `question_data`, par type de question supporté
+    /// Par défaut : les types gérés par les validations du trait
+    fn question_schemas(&self) -> BTreeMap<String, serde_json::Value> {
+        question_schemas::default_schemas(DEFAULT_QUESTION_TYPES)
+    }
 
-        // Bonus vitesse
-        if let Some(limit) = time_limit {
-            let ratio = time_spent as f32 / limit as f32;
-            if ratio < 0.3 {
-                points *= 1.5; // +50% si très rapide
-            } else if ratio < 0.5 {
-                points *= 1.25; // +25% si rapide
-            } else if ratio > 0.9 {
-                points *= 0.75; // -25% si trop lent
-            }
-        }
+    /// Explication obligatoire à la création/modification d'une question du domaine
+    fn explanation_required(&self) -> bool {
+        false
+    }
 
-        // Bonus streak
-        if streak_count >= 3 {
-            let streak_bonus = ((streak_count - 2) * 10).min(50) as f32 / 100.0;
-            points += base_points as f32 * streak_bonus;
-        }
+    /// Verdict de fin de session (None = pas de notion de réussite pour ce domaine)
+    /// `nb_questions` : nombre de questions réellement tirées pour la session
+    fn session_verdict(
+        &self,
+        _quiz: &Quiz,
+        _nb_questions: usize,
+        _answers: &[ReponseUtilisateur],
+    ) -> Option<SessionVerdict> {
+        None
+    }
 
-        points.round() as i32
+    /// 🆕 Succès propres au domaine, ajoutés au catalogue au chargement des plugins
+    /// (évalués sur les seules sessions du domaine)
+    fn achievements(&self) -> Vec<AchievementDefinition> {
+        Vec::new()
     }
 
-    /// Badge de vitesse (optionnel, override si logique spécifique)
-    fn speed_badge(&self, time_spent: i32, time_limit: Option<i32>) -> Option<String> {
-        time_limit.and_then(|limit| {
-            let ratio = time_spent as f32 / limit as f32;
-            if ratio < 0.3 {
-                Some("⚡ Éclair !".to_string())
-            } else if ratio < 0.5 {
-                Some("🚀 Rapide !".to_string())
-            } else if ratio > 0.9 {
-                Some("🐢 Prends ton temps".to_string())
-            } else {
-                None
-            }
-        })
+    /// Règles de normalisation des saisies texte (override si règles spécifiques)
+    fn text_normalizer(&self) -> TextNormalizer {
+        TextNormalizer::default()
     }
 
     /// Seed initial des données du domaine
@@ -147,17 +200,22 @@ pub trait QuizPlugin: Send + Sync {
         question: &Question,
         answer: &SubmitAnswerRequest,
     ) -> Result<ValidationResult, AppError> {
+        // QCM à choix multiples ("question_data.multi_select" ou sélection multiple soumise)
+        let multi_select = question
+            .question_data
+            .get("multi_select")
+            .and_then(|v| v.as_bool())
+            .unwrap_or(false);
+        if multi_select || answer.reponse_ids.is_some() {
+            return self.validate_qcm_multiple(pool, question, answer).await;
+        }
+
         // ✅ AJOUTER : Gérer le timeout (pas de réponse)
         let reponse_id = match &answer.reponse_id {
             Some(id) => id,
             None => {
                 // Timeout : aucune réponse sélectionnée
-                return Ok(ValidationResult {
-                    is_correct: false,
-                    feedback_message: "Temps écoulé - Aucune réponse".to_string(),
-                    explanation: None,
-                    partial_score: None,
-                });
+                return Ok(ValidationResult::incorrect("Temps écoulé - Aucune réponse"));
             }
         };
 
@@ -183,6 +241,79 @@ pub trait QuizPlugin: Send + Sync {
         }
     }
 
+    /// Validation d'un QCM à choix multiples, barème selon `question_data.scoring`
+    async fn validate_qcm_multiple(
+        &self,
+        pool: &PgPool,
+        question: &Question,
+        answer: &SubmitAnswerRequest,
+    ) -> Result<ValidationResult, AppError> {
+        let mut selected: Vec<Uuid> = match (&answer.reponse_ids, answer.reponse_id) {
+            (Some(ids), _) => ids.clone(),
+            (None, Some(id)) => vec![id],
+            (None, None) => Vec::new(),
+        };
+        selected.sort();
+        selected.dedup();
+
+        if selected.is_empty() {
+            // Timeout : aucune réponse sélectionnée
+            return Ok(ValidationResult::incorrect("Temps écoulé - Aucune réponse"));
+        }
+
+        let options: HashMap<Uuid, bool> = sqlx::query_as::<_, (Uuid, bool)>(
+            "SELECT id, is_correct FROM reponses WHERE question_id = $1",
+        )
+        .bind(question.id)
+        .fetch_all(pool)
+        .await?
+        .into_iter()
+        .collect();
+
+        let mut count = SelectionCount {
+            total_correct: options.values().filter(|c| **c).count(),
+            total_wrong: options.values().filter(|c| !**c).count(),
+            correct_selected: 0,
+            wrong_selected: 0,
+        };
+        for id in &selected {
+            match options.get(id) {
+                Some(true) => count.correct_selected += 1,
+                Some(false) => count.wrong_selected += 1,
+                None => return Err(AppError::NotFound("Réponse non trouvée".to_string())),
+            }
+        }
+
+        let scoring = MultiSelectScoring::from_question_data(&question.question_data);
+        let score = count.score(scoring);
+        let message = format!(
+            "{}/{} bonnes réponses sélectionnées, {} erreur(s)",
+            count.correct_selected, count.total_correct, count.wrong_selected
+        );
+
+        let result = if score >= 1.0 {
+            ValidationResult::correct("Bonne réponse !")
+        } else if score > 0.0 {
+            ValidationResult::partial(score, message)
+        } else {
+            ValidationResult::incorrect(message)
+        };
+
+        Ok(result
+            .with_explanation(question.explanation.clone().unwrap_or_default())
+            .with_metadata(
+                "qcm",
+                json!({
+                    "selected": selected,
+                    "correct_selected": count.correct_selected,
+                    "wrong_selected": count.wrong_selected,
+                    "total_correct": count.total_correct,
+                    "scoring": scoring.as_str(),
+                    "score": score,
+                }),
+            ))
+    }
+
     /// Validation spécifique pour le type "vrai_faux"
     async fn validate_vrai_faux(
         &self,
@@ -194,12 +325,7 @@ pub trait QuizPlugin: Send + Sync {
             Some(id) => id,
             None => {
                 // Timeout : aucune réponse sélectionnée
-                return Ok(ValidationResult {
-                    is_correct: false,
-                    feedback_message: "Temps écoulé - Aucune réponse".to_string(),
-                    explanation: None,
-                    partial_score: None,
-                });
+                return Ok(ValidationResult::incorrect("Temps écoulé - Aucune réponse"));
             }
         };
         // Reste identique
@@ -233,26 +359,229 @@ pub trait QuizPlugin: Send + Sync {
             .as_ref()
             .ok_or_else(|| AppError::BadRequest("valeur_saisie requise".to_string()))?;
 
-        // Vérifier si la valeur saisie correspond à une réponse correcte
-        let is_correct: bool = sqlx::query_scalar(
+        // Réponses acceptées (variantes comprises)
+        let accepted: Vec<String> = sqlx::query_scalar(
             r#"
-            SELECT EXISTS(
-                SELECT 1 FROM reponses
-                WHERE question_id = $1
-                AND LOWER(valeur) = LOWER($2)
-                AND is_correct = true
-            )
+            SELECT valeur
+            FROM reponses
+            WHERE question_id = $1
+            AND is_correct = true
+            AND valeur IS NOT NULL
             "#,
         )
         .bind(question.id)
-        .bind(valeur_saisie)
-        .fetch_one(pool)
+        .fetch_all(pool)
         .await?;
 
-        if is_correct {
-            Ok(ValidationResult::correct("Bonne réponse !"))
+        let tolerance = TypoTolerance::from_question_data(&question.question_data);
+        let text_match = text_matching::match_answer(
+            &self.text_normalizer(),
+            &tolerance,
+            valeur_saisie,
+            &accepted,
+        );
+
+        match text_match {
+            TextMatch::Exact(_) => Ok(ValidationResult::correct("Bonne réponse !")),
+            TextMatch::NearMiss { expected, distance } => Ok(ValidationResult::near_miss(
+                &expected,
+                distance,
+                tolerance.partial_score,
+            )),
+            TextMatch::NoMatch => Ok(ValidationResult::incorrect("Mauvaise réponse")),
+        }
+    }
+
+    /// Validation spécifique pour le type "ordre"
+    /// L'ordre attendu est celui de `reponses.ordre` (croissant)
+    async fn validate_ordre(
+        &self,
+        pool: &PgPool,
+        question: &Question,
+        answer: &SubmitAnswerRequest,
+    ) -> Result<ValidationResult, AppError> {
+        let submitted = match &answer.reponse_ids {
+            Some(ids) => ids,
+            None => {
+                // Timeout : aucun ordre proposé
+                return Ok(ValidationResult::incorrect("Temps écoulé - Aucune réponse"));
+            }
+        };
+
+        let expected: Vec<Uuid> =
+            sqlx::query_scalar("SELECT id FROM reponses WHERE question_id = $1 ORDER BY ordre ASC")
+                .bind(question.id)
+                .fetch_all(pool)
+                .await?;
+
+        // L'ordre soumis doit être une permutation des réponses de la question
+        let mut sorted_submitted = submitted.clone();
+        let mut sorted_expected = expected.clone();
+        sorted_submitted.sort();
+        sorted_expected.sort();
+        if sorted_submitted != sorted_expected {
+            return Err(AppError::BadRequest(
+                "reponse_ids doit contenir chaque réponse de la question exactement une fois"
+                    .to_string(),
+            ));
+        }
+
+        let metric = OrderingMetric::from_question_data(&question.question_data);
+        let score = ordering::score_ordering(&expected, submitted, metric);
+
+        let result = if score >= 1.0 {
+            ValidationResult::correct("Bonne réponse !")
+        } else if score > 0.0 {
+            ValidationResult::partial(score, "Ordre partiellement correct")
         } else {
-            Ok(ValidationResult::incorrect("Mauvaise réponse"))
+            ValidationResult::incorrect("Mauvais ordre")
+        };
+
+        Ok(result
+            .with_explanation(question.explanation.clone().unwrap_or_default())
+            .with_metadata(
+                "ordre",
+                json!({
+                    "submitted": submitted,
+                    "expected": expected,
+                    "metric": metric.as_str(),
+                    "score": score,
+                }),
+            ))
+    }
+
+    /// Validation spécifique pour le type "association"
+    /// Chaque réponse porte `metadata.colonne` ("gauche"/"droite") et `metadata.paire`
+    /// (clé commune aux deux éléments d'une même paire)
+    async fn validate_association(
+        &self,
+        pool: &PgPool,
+        question: &Question,
+        answer: &SubmitAnswerRequest,
+    ) -> Result<ValidationResult, AppError> {
+        let paires = match &answer.paires {
+            Some(paires) => paires,
+            None => {
+                // Timeout : aucune paire proposée
+                return Ok(ValidationResult::incorrect("Temps écoulé - Aucune réponse"));
+            }
+        };
+
+        let rows: Vec<(Uuid, serde_json::Value)> =
+            sqlx::query_as("SELECT id, metadata FROM reponses WHERE question_id = $1")
+                .bind(question.id)
+                .fetch_all(pool)
+                .await?;
+
+        let items: HashMap<Uuid, AssociationItem> = rows
+            .into_iter()
+            .filter_map(|(id, metadata)| Some((id, AssociationItem::from_metadata(&metadata)?)))
+            .collect();
+
+        if !items.values().any(|item| item.colonne == Colonne::Gauche) {
+            return Err(AppError::InternalServerError(format!(
+                "Aucune paire définie pour la question association {}",
+                question.id
+            )));
         }
+
+        let submitted: Vec<(Uuid, Uuid)> = paires.iter().map(|p| (p.gauche, p.droite)).collect();
+        let count = association::score_pairs(&items, &submitted)
+            .map_err(|e| AppError::BadRequest(e.message().to_string()))?;
+        let (correct_pairs, total_pairs) = (count.correct, count.total);
+
+        let score = count.score();
+        let message = format!("{}/{} paires correctes", correct_pairs, total_pairs);
+
+        let result = if correct_pairs == total_pairs {
+            ValidationResult::correct("Bonne réponse !")
+        } else if correct_pairs > 0 {
+            ValidationResult::partial(score, message)
+        } else {
+            ValidationResult::incorrect(message)
+        };
+
+        Ok(result
+            .with_explanation(question.explanation.clone().unwrap_or_default())
+            .with_metadata(
+                "association",
+                json!({
+                    "submitted": paires,
+                    "correct_pairs": correct_pairs,
+                    "total_pairs": total_pairs,
+                    "score": score,
+                }),
+            ))
+    }
+
+    /// Validation spécifique pour le type "numerique"
+    /// Valeur attendue, unité et tolérances dans `question_data`
+    async fn validate_numerique(
+        &self,
+        _pool: &PgPool,
+        question: &Question,
+        answer: &SubmitAnswerRequest,
+    ) -> Result<ValidationResult, AppError> {
+        let valeur_saisie = match &answer.valeur_saisie {
+            Some(valeur) => valeur,
+            None => {
+                // Timeout : aucune valeur saisie
+                return Ok(ValidationResult::incorrect("Temps écoulé - Aucune réponse"));
+            }
+        };
+
+        let spec = NumericSpec::from_question_data(&question.question_data).ok_or_else(|| {
+            AppError::InternalServerError(format!(
+                "expected_value manquant pour la question numerique {}",
+                question.id
+            ))
+        })?;
+
+        let expected = match &spec.unit {
+            Some(unit) => format!("{} {}", spec.expected_value, unit),
+            None => spec.expected_value.to_string(),
+        };
+
+        let result = match spec.evaluate(valeur_saisie) {
+            NumericOutcome::InvalidNumber => ValidationResult::incorrect(format!(
+                "Valeur numérique invalide : {}",
+                valeur_saisie
+            )),
+            NumericOutcome::InvalidUnit(unit) => {
+                ValidationResult::incorrect(format!("Unité non reconnue : {}", unit))
+            }
+            NumericOutcome::Scored {
+                value,
+                error,
+                score,
+            } => {
+                let result = if score >= 1.0 {
+                    ValidationResult::correct("Bonne réponse !")
+                } else if score > 0.0 {
+                    ValidationResult::partial(
+                        score,
+                        format!("Presque ! La bonne réponse était : {}", expected),
+                    )
+                } else {
+                    ValidationResult::incorrect(format!(
+                        "Mauvaise réponse. La bonne réponse était : {}",
+                        expected
+                    ))
+                };
+
+                result.with_metadata(
+                    "numerique",
+                    json!({
+                        "value": value,
+                        "expected_value": spec.expected_value,
+                        "unit": spec.unit,
+                        "error": error,
+                        "score": score,
+                    }),
+                )
+            }
+        };
+
+        Ok(result.with_explanation(question.explanation.clone().unwrap_or_default()))
     }
 }
@@ -0,0 +1,318 @@
+use std::collections::{BTreeMap, HashSet};
+
+use serde_json::{Value, json};
+use shared::FieldError;
+
+use super::association::{AssociationItem, Colonne};
+
+/// Types de question gérés par les validations par défaut du trait
+pub const DEFAULT_QUESTION_TYPES: &[&str] = &[
+    "qcm",
+    "vrai_faux",
+    "saisie_texte",
+    "ordre",
+    "association",
+    "numerique",
+];
+
+/// Schéma JSON d'un `question_data` : énoncé obligatoire + propriétés propres au type
+fn schema(properties: Value, required: &[&str]) -> Value {
+    let mut all_properties = json!({
+        "text": {"type": "string", "minLength": 1, "description": "Énoncé de la question"}
+    });
+    if let (Some(all), Some(extra)) = (all_properties.as_object_mut(), properties.as_object()) {
+        all.extend(extra.clone());
+    }
+
+    let mut all_required = vec!["text"];
+    all_required.extend_from_slice(required);
+
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "type": "object",
+        "properties": all_properties,
+        "required": all_required,
+    })
+}
+
+/// Schéma par défaut d'un type de question (None si le type est inconnu)
+pub fn default_schema(type_question: &str) -> Option<Value> {
+    let schema = match type_question {
+        "qcm" => schema(
+            json!({
+                "multi_select": {"type": "boolean"},
+                "scoring": {"enum": ["all_or_nothing", "proportional", "penalized"]}
+            }),
+            &[],
+        ),
+        "vrai_faux" | "association" => schema(json!({}), &[]),
+        "saisie_texte" => schema(
+            json!({
+                "typo_tolerance": {"type": "integer", "minimum": 0},
+                "typo_partial_score": {"type": "number", "minimum": 0, "maximum": 1}
+            }),
+            &[],
+        ),
+        "ordre" => schema(
+            json!({
+                "scoring": {"enum": ["exact", "longest_subsequence", "kendall_tau"]}
+            }),
+            &[],
+        ),
+        "numerique" => schema(
+            json!({
+                "expected_value": {"type": "number"},
+                "unit": {"type": "string", "minLength": 1},
+                "accepted_units": {"type": "array", "items": {"type": "string"}},
+                "absolute_tolerance": {"type": "number", "minimum": 0},
+                "relative_tolerance": {"type": "number", "minimum": 0},
+                "partial_range": {"type": "number", "exclusiveMinimum": 0}
+            }),
+            &["expected_value"],
+        ),
+        "carte_cliquable" => schema(json!({}), &[]),
+        _ => return None,
+    };
+    Some(schema)
+}
+
+/// Schémas par défaut pour une liste de types
+pub fn default_schemas(types: &[&str]) -> BTreeMap<String, Value> {
+    types
+        .iter()
+        .filter_map(|t| default_schema(t).map(|s| (t.to_string(), s)))
+        .collect()
+}
+
+/// Valider `question_data` contre un schéma, erreurs rattachées à leur champ
+pub fn validate_question_data(
+    schema: &Value,
+    question_data: &Value,
+) -> Result<(), Vec<FieldError>> {
+    let validator = jsonschema::validator_for(schema).map_err(|e| {
+        vec![FieldError {
+            field: "type_question".to_string(),
+            message: format!("Schéma invalide : {}", e),
+        }]
+    })?;
+
+    let errors: Vec<FieldError> = validator
+        .iter_errors(question_data)
+        .map(|error| {
+            let mut field = format!("question_data{}", error.instance_path);
+            if let jsonschema::error::ValidationErrorKind::Required { property } = &error.kind {
+                field = format!("{}/{}", field, property.as_str().unwrap_or_default());
+            }
+            FieldError {
+                field,
+                message: error.to_string(),
+            }
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Nombre minimum d'éléments à classer dans une question "ordre"
+pub const MIN_ORDERED_ITEMS: usize = 2;
+
+/// Réponse soumise au contrôle du jeu de réponses
+pub struct ReponseSpec<'a> {
+    pub ordre: Option<i32>,
+    pub metadata: Option<&'a Value>,
+}
+
+/// Vérifier une réponse isolée : `ordre` obligatoire pour "ordre",
+/// `metadata.colonne` ("gauche"/"droite") et `metadata.paire` pour "association"
+pub fn validate_reponse(
+    type_question: &str,
+    index: usize,
+    reponse: &ReponseSpec,
+) -> Option<FieldError> {
+    match type_question {
+        "ordre" if reponse.ordre.is_none() => Some(FieldError {
+            field: format!("reponses/{}/ordre", index),
+            message: "Position obligatoire pour un élément à classer".to_string(),
+        }),
+        "association"
+            if reponse
+                .metadata
+                .and_then(AssociationItem::from_metadata)
+                .is_none() =>
+        {
+            Some(FieldError {
+                field: format!("reponses/{}/metadata", index),
+                message: "metadata.colonne (\"gauche\"/\"droite\") et metadata.paire obligatoires"
+                    .to_string(),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Vérifier le jeu complet de réponses d'une question "ordre" ou "association"
+///
+/// Les réponses sont créées après la question (`/questions/:id/reponses`) :
+/// `QuestionService::validate_question` ne peut pas les voir, le contrôle est fait
+/// par `ReponseService` (chaque réponse, puis le jeu complet lors d'une création en masse)
+pub fn validate_reponses(
+    type_question: &str,
+    reponses: &[ReponseSpec],
+) -> Result<(), Vec<FieldError>> {
+    let mut errors: Vec<FieldError> = reponses
+        .iter()
+        .enumerate()
+        .filter_map(|(index, reponse)| validate_reponse(type_question, index, reponse))
+        .collect();
+    let mut set_error = |message: String| {
+        errors.push(FieldError {
+            field: "reponses".to_string(),
+            message,
+        })
+    };
+
+    match type_question {
+        "ordre" => {
+            if reponses.len() < MIN_ORDERED_ITEMS {
+                set_error(format!(
+                    "Au moins {} éléments à classer (reçu : {})",
+                    MIN_ORDERED_ITEMS,
+                    reponses.len()
+                ));
+            }
+            let mut positions = HashSet::new();
+            if reponses
+                .iter()
+                .filter_map(|r| r.ordre)
+                .any(|ordre| !positions.insert(ordre))
+            {
+                set_error("Deux éléments ne peuvent pas occuper la même position".to_string());
+            }
+        }
+        "association" => {
+            // Clé de paire -> (éléments gauche, éléments droite)
+            let mut pairs: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+            for item in reponses
+                .iter()
+                .filter_map(|r| r.metadata.and_then(AssociationItem::from_metadata))
+            {
+                let counts = pairs.entry(item.paire).or_default();
+                match item.colonne {
+                    Colonne::Gauche => counts.0 += 1,
+                    Colonne::Droite => counts.1 += 1,
+                }
+            }
+            if pairs.is_empty() {
+                set_error("Au moins une paire gauche/droite attendue".to_string());
+            }
+            for (paire, _) in pairs.iter().filter(|(_, counts)| **counts != (1, 1)) {
+                set_error(format!(
+                    "La paire '{}' doit avoir exactement un élément gauche et un élément droite",
+                    paire
+                ));
+            }
+        }
+        _ => {}
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_text() {
+        let errors =
+            validate_question_data(&default_schema("qcm").unwrap(), &json!({})).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "question_data/text");
+    }
+
+    #[test]
+    fn test_numerique_fields() {
+        let schema = default_schema("numerique").unwrap();
+        assert!(
+            validate_question_data(
+                &schema,
+                &json!({"text": "Altitude ?", "expected_value": 4808})
+            )
+            .is_ok()
+        );
+
+        let errors = validate_question_data(
+            &schema,
+            &json!({"text": "Altitude ?", "expected_value": "4808", "absolute_tolerance": -1}),
+        )
+        .unwrap_err();
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert!(fields.contains(&"question_data/expected_value"));
+        assert!(fields.contains(&"question_data/absolute_tolerance"));
+    }
+
+    #[test]
+    fn test_unknown_scoring() {
+        let errors = validate_question_data(
+            &default_schema("ordre").unwrap(),
+            &json!({"text": "Classez", "scoring": "aleatoire"}),
+        )
+        .unwrap_err();
+        assert_eq!(errors[0].field, "question_data/scoring");
+        assert!(default_schema("dessin").is_none());
+    }
+
+    #[test]
+    fn test_ordre_reponses() {
+        let spec = |ordre| ReponseSpec {
+            ordre,
+            metadata: None,
+        };
+        assert!(validate_reponses("ordre", &[spec(Some(1)), spec(Some(2))]).is_ok());
+
+        // Question "ordre" sans éléments
+        let errors = validate_reponses("ordre", &[]).unwrap_err();
+        assert_eq!(errors[0].field, "reponses");
+
+        let errors =
+            validate_reponses("ordre", &[spec(Some(1)), spec(None), spec(Some(1))]).unwrap_err();
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["reponses/1/ordre", "reponses"]);
+    }
+
+    #[test]
+    fn test_association_reponses() {
+        let metadata = [
+            json!({"colonne": "gauche", "paire": "fr"}),
+            json!({"colonne": "droite", "paire": "fr"}),
+            json!({"colonne": "gauche", "paire": "it"}),
+            json!({"paire": "it"}),
+        ];
+        let specs: Vec<ReponseSpec> = metadata
+            .iter()
+            .map(|m| ReponseSpec {
+                ordre: None,
+                metadata: Some(m),
+            })
+            .collect();
+
+        assert!(validate_reponses("association", &specs[..2]).is_ok());
+        assert!(validate_reponses("association", &[]).is_err());
+
+        let errors = validate_reponses("association", &specs).unwrap_err();
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert_eq!(fields, vec!["reponses/3/metadata", "reponses"]);
+        assert!(errors[1].message.contains("'it'"));
+
+        // Pas de contrainte pour les autres types
+        assert!(validate_reponses("qcm", &[]).is_ok());
+    }
+}
@@ -1,22 +1,34 @@
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::Arc;
 
-use super::QuizPlugin;
+use super::{QuizPlugin, WasmPlugin, WasmRuntime};
 
 /// Registry centralisé des plugins de domaine
 #[derive(Clone)]
 pub struct PluginRegistry {
     plugins: HashMap<String, Arc<dyn QuizPlugin>>,
+    version: u64, // Séquence persistée, nouvelle valeur à chaque rechargement (voir PluginManager)
 }
 
 impl PluginRegistry {
     /// Créer un nouveau registry vide
     pub fn new() -> Self {
+        Self::with_version(0)
+    }
+
+    /// Créer un registry vide pour une version donnée
+    pub fn with_version(version: u64) -> Self {
         Self {
             plugins: HashMap::new(),
+            version,
         }
     }
 
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
     /// Enregistrer un plugin
     pub fn register(&mut self, plugin: Arc<dyn QuizPlugin>) {
         let domain = plugin.domain_name().to_string();
@@ -28,6 +40,23 @@ impl PluginRegistry {
         self.plugins.insert(domain, plugin);
     }
 
+    /// Charger les plugins WASM d'un répertoire (les plugins natifs restent prioritaires)
+    pub fn load_wasm_dir(&mut self, runtime: Arc<WasmRuntime>, dir: &Path) -> usize {
+        let mut loaded = 0;
+        for plugin in WasmPlugin::load_dir(runtime, dir) {
+            if self.has_domain(plugin.domain_name()) {
+                tracing::warn!(
+                    domain = %plugin.domain_name(),
+                    "WASM plugin ignored: domain already registered"
+                );
+                continue;
+            }
+            self.register(Arc::new(plugin));
+            loaded += 1;
+        }
+        loaded
+    }
+
     /// Récupérer un plugin par nom de domaine
     pub fn get(&self, domain: &str) -> Option<&Arc<dyn QuizPlugin>> {
         self.plugins.get(domain)
@@ -38,6 +67,11 @@ impl PluginRegistry {
         self.plugins.keys().cloned().collect()
     }
 
+    /// Ne conserver que les domaines acceptés par le prédicat
+    pub fn retain(&mut self, mut keep: impl FnMut(&str) -> bool) {
+        self.plugins.retain(|domain, _| keep(domain));
+    }
+
     /// Nombre de plugins enregistrés
     pub fn count(&self) -> usize {
         self.plugins.len()
@@ -0,0 +1,249 @@
+use std::sync::LazyLock;
+
+use serde::Deserialize;
+
+use super::plugin_trait::ValidationResult;
+
+/// Pénalité par défaut lorsqu'un indice a été consulté (-20%)
+pub const DEFAULT_HINT_PENALTY: f32 = 0.2;
+
+/// Multiplicateur maximum du barème par défaut : bonus vitesse (x1.5) + streak (+50%)
+pub const MAX_SCORE_MULTIPLIER: f32 = 2.0;
+
+/// Barème par défaut du trait `QuizPlugin`
+pub static DEFAULT_SCORING: LazyLock<ScoringRules> = LazyLock::new(ScoringRules::default);
+
+/// Palier de rapidité : ratio temps passé / temps limite
+#[derive(Debug, Clone, Deserialize)]
+pub struct SpeedTier {
+    pub max_ratio: f32,
+    pub multiplier: f32,
+    pub badge: Option<String>,
+}
+
+/// Pénalité de lenteur : au-delà de `min_ratio`
+#[derive(Debug, Clone, Deserialize)]
+pub struct SlowPenalty {
+    pub min_ratio: f32,
+    pub multiplier: f32,
+    pub badge: Option<String>,
+}
+
+/// Bonus/malus de vitesse (paliers testés dans l'ordre, le premier qui correspond l'emporte)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SpeedBonus {
+    pub tiers: Vec<SpeedTier>,
+    pub slow: Option<SlowPenalty>,
+}
+
+impl Default for SpeedBonus {
+    fn default() -> Self {
+        Self {
+            tiers: vec![
+                SpeedTier {
+                    max_ratio: 0.3,
+                    multiplier: 1.5,
+                    badge: Some("⚡ Éclair !".to_string()),
+                },
+                SpeedTier {
+                    max_ratio: 0.5,
+                    multiplier: 1.25,
+                    badge: Some("🚀 Rapide !".to_string()),
+                },
+            ],
+            slow: Some(SlowPenalty {
+                min_ratio: 0.9,
+                multiplier: 0.75,
+                badge: Some("🐢 Prends ton temps".to_string()),
+            }),
+        }
+    }
+}
+
+impl SpeedBonus {
+    /// Multiplicateur et badge pour un temps de réponse donné
+    pub fn apply(&self, time_spent: i32, time_limit: Option<i32>) -> (f32, Option<&str>) {
+        let Some(limit) = time_limit.filter(|l| *l > 0) else {
+            return (1.0, None);
+        };
+        let ratio = time_spent as f32 / limit as f32;
+
+        if let Some(tier) = self.tiers.iter().find(|t| ratio < t.max_ratio) {
+            return (tier.multiplier, tier.badge.as_deref());
+        }
+        match &self.slow {
+            Some(slow) if ratio > slow.min_ratio => (slow.multiplier, slow.badge.as_deref()),
+            _ => (1.0, None),
+        }
+    }
+}
+
+/// Bonus de série : `curve[i]` = bonus (fraction des points de base)
+/// pour la série `min_streak + i`, la dernière valeur s'applique au-delà
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct StreakBonus {
+    pub min_streak: i32,
+    pub curve: Vec<f32>,
+}
+
+impl Default for StreakBonus {
+    fn default() -> Self {
+        Self {
+            min_streak: 3,
+            curve: vec![0.1, 0.2, 0.3, 0.4, 0.5],
+        }
+    }
+}
+
+impl StreakBonus {
+    pub fn bonus(&self, streak_count: i32) -> f32 {
+        if streak_count < self.min_streak {
+            return 0.0;
+        }
+        let index = (streak_count - self.min_streak) as usize;
+        self.curve
+            .get(index)
+            .or(self.curve.last())
+            .copied()
+            .unwrap_or(0.0)
+    }
+}
+
+/// Barème d'un domaine : paliers de vitesse, courbe de série et pénalité d'indice
+#[derive(Debug, Clone)]
+pub struct ScoringRules {
+    pub speed_bonus: SpeedBonus,
+    pub streak_bonus: StreakBonus,
+    /// Part des points retirée si l'indice a été consulté (0.0..=1.0)
+    pub hint_penalty: f32,
+}
+
+impl Default for ScoringRules {
+    fn default() -> Self {
+        Self {
+            speed_bonus: SpeedBonus::default(),
+            streak_bonus: StreakBonus::default(),
+            hint_penalty: DEFAULT_HINT_PENALTY,
+        }
+    }
+}
+
+/// Formule de score commune : score partiel, bonus vitesse, bonus streak et pénalité d'indice
+/// `time_limit: None` désactive le bonus vitesse
+pub fn default_score(
+    rules: &ScoringRules,
+    base_points: i32,
+    validation: &ValidationResult,
+    time_spent: i32,
+    time_limit: Option<i32>,
+    streak_count: i32,
+    hint_used: bool,
+) -> i32 {
+    if !validation.is_correct && validation.partial_score.is_none() {
+        return 0;
+    }
+
+    let mut points = base_points as f32;
+
+    // Score partiel (ordre, association, QCM multiple...)
+    if let Some(partial) = validation.partial_score {
+        points *= partial;
+    }
+
+    // Bonus vitesse
+    let (multiplier, _) = rules.speed_bonus.apply(time_spent, time_limit);
+    points *= multiplier;
+
+    // Bonus streak
+    points += base_points as f32 * rules.streak_bonus.bonus(streak_count);
+
+    // Pénalité d'indice
+    if hint_used {
+        points *= 1.0 - rules.hint_penalty;
+    }
+
+    points.round() as i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn correct() -> ValidationResult {
+        ValidationResult::correct("Bonne réponse !")
+    }
+
+    #[test]
+    fn test_default_rules() {
+        let rules = &*DEFAULT_SCORING;
+        // Très rapide (x1.5), rapide (x1.25), normal, lent (x0.75)
+        assert_eq!(
+            default_score(rules, 10, &correct(), 2, Some(10), 0, false),
+            15
+        );
+        assert_eq!(
+            default_score(rules, 10, &correct(), 4, Some(10), 0, false),
+            13
+        );
+        assert_eq!(
+            default_score(rules, 10, &correct(), 7, Some(10), 0, false),
+            10
+        );
+        assert_eq!(
+            default_score(rules, 10, &correct(), 10, Some(10), 0, false),
+            8
+        );
+        // Série de 5 : +30 % ; plafond à +50 %
+        assert_eq!(default_score(rules, 10, &correct(), 7, None, 5, false), 13);
+        assert_eq!(default_score(rules, 10, &correct(), 7, None, 20, false), 15);
+        // Maximum : x1.5 puis +50 %
+        assert_eq!(
+            default_score(rules, 10, &correct(), 0, Some(10), 20, false),
+            (10.0 * MAX_SCORE_MULTIPLIER) as i32
+        );
+        // Indice : -20 %
+        assert_eq!(default_score(rules, 10, &correct(), 7, None, 0, true), 8);
+    }
+
+    #[test]
+    fn test_partial_and_wrong_answers() {
+        let rules = &*DEFAULT_SCORING;
+        let partial = ValidationResult::partial(0.5, "Ordre partiellement correct");
+        assert_eq!(default_score(rules, 10, &partial, 7, None, 0, false), 5);
+        let wrong = ValidationResult::incorrect("Mauvaise réponse");
+        assert_eq!(default_score(rules, 10, &wrong, 0, Some(10), 10, false), 0);
+    }
+
+    #[test]
+    fn test_custom_rules() {
+        let rules = ScoringRules {
+            speed_bonus: SpeedBonus {
+                tiers: vec![SpeedTier {
+                    max_ratio: 0.5,
+                    multiplier: 2.0,
+                    badge: None,
+                }],
+                slow: None,
+            },
+            streak_bonus: StreakBonus {
+                min_streak: 2,
+                curve: vec![1.0],
+            },
+            hint_penalty: 0.5,
+        };
+        assert_eq!(
+            default_score(&rules, 10, &correct(), 4, Some(10), 0, false),
+            20
+        );
+        assert_eq!(
+            default_score(&rules, 10, &correct(), 10, Some(10), 2, false),
+            20
+        );
+        assert_eq!(
+            default_score(&rules, 10, &correct(), 10, Some(10), 0, true),
+            5
+        );
+    }
+}
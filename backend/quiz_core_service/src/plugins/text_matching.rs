@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+use unicode_normalization::UnicodeNormalization;
+
+/// Règles de normalisation des saisies texte
+/// Ex: "St-Étienne" → "saint etienne", "l'Oise" → "oise"
+/// Désérialisable (champs absents = règles françaises par défaut)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct TextNormalizer {
+    /// Retirer les accents (décomposition NFD puis suppression des diacritiques)
+    pub fold_accents: bool,
+    /// Articles retirés en début de saisie ("le", "la", "l'"...)
+    pub articles: Vec<String>,
+    /// Abréviations développées mot par mot ("st" → "saint")
+    pub abbreviations: HashMap<String, String>,
+}
+
+impl Default for TextNormalizer {
+    /// Règles françaises par défaut
+    fn default() -> Self {
+        Self {
+            fold_accents: true,
+            articles: ["le", "la", "les", "l"]
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+            abbreviations: [
+                ("st", "saint"),
+                ("ste", "sainte"),
+                ("sts", "saints"),
+                ("stes", "saintes"),
+                ("mt", "mont"),
+                ("mts", "monts"),
+            ]
+            .into_iter()
+            .map(|(short, long)| (short.to_string(), long.to_string()))
+            .collect(),
+        }
+    }
+}
+
+impl TextNormalizer {
+    /// Normaliser une saisie : minuscules, accents, ponctuation, abréviations, articles
+    pub fn normalize(&self, input: &str) -> String {
+        let folded: String = if self.fold_accents {
+            input
+                .nfd()
+                .filter(|c| !unicode_normalization::char::is_combining_mark(*c))
+                .collect()
+        } else {
+            input.to_string()
+        };
+
+        // Ponctuation (tirets, apostrophes, points...) → espaces
+        let cleaned: String = folded
+            .to_lowercase()
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+            .collect();
+
+        let mut words: Vec<&str> = cleaned
+            .split_whitespace()
+            .map(|word| {
+                self.abbreviations
+                    .get(word)
+                    .map(String::as_str)
+                    .unwrap_or(word)
+            })
+            .collect();
+
+        // Articles en tête ("le massif central", "l'oise")
+        while words.len() > 1 && self.articles.iter().any(|a| a == words[0]) {
+            words.remove(0);
+        }
+
+        words.join(" ")
+    }
+}
+
+/// Tolérance aux fautes de frappe, configurable via `question_data`
+/// - `typo_tolerance` : distance maximale acceptée (sinon selon la longueur)
+/// - `typo_partial_score` : score partiel (0.0 à 1.0) pour une réponse "presque" correcte
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TypoTolerance {
+    pub max_distance: Option<usize>,
+    pub partial_score: Option<f32>,
+}
+
+impl TypoTolerance {
+    pub fn from_question_data(question_data: &serde_json::Value) -> Self {
+        Self {
+            max_distance: question_data
+                .get("typo_tolerance")
+                .and_then(|v| v.as_u64())
+                .map(|v| v as usize),
+            partial_score: question_data
+                .get("typo_partial_score")
+                .and_then(|v| v.as_f64())
+                .map(|v| v.clamp(0.0, 1.0) as f32),
+        }
+    }
+
+    /// Distance maximale pour une réponse attendue donnée
+    /// Par défaut : 0 faute sous 5 caractères, 1 jusqu'à 9, 2 au-delà
+    pub fn max_distance_for(&self, expected: &str) -> usize {
+        self.max_distance
+            .unwrap_or_else(|| match expected.chars().count() {
+                0..=4 => 0,
+                5..=9 => 1,
+                _ => 2,
+            })
+    }
+}
+
+/// Résultat de la comparaison d'une saisie avec les réponses acceptées
+#[derive(Debug, Clone, PartialEq)]
+pub enum TextMatch {
+    /// Correspondance exacte après normalisation
+    Exact(String),
+    /// Correspondance à quelques fautes près
+    NearMiss {
+        expected: String,
+        distance: usize,
+    },
+    NoMatch,
+}
+
+/// Comparer une saisie avec les réponses acceptées (la meilleure l'emporte)
+pub fn match_answer(
+    normalizer: &TextNormalizer,
+    tolerance: &TypoTolerance,
+    input: &str,
+    accepted: &[String],
+) -> TextMatch {
+    let normalized_input = normalizer.normalize(input);
+    let mut best: Option<(usize, &String)> = None;
+
+    for candidate in accepted {
+        let normalized_candidate = normalizer.normalize(candidate);
+        if normalized_candidate == normalized_input {
+            return TextMatch::Exact(candidate.clone());
+        }
+
+        let distance = damerau_levenshtein(&normalized_input, &normalized_candidate);
+        if distance <= tolerance.max_distance_for(&normalized_candidate)
+            && best.is_none_or(|(best_distance, _)| distance < best_distance)
+        {
+            best = Some((distance, candidate));
+        }
+    }
+
+    match best {
+        Some((distance, expected)) => TextMatch::NearMiss {
+            expected: expected.clone(),
+            distance,
+        },
+        None => TextMatch::NoMatch,
+    }
+}
+
+/// Distance de Damerau-Levenshtein (variante "optimal string alignment") :
+/// insertion, suppression, substitution et transposition de deux lettres adjacentes
+pub fn damerau_levenshtein(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (n, m) = (a.len(), b.len());
+
+    let mut d = vec![vec![0usize; m + 1]; n + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=n {
+        for j in 1..=m {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    d[n][m]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_accents_and_abbreviations() {
+        let normalizer = TextNormalizer::default();
+        assert_eq!(normalizer.normalize("Rhône"), "rhone");
+        assert_eq!(normalizer.normalize("St-Étienne"), "saint etienne");
+        assert_eq!(normalizer.normalize("  Saint Etienne "), "saint etienne");
+        assert_eq!(
+            normalizer.normalize("Auvergne-Rhône-Alpes"),
+            "auvergne rhone alpes"
+        );
+    }
+
+    #[test]
+    fn test_normalize_articles() {
+        let normalizer = TextNormalizer::default();
+        assert_eq!(normalizer.normalize("Le Massif central"), "massif central");
+        assert_eq!(normalizer.normalize("l'Oise"), "oise");
+        // Un article seul n'est pas supprimé
+        assert_eq!(normalizer.normalize("La"), "la");
+    }
+
+    #[test]
+    fn test_damerau_levenshtein() {
+        assert_eq!(damerau_levenshtein("seine", "seine"), 0);
+        assert_eq!(damerau_levenshtein("sene", "seine"), 1);
+        assert_eq!(damerau_levenshtein("siene", "seine"), 1); // transposition
+        assert_eq!(damerau_levenshtein("loire", "garonne"), 5);
+    }
+
+    #[test]
+    fn test_match_answer() {
+        let normalizer = TextNormalizer::default();
+        let tolerance = TypoTolerance::default();
+        let accepted = vec!["La Seine".to_string()];
+
+        assert_eq!(
+            match_answer(&normalizer, &tolerance, "seine", &accepted),
+            TextMatch::Exact("La Seine".to_string())
+        );
+        assert_eq!(
+            match_answer(&normalizer, &tolerance, "Siene", &accepted),
+            TextMatch::NearMiss {
+                expected: "La Seine".to_string(),
+                distance: 1
+            }
+        );
+        assert_eq!(
+            match_answer(&normalizer, &tolerance, "Loire", &accepted),
+            TextMatch::NoMatch
+        );
+
+        let strict = TypoTolerance {
+            max_distance: Some(0),
+            partial_score: None,
+        };
+        assert_eq!(
+            match_answer(&normalizer, &strict, "Siene", &accepted),
+            TextMatch::NoMatch
+        );
+    }
+}
@@ -0,0 +1,5 @@
+mod wasm_plugin;
+mod wasm_runtime;
+
+pub use wasm_plugin::WasmPlugin;
+pub use wasm_runtime::{WasmLimits, WasmRuntime};
@@ -0,0 +1,275 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use serde::Deserialize;
+use serde_json::json;
+use shared::AppError;
+use sqlx::PgPool;
+use wasmtime::Module;
+
+use super::wasm_runtime::WasmRuntime;
+use crate::{
+    dto::session_dto::SubmitAnswerRequest,
+    models::Question,
+    plugins::{
+        QuizPlugin, ValidationResult,
+        question_schemas::{self, DEFAULT_QUESTION_TYPES},
+        scoring::{DEFAULT_SCORING, MAX_SCORE_MULTIPLIER, default_score},
+    },
+    repositories::ReponseRepository,
+};
+
+/// Manifeste optionnel à côté du module : `<domaine>.json`
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct WasmManifest {
+    display_name: Option<String>,
+    description: Option<String>,
+    /// Schémas de `question_data` par type (types par défaut si absent)
+    question_schemas: Option<BTreeMap<String, serde_json::Value>>,
+}
+
+/// Sortie de `validate_answer` côté invité
+#[derive(Debug, Deserialize)]
+struct GuestValidation {
+    is_correct: bool,
+    #[serde(default)]
+    feedback_message: String,
+    explanation: Option<String>,
+    partial_score: Option<f32>,
+    #[serde(default)]
+    metadata: Option<serde_json::Value>,
+}
+
+/// Sortie de `calculate_score` côté invité
+#[derive(Debug, Deserialize)]
+struct GuestScore {
+    points: i32,
+}
+
+/// Plugin de domaine chargé depuis un module WebAssembly (`<domaine>.wasm`)
+/// Exécuté en bac à sable : aucun accès hôte, carburant, mémoire et durée limités
+pub struct WasmPlugin {
+    name: String,
+    display_name: String,
+    description: String,
+    question_schemas: BTreeMap<String, serde_json::Value>,
+    runtime: Arc<WasmRuntime>,
+    module: Module,
+    has_calculate_score: bool,
+}
+
+impl WasmPlugin {
+    /// Charger un module ; le nom du fichier donne le domaine
+    pub fn load(runtime: Arc<WasmRuntime>, path: &Path) -> anyhow::Result<Self> {
+        let name = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .ok_or_else(|| anyhow::anyhow!("nom de module invalide : {}", path.display()))?
+            .to_string();
+
+        let module = runtime.compile(&std::fs::read(path)?)?;
+        let has_calculate_score = module.get_export("calculate_score").is_some();
+
+        let manifest: WasmManifest = match std::fs::read(path.with_extension("json")) {
+            Ok(bytes) => serde_json::from_slice(&bytes)?,
+            Err(_) => WasmManifest::default(),
+        };
+
+        Ok(Self {
+            display_name: manifest.display_name.unwrap_or_else(|| name.clone()),
+            description: manifest.description.unwrap_or_default(),
+            question_schemas: manifest
+                .question_schemas
+                .unwrap_or_else(|| question_schemas::default_schemas(DEFAULT_QUESTION_TYPES)),
+            name,
+            runtime,
+            module,
+            has_calculate_score,
+        })
+    }
+
+    /// Charger tous les modules `.wasm` d'un répertoire (modules invalides ignorés)
+    pub fn load_dir(runtime: Arc<WasmRuntime>, dir: &Path) -> Vec<Self> {
+        let entries = match std::fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(_) => {
+                tracing::info!(dir = %dir.display(), "No WASM plugins directory");
+                return Vec::new();
+            }
+        };
+
+        entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "wasm"))
+            .filter_map(|path| match Self::load(runtime.clone(), &path) {
+                Ok(plugin) => Some(plugin),
+                Err(e) => {
+                    tracing::error!(path = %path.display(), error = %e, "Invalid WASM plugin, skipped");
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl QuizPlugin for WasmPlugin {
+    fn domain_name(&self) -> &str {
+        &self.name
+    }
+
+    fn display_name(&self) -> &str {
+        &self.display_name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn question_schemas(&self) -> BTreeMap<String, serde_json::Value> {
+        self.question_schemas.clone()
+    }
+
+    /// Validation déléguée au module : question, réponses possibles et réponse soumise en JSON
+    async fn validate_answer(
+        &self,
+        pool: &PgPool,
+        question: &Question,
+        answer: &SubmitAnswerRequest,
+    ) -> Result<ValidationResult, AppError> {
+        let reponses = ReponseRepository::find_by_question_id(pool, question.id).await?;
+        let input = json!({
+            "question": question,
+            "reponses": reponses,
+            "answer": answer,
+        });
+
+        // Appel bloquant hors du runtime async (borné par carburant et timeout)
+        let runtime = self.runtime.clone();
+        let module = self.module.clone();
+        let output: GuestValidation =
+            tokio::task::spawn_blocking(move || runtime.call(&module, "validate_answer", &input))
+                .await
+                .map_err(|e| AppError::InternalServerError(e.to_string()))?
+                .map_err(|e| {
+                    tracing::error!(domain = %self.name, error = %e, "WASM validate_answer failed");
+                    AppError::InternalServerError(format!("Plugin {} : {}", self.name, e))
+                })?;
+
+        Ok(ValidationResult {
+            is_correct: output.is_correct,
+            feedback_message: output.feedback_message,
+            explanation: output.explanation,
+            partial_score: output.partial_score.map(|s| s.clamp(0.0, 1.0)),
+            metadata: output.metadata.unwrap_or_else(|| json!({})),
+        })
+    }
+
+    /// Score calculé par le module s'il exporte `calculate_score`, barème par défaut sinon
+    /// Points bornés au maximum du barème par défaut (base x `MAX_SCORE_MULTIPLIER`)
+    async fn calculate_score(
+        &self,
+        base_points: i32,
+        validation: &ValidationResult,
+        time_spent: i32,
+        time_limit: Option<i32>,
+        streak_count: i32,
+        hint_used: bool,
+    ) -> i32 {
+        let fallback = || {
+            default_score(
+                &DEFAULT_SCORING,
+                base_points,
+                validation,
+                time_spent,
+                time_limit,
+                streak_count,
+                hint_used,
+            )
+        };
+        if !self.has_calculate_score {
+            return fallback();
+        }
+
+        let input = json!({
+            "base_points": base_points,
+            "validation": {
+                "is_correct": validation.is_correct,
+                "partial_score": validation.partial_score,
+                "metadata": validation.metadata,
+            },
+            "time_spent": time_spent,
+            "time_limit": time_limit,
+            "streak_count": streak_count,
+            "hint_used": hint_used,
+        });
+
+        // Appel bloquant hors du runtime async, comme pour la validation
+        let runtime = self.runtime.clone();
+        let module = self.module.clone();
+        let output = tokio::task::spawn_blocking(move || {
+            runtime.call::<_, GuestScore>(&module, "calculate_score", &input)
+        })
+        .await
+        .map_err(anyhow::Error::from)
+        .and_then(|result| result);
+
+        match output {
+            Ok(score) => {
+                let max_points = (base_points.max(0) as f32 * MAX_SCORE_MULTIPLIER).round() as i32;
+                score.points.clamp(0, max_points)
+            }
+            Err(e) => {
+                tracing::error!(domain = %self.name, error = %e, "WASM calculate_score failed, default scoring used");
+                fallback()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::wasm::wasm_runtime::WasmLimits;
+
+    /// `calculate_score` renvoie toujours `{"points":2147483647}`
+    const GREEDY_SCORER: &str = r#"
+        (module
+          (memory (export "memory") 1)
+          (global $heap (mut i32) (i32.const 1024))
+          (data (i32.const 0) "{\"points\":2147483647}")
+          (func (export "alloc") (param $len i32) (result i32)
+            (local $ptr i32)
+            (local.set $ptr (global.get $heap))
+            (global.set $heap (i32.add (global.get $heap) (local.get $len)))
+            (local.get $ptr))
+          (func (export "validate_answer") (param i32 i32) (result i64)
+            (i64.const 0))
+          (func (export "calculate_score") (param i32 i32) (result i64)
+            (i64.const 21)))
+    "#;
+
+    #[tokio::test]
+    async fn test_guest_score_is_bounded() {
+        let runtime = WasmRuntime::new(WasmLimits::default()).unwrap();
+        let module = runtime.compile(GREEDY_SCORER.as_bytes()).unwrap();
+        let plugin = WasmPlugin {
+            name: "greedy".to_string(),
+            display_name: "Greedy".to_string(),
+            description: String::new(),
+            question_schemas: BTreeMap::new(),
+            runtime,
+            module,
+            has_calculate_score: true,
+        };
+
+        let validation = ValidationResult::correct("Bonne réponse !");
+        let points = plugin
+            .calculate_score(10, &validation, 2, Some(10), 5, false)
+            .await;
+        assert_eq!(points, 20);
+    }
+}
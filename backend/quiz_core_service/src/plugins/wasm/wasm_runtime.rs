@@ -0,0 +1,217 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::{Serialize, de::DeserializeOwned};
+use wasmtime::{Config, Engine, Instance, Linker, Module, Store, StoreLimits, StoreLimitsBuilder};
+
+/// Pas de l'horloge d'interruption (epoch) des modules WASM
+const EPOCH_TICK: Duration = Duration::from_millis(10);
+
+/// Limites appliquées à chaque appel d'un module WASM
+#[derive(Debug, Clone, Copy)]
+pub struct WasmLimits {
+    /// Instructions (approx.) autorisées par appel
+    pub fuel: u64,
+    /// Mémoire linéaire maximale, en octets
+    pub max_memory_bytes: usize,
+    /// Durée maximale d'un appel
+    pub timeout: Duration,
+}
+
+impl Default for WasmLimits {
+    fn default() -> Self {
+        Self {
+            fuel: 50_000_000,
+            max_memory_bytes: 16 * 1024 * 1024,
+            timeout: Duration::from_millis(200),
+        }
+    }
+}
+
+/// Moteur wasmtime partagé par tous les plugins WASM
+///
+/// ABI invité (module core, aucun import autorisé) :
+/// - `memory` : mémoire exportée
+/// - `alloc(len: i32) -> i32` : réserve `len` octets pour l'entrée
+/// - `validate_answer(ptr: i32, len: i32) -> i64` et `calculate_score(ptr: i32, len: i32) -> i64` :
+///   entrée et sortie en JSON UTF-8, sortie retournée sous la forme `(ptr << 32) | len`
+#[derive(Clone)]
+pub struct WasmRuntime {
+    engine: Engine,
+    limits: WasmLimits,
+}
+
+/// État d'un Store : uniquement ses limites mémoire (aucune fonction hôte exposée)
+struct GuestState {
+    limits: StoreLimits,
+}
+
+impl WasmRuntime {
+    pub fn new(limits: WasmLimits) -> anyhow::Result<Arc<Self>> {
+        let mut config = Config::new();
+        config.consume_fuel(true);
+        config.epoch_interruption(true);
+        let engine = Engine::new(&config)?;
+
+        // Horloge d'interruption : s'arrête quand le moteur est libéré
+        let weak = engine.weak();
+        std::thread::Builder::new()
+            .name("wasm-epoch".to_string())
+            .spawn(move || {
+                while let Some(engine) = weak.upgrade() {
+                    engine.increment_epoch();
+                    drop(engine);
+                    std::thread::sleep(EPOCH_TICK);
+                }
+            })?;
+
+        Ok(Arc::new(Self { engine, limits }))
+    }
+
+    /// Compiler un module (fichier .wasm ou texte .wat)
+    pub fn compile(&self, bytes: &[u8]) -> anyhow::Result<Module> {
+        let module = Module::new(&self.engine, bytes)?;
+        if module.imports().len() > 0 {
+            anyhow::bail!("les modules de plugin ne doivent importer aucune fonction hôte");
+        }
+        for export in ["memory", "alloc", "validate_answer"] {
+            if module.get_export(export).is_none() {
+                anyhow::bail!("export manquant : {}", export);
+            }
+        }
+        Ok(module)
+    }
+
+    /// Appeler une fonction invitée avec une entrée JSON, dans un Store neuf et limité
+    pub fn call<I: Serialize, O: DeserializeOwned>(
+        &self,
+        module: &Module,
+        function: &str,
+        input: &I,
+    ) -> anyhow::Result<O> {
+        let mut store = Store::new(
+            &self.engine,
+            GuestState {
+                limits: StoreLimitsBuilder::new()
+                    .memory_size(self.limits.max_memory_bytes)
+                    .instances(1)
+                    .trap_on_grow_failure(true)
+                    .build(),
+            },
+        );
+        store.limiter(|state| &mut state.limits);
+        store.set_fuel(self.limits.fuel)?;
+        let ticks = self.limits.timeout.as_millis() / EPOCH_TICK.as_millis();
+        store.set_epoch_deadline(ticks.max(1) as u64);
+
+        let linker = Linker::new(&self.engine);
+        let instance: Instance = linker.instantiate(&mut store, module)?;
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .ok_or_else(|| anyhow::anyhow!("export manquant : memory"))?;
+
+        let input = serde_json::to_vec(input)?;
+        let alloc = instance.get_typed_func::<i32, i32>(&mut store, "alloc")?;
+        let input_ptr = alloc.call(&mut store, input.len() as i32)?;
+        memory.write(&mut store, input_ptr as usize, &input)?;
+
+        let func = instance.get_typed_func::<(i32, i32), i64>(&mut store, function)?;
+        let packed = func.call(&mut store, (input_ptr, input.len() as i32))? as u64;
+        let (output_ptr, output_len) = ((packed >> 32) as usize, (packed & 0xFFFF_FFFF) as usize);
+
+        let output = memory
+            .data(&store)
+            .get(output_ptr..output_ptr + output_len)
+            .ok_or_else(|| anyhow::anyhow!("sortie hors de la mémoire du module"))?;
+        Ok(serde_json::from_slice(output)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{Value, json};
+
+    /// Module minimal : `validate_answer` renvoie un JSON constant, `spin` boucle à l'infini
+    const GUEST: &str = r#"
+        (module
+          (memory (export "memory") 1)
+          (global $heap (mut i32) (i32.const 1024))
+          (data (i32.const 0) "{\"is_correct\":true}")
+          (func (export "alloc") (param $len i32) (result i32)
+            (local $ptr i32)
+            (local.set $ptr (global.get $heap))
+            (global.set $heap (i32.add (global.get $heap) (local.get $len)))
+            (local.get $ptr))
+          (func (export "validate_answer") (param i32 i32) (result i64)
+            (i64.const 19))
+          (func (export "spin") (param i32 i32) (result i64)
+            (loop $l (br $l))
+            (i64.const 0))
+          (func (export "grow") (param i32 i32) (result i64)
+            (drop (memory.grow (i32.const 512)))
+            (i64.const 19)))
+    "#;
+
+    fn runtime(limits: WasmLimits) -> (Arc<WasmRuntime>, Module) {
+        let runtime = WasmRuntime::new(limits).unwrap();
+        let module = runtime.compile(GUEST.as_bytes()).unwrap();
+        (runtime, module)
+    }
+
+    #[test]
+    fn test_call_roundtrip() {
+        let (runtime, module) = runtime(WasmLimits::default());
+        let output: Value = runtime
+            .call(&module, "validate_answer", &json!({"answer": {}}))
+            .unwrap();
+        assert_eq!(output, json!({"is_correct": true}));
+    }
+
+    #[test]
+    fn test_fuel_limit() {
+        let (runtime, module) = runtime(WasmLimits {
+            fuel: 10_000,
+            ..WasmLimits::default()
+        });
+        assert!(
+            runtime
+                .call::<_, Value>(&module, "spin", &json!({}))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_timeout() {
+        let (runtime, module) = runtime(WasmLimits {
+            fuel: u64::MAX,
+            timeout: Duration::from_millis(50),
+            ..WasmLimits::default()
+        });
+        assert!(
+            runtime
+                .call::<_, Value>(&module, "spin", &json!({}))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_memory_limit() {
+        let (runtime, module) = runtime(WasmLimits {
+            max_memory_bytes: 1024 * 1024,
+            ..WasmLimits::default()
+        });
+        assert!(
+            runtime
+                .call::<_, Value>(&module, "grow", &json!({}))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_rejects_host_imports() {
+        let runtime = WasmRuntime::new(WasmLimits::default()).unwrap();
+        let module = r#"(module (import "env" "exit" (func)))"#;
+        assert!(runtime.compile(module.as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,198 @@
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::{
+    models::{Achievement, UserAchievement},
+    services::achievements::AchievementDefinition,
+};
+
+pub struct AchievementRepository;
+
+impl AchievementRepository {
+    /// Ajouter ou mettre à jour un succès déclaré par un plugin
+    pub async fn upsert_definition(
+        pool: &PgPool,
+        domain: &str,
+        definition: &AchievementDefinition,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO achievements (code, domain, titre, description, icon, rule)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (code) DO UPDATE
+            SET domain = EXCLUDED.domain,
+                titre = EXCLUDED.titre,
+                description = EXCLUDED.description,
+                icon = EXCLUDED.icon,
+                rule = EXCLUDED.rule,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(&definition.code)
+        .bind(domain)
+        .bind(&definition.titre)
+        .bind(&definition.description)
+        .bind(&definition.icon)
+        .bind(serde_json::to_value(&definition.rule).unwrap_or_default())
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Succès actifs (généraux et du domaine) pas encore débloqués par le joueur
+    pub async fn find_locked(
+        executor: impl PgExecutor<'_>,
+        user_id: Uuid,
+        domain: &str,
+    ) -> Result<Vec<Achievement>, sqlx::Error> {
+        sqlx::query_as::<_, Achievement>(
+            r#"
+            SELECT a.* FROM achievements a
+            WHERE a.is_active
+              AND (a.domain IS NULL OR a.domain = $2)
+              AND NOT EXISTS (
+                  SELECT 1 FROM user_achievements ua
+                  WHERE ua.user_id = $1 AND ua.achievement_code = a.code
+              )
+            "#,
+        )
+        .bind(user_id)
+        .bind(domain)
+        .fetch_all(executor)
+        .await
+    }
+
+    /// Débloquer un succès (false s'il l'était déjà)
+    pub async fn unlock(
+        executor: impl PgExecutor<'_>,
+        user_id: Uuid,
+        code: &str,
+        session_id: Uuid,
+    ) -> Result<bool, sqlx::Error> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO user_achievements (user_id, achievement_code, session_id)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (user_id, achievement_code) DO NOTHING
+            "#,
+        )
+        .bind(user_id)
+        .bind(code)
+        .bind(session_id)
+        .execute(executor)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// Succès débloqués pendant une session
+    pub async fn find_by_session(
+        pool: &PgPool,
+        session_id: Uuid,
+    ) -> Result<Vec<UserAchievement>, sqlx::Error> {
+        sqlx::query_as::<_, UserAchievement>(
+            r#"
+            SELECT a.code, a.domain, a.titre, a.description, a.icon, ua.unlocked_at, ua.session_id
+            FROM user_achievements ua
+            JOIN achievements a ON a.code = ua.achievement_code
+            WHERE ua.session_id = $1
+            ORDER BY ua.unlocked_at
+            "#,
+        )
+        .bind(session_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Catalogue vu par un joueur : débloqués d'abord (les plus récents en tête)
+    pub async fn find_for_user(
+        pool: &PgPool,
+        user_id: Uuid,
+    ) -> Result<Vec<UserAchievement>, sqlx::Error> {
+        sqlx::query_as::<_, UserAchievement>(
+            r#"
+            SELECT a.code, a.domain, a.titre, a.description, a.icon, ua.unlocked_at, ua.session_id
+            FROM achievements a
+            LEFT JOIN user_achievements ua
+                   ON ua.achievement_code = a.code AND ua.user_id = $1
+            WHERE a.is_active OR ua.unlocked_at IS NOT NULL
+            ORDER BY ua.unlocked_at DESC NULLS LAST, a.domain NULLS FIRST, a.code
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Sessions terminées et sessions à 100 % (hors révisions)
+    pub async fn count_sessions(
+        executor: impl PgExecutor<'_>,
+        user_id: Uuid,
+        domain: Option<&str>,
+    ) -> Result<(i64, i64), sqlx::Error> {
+        sqlx::query_as::<_, (i64, i64)>(
+            r#"
+            SELECT COUNT(*), COUNT(*) FILTER (WHERE s.pourcentage >= 100)
+            FROM sessions_quiz s
+            JOIN quizzes q ON q.id = s.quiz_id
+            WHERE s.user_id = $1
+              AND s.status = 'termine'
+              AND COALESCE(s.metadata->>'kind', '') <> 'review'
+              AND ($2::TEXT IS NULL OR q.domain = $2)
+            "#,
+        )
+        .bind(user_id)
+        .bind(domain)
+        .fetch_one(executor)
+        .await
+    }
+
+    /// Bonnes réponses données en moins de `max_ratio` du temps imparti
+    pub async fn count_fast_answers(
+        executor: impl PgExecutor<'_>,
+        user_id: Uuid,
+        domain: Option<&str>,
+        max_ratio: f64,
+    ) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT COUNT(*)
+            FROM reponses_utilisateur r
+            JOIN sessions_quiz s ON s.id = r.session_id
+            JOIN questions qu ON qu.id = r.question_id
+            JOIN quizzes q ON q.id = qu.quiz_id
+            WHERE s.user_id = $1
+              AND r.is_correct
+              AND qu.temps_limite_sec > 0
+              AND r.temps_reponse_sec < qu.temps_limite_sec * $3
+              AND ($2::TEXT IS NULL OR q.domain = $2)
+            "#,
+        )
+        .bind(user_id)
+        .bind(domain)
+        .bind(max_ratio)
+        .fetch_one(executor)
+        .await
+    }
+
+    /// Quiz actifs (portée) et s'ils ont été terminés au moins une fois par le joueur
+    pub async fn find_quiz_completions(
+        executor: impl PgExecutor<'_>,
+        user_id: Uuid,
+        domain: Option<&str>,
+    ) -> Result<Vec<(String, bool)>, sqlx::Error> {
+        sqlx::query_as::<_, (String, bool)>(
+            r#"
+            SELECT q.scope, EXISTS (
+                SELECT 1 FROM sessions_quiz s
+                WHERE s.quiz_id = q.id AND s.user_id = $1 AND s.status = 'termine'
+            )
+            FROM quizzes q
+            WHERE q.is_active AND ($2::TEXT IS NULL OR q.domain = $2)
+            "#,
+        )
+        .bind(user_id)
+        .bind(domain)
+        .fetch_all(executor)
+        .await
+    }
+}
@@ -0,0 +1,83 @@
+use sqlx::PgPool;
+
+use crate::models::Domain;
+
+pub struct DomainRepository;
+
+impl DomainRepository {
+    /// Trouver tous les domaines actifs
+    pub async fn find_all_active(pool: &PgPool) -> Result<Vec<Domain>, sqlx::Error> {
+        sqlx::query_as::<_, Domain>(
+            "SELECT * FROM domains WHERE is_active = true ORDER BY name ASC",
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Trouver tous les domaines (actifs ou non)
+    pub async fn find_all(pool: &PgPool) -> Result<Vec<Domain>, sqlx::Error> {
+        sqlx::query_as::<_, Domain>("SELECT * FROM domains ORDER BY name ASC")
+            .fetch_all(pool)
+            .await
+    }
+
+    /// Activer/désactiver un domaine
+    pub async fn set_active(
+        pool: &PgPool,
+        name: &str,
+        is_active: bool,
+    ) -> Result<Option<Domain>, sqlx::Error> {
+        sqlx::query_as::<_, Domain>(
+            "UPDATE domains SET is_active = $2, updated_at = NOW() WHERE name = $1 RETURNING *",
+        )
+        .bind(name)
+        .bind(is_active)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Remplacer la configuration d'un domaine (`domains.config`)
+    pub async fn update_config(
+        pool: &PgPool,
+        name: &str,
+        config: &serde_json::Value,
+    ) -> Result<Option<Domain>, sqlx::Error> {
+        sqlx::query_as::<_, Domain>(
+            "UPDATE domains SET config = $2, updated_at = NOW() WHERE name = $1 RETURNING *",
+        )
+        .bind(name)
+        .bind(config)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Prochaine version du registry de plugins (séquence persistée, monotone)
+    pub async fn next_registry_version(pool: &PgPool) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar("SELECT nextval('plugin_registry_version_seq')")
+            .fetch_one(pool)
+            .await
+    }
+
+    /// Dernière version du registry tirée par une instance (0 si aucune)
+    pub async fn last_registry_version(pool: &PgPool) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar(
+            "SELECT CASE WHEN is_called THEN last_value ELSE last_value - 1 END FROM plugin_registry_version_seq",
+        )
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Prévenir les autres instances qu'un nouveau registry est disponible
+    pub async fn notify_registry_reload(
+        pool: &PgPool,
+        channel: &str,
+        version: i64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query("SELECT pg_notify($1, $2)")
+            .bind(channel)
+            .bind(version.to_string())
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+}
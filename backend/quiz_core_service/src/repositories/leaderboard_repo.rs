@@ -0,0 +1,197 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::models::LeaderboardEntry;
+
+/// Résultat d'une session terminée, à reporter dans les classements
+pub struct LeaderboardSession {
+    pub session_id: Uuid,
+    pub user_id: Uuid,
+    pub score: i32,
+    pub temps_total_sec: i32,
+    pub achieved_at: DateTime<Utc>,
+}
+
+pub struct LeaderboardRepository;
+
+impl LeaderboardRepository {
+    /// Classement d'un quiz : garder la meilleure session de chaque fenêtre
+    pub async fn record_best(
+        executor: impl PgExecutor<'_>,
+        quiz_id: Uuid,
+        session: &LeaderboardSession,
+        periods: &[String],
+        period_starts: &[NaiveDate],
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO leaderboard_entries (
+                scope, scope_key, period, period_start, user_id,
+                score, temps_total_sec, session_id, achieved_at
+            )
+            SELECT 'quiz', $1, w.period, w.period_start, $2, $3, $4, $5, $6
+            FROM UNNEST($7::TEXT[], $8::DATE[]) AS w(period, period_start)
+            ON CONFLICT (scope, scope_key, period, period_start, user_id) DO UPDATE
+            SET sessions_count = leaderboard_entries.sessions_count + 1,
+                score = GREATEST(leaderboard_entries.score, EXCLUDED.score),
+                temps_total_sec = CASE
+                    WHEN EXCLUDED.score > leaderboard_entries.score
+                      OR (EXCLUDED.score = leaderboard_entries.score
+                          AND EXCLUDED.temps_total_sec < leaderboard_entries.temps_total_sec)
+                    THEN EXCLUDED.temps_total_sec ELSE leaderboard_entries.temps_total_sec END,
+                session_id = CASE
+                    WHEN EXCLUDED.score > leaderboard_entries.score
+                      OR (EXCLUDED.score = leaderboard_entries.score
+                          AND EXCLUDED.temps_total_sec < leaderboard_entries.temps_total_sec)
+                    THEN EXCLUDED.session_id ELSE leaderboard_entries.session_id END,
+                achieved_at = CASE
+                    WHEN EXCLUDED.score > leaderboard_entries.score
+                      OR (EXCLUDED.score = leaderboard_entries.score
+                          AND EXCLUDED.temps_total_sec < leaderboard_entries.temps_total_sec)
+                    THEN EXCLUDED.achieved_at ELSE leaderboard_entries.achieved_at END
+            "#,
+        )
+        .bind(quiz_id.to_string())
+        .bind(session.user_id)
+        .bind(session.score)
+        .bind(session.temps_total_sec)
+        .bind(session.session_id)
+        .bind(session.achieved_at)
+        .bind(periods)
+        .bind(period_starts)
+        .execute(executor)
+        .await?;
+        Ok(())
+    }
+
+    /// Classement d'un domaine : cumuler les sessions de chaque fenêtre
+    pub async fn record_cumulative(
+        executor: impl PgExecutor<'_>,
+        domain: &str,
+        session: &LeaderboardSession,
+        periods: &[String],
+        period_starts: &[NaiveDate],
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO leaderboard_entries (
+                scope, scope_key, period, period_start, user_id,
+                score, temps_total_sec, session_id, achieved_at
+            )
+            SELECT 'domain', $1, w.period, w.period_start, $2, $3, $4, $5, $6
+            FROM UNNEST($7::TEXT[], $8::DATE[]) AS w(period, period_start)
+            ON CONFLICT (scope, scope_key, period, period_start, user_id) DO UPDATE
+            SET sessions_count = leaderboard_entries.sessions_count + 1,
+                score = leaderboard_entries.score + EXCLUDED.score,
+                temps_total_sec = leaderboard_entries.temps_total_sec + EXCLUDED.temps_total_sec,
+                session_id = EXCLUDED.session_id,
+                achieved_at = GREATEST(leaderboard_entries.achieved_at, EXCLUDED.achieved_at)
+            "#,
+        )
+        .bind(domain)
+        .bind(session.user_id)
+        .bind(session.score)
+        .bind(session.temps_total_sec)
+        .bind(session.session_id)
+        .bind(session.achieved_at)
+        .bind(periods)
+        .bind(period_starts)
+        .execute(executor)
+        .await?;
+        Ok(())
+    }
+
+    /// Page du classement d'une fenêtre
+    pub async fn find_top(
+        pool: &PgPool,
+        scope: &str,
+        scope_key: &str,
+        period: &str,
+        period_start: NaiveDate,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<LeaderboardEntry>, sqlx::Error> {
+        sqlx::query_as::<_, LeaderboardEntry>(
+            r#"
+            WITH ranked AS (
+                -- Score décroissant, puis temps croissant (ex aequo sur les deux)
+                SELECT RANK() OVER (ORDER BY score DESC, temps_total_sec ASC) AS rank,
+                       ROW_NUMBER() OVER (ORDER BY score DESC, temps_total_sec ASC, user_id) AS position,
+                       user_id, score, temps_total_sec, sessions_count, achieved_at
+                FROM leaderboard_entries
+                WHERE scope = $1 AND scope_key = $2 AND period = $3 AND period_start = $4
+            )
+            SELECT * FROM ranked
+            ORDER BY position
+            LIMIT $5 OFFSET $6
+            "#,
+        )
+        .bind(scope)
+        .bind(scope_key)
+        .bind(period)
+        .bind(period_start)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Le joueur et ses `radius` voisins de chaque côté (vide s'il n'est pas classé)
+    pub async fn find_around(
+        pool: &PgPool,
+        scope: &str,
+        scope_key: &str,
+        period: &str,
+        period_start: NaiveDate,
+        user_id: Uuid,
+        radius: i64,
+    ) -> Result<Vec<LeaderboardEntry>, sqlx::Error> {
+        sqlx::query_as::<_, LeaderboardEntry>(
+            r#"
+            WITH ranked AS (
+                -- Score décroissant, puis temps croissant (ex aequo sur les deux)
+                SELECT RANK() OVER (ORDER BY score DESC, temps_total_sec ASC) AS rank,
+                       ROW_NUMBER() OVER (ORDER BY score DESC, temps_total_sec ASC, user_id) AS position,
+                       user_id, score, temps_total_sec, sessions_count, achieved_at
+                FROM leaderboard_entries
+                WHERE scope = $1 AND scope_key = $2 AND period = $3 AND period_start = $4
+            )
+            SELECT ranked.* FROM ranked
+            JOIN ranked me ON me.user_id = $5
+            WHERE ranked.position BETWEEN me.position - $6 AND me.position + $6
+            ORDER BY ranked.position
+            "#,
+        )
+        .bind(scope)
+        .bind(scope_key)
+        .bind(period)
+        .bind(period_start)
+        .bind(user_id)
+        .bind(radius)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Nombre de joueurs classés dans une fenêtre
+    pub async fn count(
+        pool: &PgPool,
+        scope: &str,
+        scope_key: &str,
+        period: &str,
+        period_start: NaiveDate,
+    ) -> Result<i64, sqlx::Error> {
+        sqlx::query_scalar::<_, i64>(
+            r#"
+            SELECT COUNT(*) FROM leaderboard_entries
+            WHERE scope = $1 AND scope_key = $2 AND period = $3 AND period_start = $4
+            "#,
+        )
+        .bind(scope)
+        .bind(scope_key)
+        .bind(period)
+        .bind(period_start)
+        .fetch_one(pool)
+        .await
+    }
+}
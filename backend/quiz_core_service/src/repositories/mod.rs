@@ -1,9 +1,21 @@
+pub mod achievement_repo;
+pub mod domain_repo;
+pub mod leaderboard_repo;
+pub mod progress_repo;
 pub mod question_repo;
 pub mod quiz_repo;
 pub mod reponse_repo;
+pub mod review_repo;
 pub mod session_repo;
+pub mod skill_repo;
 
+pub use achievement_repo::AchievementRepository;
+pub use domain_repo::DomainRepository;
+pub use leaderboard_repo::LeaderboardRepository;
+pub use progress_repo::ProgressRepository;
 pub use question_repo::QuestionRepository;
 pub use quiz_repo::QuizRepository;
 pub use reponse_repo::ReponseRepository;
+pub use review_repo::ReviewRepository;
 pub use session_repo::SessionRepository;
+pub use skill_repo::SkillRepository;
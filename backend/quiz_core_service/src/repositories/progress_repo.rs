@@ -0,0 +1,222 @@
+use chrono::{DateTime, Utc};
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::{
+    models::{UserDomainProgress, UserProgress},
+    services::progression::StreakState,
+};
+
+/// Session terminée, avec ce qu'il faut du quiz pour calculer l'XP
+#[derive(Debug, sqlx::FromRow)]
+pub struct FinishedSession {
+    pub date_fin: DateTime<Utc>,
+    pub pourcentage: Option<f64>,
+    pub domain: String,
+    pub niveau_difficulte: String,
+    pub mode: String,
+}
+
+pub struct ProgressRepository;
+
+impl ProgressRepository {
+    pub async fn find(
+        executor: impl PgExecutor<'_>,
+        user_id: Uuid,
+    ) -> Result<Option<UserProgress>, sqlx::Error> {
+        sqlx::query_as::<_, UserProgress>("SELECT * FROM user_progress WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_optional(executor)
+            .await
+    }
+
+    pub async fn find_domains(
+        pool: &PgPool,
+        user_id: Uuid,
+    ) -> Result<Vec<UserDomainProgress>, sqlx::Error> {
+        sqlx::query_as::<_, UserDomainProgress>(
+            "SELECT * FROM user_domain_progress WHERE user_id = $1 ORDER BY xp DESC, domain",
+        )
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Ajouter une session terminée : XP cumulée, nouvelle série
+    pub async fn add_session(
+        executor: impl PgExecutor<'_>,
+        user_id: Uuid,
+        xp: i64,
+        streak: &StreakState,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO user_progress (
+                user_id, xp, sessions_count,
+                current_streak, best_streak, grace_tokens, last_played_on
+            )
+            VALUES ($1, $2, 1, $3, $4, $5, $6)
+            ON CONFLICT (user_id) DO UPDATE
+            SET xp = user_progress.xp + EXCLUDED.xp,
+                sessions_count = user_progress.sessions_count + 1,
+                current_streak = EXCLUDED.current_streak,
+                best_streak = EXCLUDED.best_streak,
+                grace_tokens = EXCLUDED.grace_tokens,
+                last_played_on = EXCLUDED.last_played_on,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(user_id)
+        .bind(xp)
+        .bind(streak.current)
+        .bind(streak.best)
+        .bind(streak.grace_tokens)
+        .bind(streak.last_played_on)
+        .execute(executor)
+        .await?;
+        Ok(())
+    }
+
+    /// Ajouter l'XP et les réponses d'une session à un domaine
+    pub async fn add_domain(
+        executor: impl PgExecutor<'_>,
+        user_id: Uuid,
+        domain: &str,
+        xp: i64,
+        answers_count: i32,
+        correct_count: i32,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO user_domain_progress (user_id, domain, xp, answers_count, correct_count)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (user_id, domain) DO UPDATE
+            SET xp = user_domain_progress.xp + EXCLUDED.xp,
+                answers_count = user_domain_progress.answers_count + EXCLUDED.answers_count,
+                correct_count = user_domain_progress.correct_count + EXCLUDED.correct_count,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(user_id)
+        .bind(domain)
+        .bind(xp)
+        .bind(answers_count)
+        .bind(correct_count)
+        .execute(executor)
+        .await?;
+        Ok(())
+    }
+
+    /// Remplacer la progression globale (reconstruction ; le fuseau est conservé)
+    pub async fn replace(
+        pool: &PgPool,
+        user_id: Uuid,
+        xp: i64,
+        sessions_count: i32,
+        streak: &StreakState,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO user_progress (
+                user_id, xp, sessions_count,
+                current_streak, best_streak, grace_tokens, last_played_on
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (user_id) DO UPDATE
+            SET xp = EXCLUDED.xp,
+                sessions_count = EXCLUDED.sessions_count,
+                current_streak = EXCLUDED.current_streak,
+                best_streak = EXCLUDED.best_streak,
+                grace_tokens = EXCLUDED.grace_tokens,
+                last_played_on = EXCLUDED.last_played_on,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(user_id)
+        .bind(xp)
+        .bind(sessions_count)
+        .bind(streak.current)
+        .bind(streak.best)
+        .bind(streak.grace_tokens)
+        .bind(streak.last_played_on)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn delete_domains(pool: &PgPool, user_id: Uuid) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM user_domain_progress WHERE user_id = $1")
+            .bind(user_id)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Fuseau horaire du joueur (IANA, ex: "Europe/Paris")
+    pub async fn set_timezone(
+        pool: &PgPool,
+        user_id: Uuid,
+        timezone: &str,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            INSERT INTO user_progress (user_id, timezone) VALUES ($1, $2)
+            ON CONFLICT (user_id) DO UPDATE SET timezone = EXCLUDED.timezone, updated_at = NOW()
+            "#,
+        )
+        .bind(user_id)
+        .bind(timezone)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Sessions terminées d'un joueur, dans l'ordre chronologique
+    pub async fn find_finished_sessions(
+        pool: &PgPool,
+        user_id: Uuid,
+    ) -> Result<Vec<FinishedSession>, sqlx::Error> {
+        sqlx::query_as::<_, FinishedSession>(
+            r#"
+            SELECT COALESCE(s.date_fin, s.date_debut) AS date_fin, s.pourcentage,
+                   q.domain, q.niveau_difficulte, q.mode
+            FROM sessions_quiz s
+            JOIN quizzes q ON q.id = s.quiz_id
+            WHERE s.user_id = $1 AND s.status = 'termine'
+            ORDER BY date_fin
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Réponses et bonnes réponses par domaine (sessions terminées)
+    pub async fn count_domain_answers(
+        pool: &PgPool,
+        user_id: Uuid,
+    ) -> Result<Vec<(String, i64, i64)>, sqlx::Error> {
+        sqlx::query_as::<_, (String, i64, i64)>(
+            r#"
+            SELECT q.domain, COUNT(*), COUNT(*) FILTER (WHERE r.is_correct)
+            FROM reponses_utilisateur r
+            JOIN sessions_quiz s ON s.id = r.session_id
+            JOIN quizzes q ON q.id = s.quiz_id
+            WHERE s.user_id = $1 AND s.status = 'termine'
+            GROUP BY q.domain
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Joueurs ayant au moins une session terminée
+    pub async fn find_user_ids(pool: &PgPool) -> Result<Vec<Uuid>, sqlx::Error> {
+        sqlx::query_scalar::<_, Uuid>(
+            "SELECT DISTINCT user_id FROM sessions_quiz WHERE status = 'termine'",
+        )
+        .fetch_all(pool)
+        .await
+    }
+}
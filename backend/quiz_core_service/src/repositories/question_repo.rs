@@ -1,4 +1,4 @@
-use sqlx::PgPool;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
 use crate::models::{Question, Reponse};
@@ -139,6 +139,30 @@ impl QuestionRepository {
         Ok(())
     }
 
+    /// Comptabiliser une tentative (atomique) et ajuster la difficulté Elo
+    pub async fn record_attempt(
+        executor: impl PgExecutor<'_>,
+        id: Uuid,
+        is_correct: bool,
+        difficulty_delta: f64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE questions
+            SET total_attempts = COALESCE(total_attempts, 0) + 1,
+                correct_attempts = COALESCE(correct_attempts, 0) + CASE WHEN $2 THEN 1 ELSE 0 END,
+                difficulty_rating = difficulty_rating + $3
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(is_correct)
+        .bind(difficulty_delta)
+        .execute(executor)
+        .await?;
+        Ok(())
+    }
+
     /// ✅ NOUVEAU : Récupérer questions avec leurs réponses
     pub async fn find_by_quiz_id_with_reponses(
         pool: &PgPool,
@@ -162,4 +186,42 @@ impl QuestionRepository {
 
         Ok(result)
     }
+
+    /// Questions des quiz actifs d'un domaine, avec leurs réponses (ordre stable : par id)
+    pub async fn find_by_domain_with_reponses(
+        pool: &PgPool,
+        domain: &str,
+    ) -> Result<Vec<(Question, Vec<Reponse>)>, sqlx::Error> {
+        let questions = sqlx::query_as::<_, Question>(
+            r#"
+            SELECT qu.* FROM questions qu
+            JOIN quizzes q ON q.id = qu.quiz_id
+            WHERE q.domain = $1 AND q.is_active = true
+            ORDER BY qu.id
+            "#,
+        )
+        .bind(domain)
+        .fetch_all(pool)
+        .await?;
+
+        let question_ids: Vec<Uuid> = questions.iter().map(|q| q.id).collect();
+        let reponses = sqlx::query_as::<_, Reponse>(
+            "SELECT * FROM reponses WHERE question_id = ANY($1) ORDER BY ordre ASC",
+        )
+        .bind(&question_ids)
+        .fetch_all(pool)
+        .await?;
+
+        Ok(questions
+            .into_iter()
+            .map(|question| {
+                let question_reponses = reponses
+                    .iter()
+                    .filter(|r| r.question_id == question.id)
+                    .cloned()
+                    .collect();
+                (question, question_reponses)
+            })
+            .collect())
+    }
 }
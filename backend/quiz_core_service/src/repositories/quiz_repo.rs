@@ -1,7 +1,8 @@
-use sqlx::PgPool;
+use chrono::NaiveDate;
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
-use crate::models::Quiz;
+use crate::models::{DailyChallengeSummary, Quiz};
 
 pub struct QuizRepository;
 
@@ -67,6 +68,133 @@ impl QuizRepository {
         .await
     }
 
+    /// Quiz technique de révision d'un domaine (créé au premier besoin)
+    /// Masqué des listes : `is_active = false`, `metadata.kind = "review"`
+    pub async fn find_or_create_review_quiz(
+        pool: &PgPool,
+        domain: &str,
+    ) -> Result<Quiz, sqlx::Error> {
+        sqlx::query_as::<_, Quiz>(
+            r#"
+            INSERT INTO quizzes (
+                domain, titre, description, niveau_difficulte, scope, mode,
+                nb_questions, is_active, is_public, metadata
+            )
+            VALUES ($1, 'Révisions', 'Questions à réviser (répétition espacée)', 'moyen',
+                    'revision', 'entrainement', 0, false, false, '{"kind": "review"}'::jsonb)
+            ON CONFLICT (domain) WHERE metadata->>'kind' = 'review'
+            DO UPDATE SET updated_at = quizzes.updated_at
+            RETURNING *
+            "#,
+        )
+        .bind(domain)
+        .fetch_one(pool)
+        .await
+    }
+
+    /// Défi du jour d'un domaine (`metadata.kind = "daily_challenge"`)
+    pub async fn find_daily_challenge(
+        pool: &PgPool,
+        domain: &str,
+        date: NaiveDate,
+    ) -> Result<Option<Quiz>, sqlx::Error> {
+        sqlx::query_as::<_, Quiz>(
+            r#"
+            SELECT * FROM quizzes
+            WHERE domain = $1
+              AND metadata->>'kind' = 'daily_challenge'
+              AND metadata->>'date' = $2
+            "#,
+        )
+        .bind(domain)
+        .bind(date.to_string())
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Enregistrer le défi du jour (None s'il vient d'être créé par une autre requête)
+    /// Masqué des listes : `is_active = false`
+    pub async fn create_daily_challenge(
+        pool: &PgPool,
+        domain: &str,
+        date: NaiveDate,
+        nb_questions: i32,
+        metadata: &serde_json::Value,
+    ) -> Result<Option<Quiz>, sqlx::Error> {
+        sqlx::query_as::<_, Quiz>(
+            r#"
+            INSERT INTO quizzes (
+                domain, titre, description, niveau_difficulte, scope, mode,
+                nb_questions, is_active, is_public, metadata
+            )
+            VALUES ($1, 'Défi du jour', $2, 'moyen', 'defi', 'defi_du_jour',
+                    $3, false, true, $4)
+            ON CONFLICT (domain, (metadata->>'date')) WHERE metadata->>'kind' = 'daily_challenge'
+            DO NOTHING
+            RETURNING *
+            "#,
+        )
+        .bind(domain)
+        .bind(format!("Défi du jour du {}", date))
+        .bind(nb_questions)
+        .bind(metadata)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Défis passés d'un domaine (les plus récents d'abord), avec le résultat du joueur
+    pub async fn find_daily_challenges(
+        pool: &PgPool,
+        domain: &str,
+        user_id: Option<Uuid>,
+        limit: i64,
+    ) -> Result<Vec<DailyChallengeSummary>, sqlx::Error> {
+        sqlx::query_as::<_, DailyChallengeSummary>(
+            r#"
+            SELECT q.id AS quiz_id,
+                   (q.metadata->>'date')::DATE AS date,
+                   q.nb_questions,
+                   COUNT(s.id) FILTER (WHERE s.status = 'termine') AS players,
+                   MAX(s.score) FILTER (WHERE s.status = 'termine') AS best_score,
+                   COALESCE(BOOL_OR(s.user_id = $2), false) AS played,
+                   MAX(s.score) FILTER (WHERE s.status = 'termine' AND s.user_id = $2) AS my_score
+            FROM quizzes q
+            LEFT JOIN sessions_quiz s ON s.quiz_id = q.id
+            WHERE q.domain = $1 AND q.metadata->>'kind' = 'daily_challenge'
+            GROUP BY q.id
+            ORDER BY date DESC
+            LIMIT $3
+            "#,
+        )
+        .bind(domain)
+        .bind(user_id)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Comptabiliser une session terminée : moyenne glissante du pourcentage (atomique)
+    pub async fn record_session_score(
+        executor: impl PgExecutor<'_>,
+        id: Uuid,
+        pourcentage: f64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            r#"
+            UPDATE quizzes
+            SET average_score = (COALESCE(average_score, 0) * COALESCE(total_attempts, 0) + $2)
+                    / (COALESCE(total_attempts, 0) + 1),
+                total_attempts = COALESCE(total_attempts, 0) + 1
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(pourcentage)
+        .execute(executor)
+        .await?;
+        Ok(())
+    }
+
     /// 🆕 Compter les quiz par domaine
     pub async fn count_by_domain(pool: &PgPool, domain: &str) -> Result<i64, sqlx::Error> {
         sqlx::query_scalar("SELECT COUNT(*) FROM quizzes WHERE domain = $1")
@@ -2,6 +2,16 @@ use crate::models::Reponse;
 use sqlx::PgPool;
 use uuid::Uuid;
 
+/// Champs d'une réponse à créer ou mettre à jour
+pub struct ReponseFields<'a> {
+    pub valeur: Option<&'a str>,
+    pub region_id: Option<Uuid>,
+    pub is_correct: bool,
+    pub ordre: Option<i32>,
+    pub tolerance_meters: Option<i32>,
+    pub metadata: Option<&'a serde_json::Value>,
+}
+
 pub struct ReponseRepository;
 
 impl ReponseRepository {
@@ -55,25 +65,22 @@ impl ReponseRepository {
     pub async fn create(
         pool: &PgPool,
         question_id: Uuid,
-        valeur: Option<&str>,
-        region_id: Option<Uuid>,
-        is_correct: bool,
-        ordre: Option<i32>,
-        tolerance_meters: Option<i32>,
+        fields: &ReponseFields<'_>,
     ) -> Result<Reponse, sqlx::Error> {
         sqlx::query_as::<_, Reponse>(
             r#"
-            INSERT INTO reponses (question_id, valeur, region_id, is_correct, ordre, tolerance_meters)
-            VALUES ($1, $2, $3, $4, $5, $6)
+            INSERT INTO reponses (question_id, valeur, region_id, is_correct, ordre, tolerance_meters, metadata)
+            VALUES ($1, $2, $3, $4, $5, $6, COALESCE($7, '{}'::jsonb))
             RETURNING *
             "#
         )
             .bind(question_id)
-            .bind(valeur)
-            .bind(region_id)
-            .bind(is_correct)
-            .bind(ordre)
-            .bind(tolerance_meters)
+            .bind(fields.valeur)
+            .bind(fields.region_id)
+            .bind(fields.is_correct)
+            .bind(fields.ordre)
+            .bind(fields.tolerance_meters)
+            .bind(fields.metadata)
             .fetch_one(pool)
             .await
     }
@@ -82,26 +89,24 @@ impl ReponseRepository {
     pub async fn update(
         pool: &PgPool,
         id: Uuid,
-        valeur: Option<&str>,
-        region_id: Option<Uuid>,
-        is_correct: bool,
-        ordre: Option<i32>,
-        tolerance_meters: Option<i32>,
+        fields: &ReponseFields<'_>,
     ) -> Result<Option<Reponse>, sqlx::Error> {
         sqlx::query_as::<_, Reponse>(
             r#"
             UPDATE reponses
-            SET valeur = $2, region_id = $3, is_correct = $4, ordre = $5, tolerance_meters = $6
+            SET valeur = $2, region_id = $3, is_correct = $4, ordre = $5, tolerance_meters = $6,
+                metadata = COALESCE($7, metadata)
             WHERE id = $1
             RETURNING *
             "#,
         )
         .bind(id)
-        .bind(valeur)
-        .bind(region_id)
-        .bind(is_correct)
-        .bind(ordre)
-        .bind(tolerance_meters)
+        .bind(fields.valeur)
+        .bind(fields.region_id)
+        .bind(fields.is_correct)
+        .bind(fields.ordre)
+        .bind(fields.tolerance_meters)
+        .bind(fields.metadata)
         .fetch_optional(pool)
         .await
     }
@@ -0,0 +1,151 @@
+use chrono::{DateTime, Utc};
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+use crate::models::ReviewCard;
+use crate::services::spaced_repetition::CardSchedule;
+
+/// Réponse passée rejouée pour reconstruire les cartes
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ReviewHistoryEntry {
+    pub question_id: Uuid,
+    pub is_correct: bool,
+    pub partial_score: Option<f64>,
+    pub temps_reponse_sec: i32,
+    pub temps_limite_sec: Option<i32>,
+    pub created_at: DateTime<Utc>,
+}
+
+pub struct ReviewRepository;
+
+impl ReviewRepository {
+    /// Carte d'un joueur pour une question
+    pub async fn find(
+        executor: impl PgExecutor<'_>,
+        user_id: Uuid,
+        question_id: Uuid,
+    ) -> Result<Option<ReviewCard>, sqlx::Error> {
+        sqlx::query_as::<_, ReviewCard>(
+            "SELECT * FROM review_cards WHERE user_id = $1 AND question_id = $2",
+        )
+        .bind(user_id)
+        .bind(question_id)
+        .fetch_optional(executor)
+        .await
+    }
+
+    /// Enregistrer l'état d'une carte après révision
+    pub async fn upsert(
+        executor: impl PgExecutor<'_>,
+        user_id: Uuid,
+        question_id: Uuid,
+        schedule: &CardSchedule,
+        reviewed_at: DateTime<Utc>,
+    ) -> Result<ReviewCard, sqlx::Error> {
+        sqlx::query_as::<_, ReviewCard>(
+            r#"
+            INSERT INTO review_cards (
+                user_id, question_id, ease_factor, interval_days,
+                repetitions, lapses, due_at, last_reviewed_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            ON CONFLICT (user_id, question_id) DO UPDATE
+            SET ease_factor = EXCLUDED.ease_factor,
+                interval_days = EXCLUDED.interval_days,
+                repetitions = EXCLUDED.repetitions,
+                lapses = EXCLUDED.lapses,
+                due_at = EXCLUDED.due_at,
+                last_reviewed_at = EXCLUDED.last_reviewed_at
+            RETURNING *
+            "#,
+        )
+        .bind(user_id)
+        .bind(question_id)
+        .bind(schedule.ease_factor)
+        .bind(schedule.interval_days)
+        .bind(schedule.repetitions)
+        .bind(schedule.lapses)
+        .bind(schedule.due_at(reviewed_at))
+        .bind(reviewed_at)
+        .fetch_one(executor)
+        .await
+    }
+
+    /// Questions à réviser d'un domaine (les plus en retard d'abord)
+    pub async fn find_due_question_ids(
+        pool: &PgPool,
+        user_id: Uuid,
+        domain: &str,
+        limit: i64,
+    ) -> Result<Vec<Uuid>, sqlx::Error> {
+        sqlx::query_scalar(
+            r#"
+            SELECT c.question_id
+            FROM review_cards c
+            JOIN questions q ON q.id = c.question_id
+            JOIN quizzes qz ON qz.id = q.quiz_id
+            WHERE c.user_id = $1 AND qz.domain = $2 AND c.due_at <= NOW()
+            ORDER BY c.due_at ASC
+            LIMIT $3
+            "#,
+        )
+        .bind(user_id)
+        .bind(domain)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Nombre de cartes à réviser par domaine
+    pub async fn count_due_by_domain(
+        pool: &PgPool,
+        user_id: Uuid,
+    ) -> Result<Vec<(String, i64)>, sqlx::Error> {
+        sqlx::query_as(
+            r#"
+            SELECT qz.domain, COUNT(*)
+            FROM review_cards c
+            JOIN questions q ON q.id = c.question_id
+            JOIN quizzes qz ON qz.id = q.quiz_id
+            WHERE c.user_id = $1 AND c.due_at <= NOW()
+            GROUP BY qz.domain
+            ORDER BY qz.domain ASC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Réponses d'entraînement d'un joueur, dans l'ordre chronologique
+    pub async fn find_history(
+        pool: &PgPool,
+        user_id: Uuid,
+    ) -> Result<Vec<ReviewHistoryEntry>, sqlx::Error> {
+        sqlx::query_as::<_, ReviewHistoryEntry>(
+            r#"
+            SELECT ru.question_id, ru.is_correct,
+                   (ru.metadata->>'partial_score')::DOUBLE PRECISION AS partial_score,
+                   ru.temps_reponse_sec, q.temps_limite_sec, ru.created_at
+            FROM reponses_utilisateur ru
+            JOIN sessions_quiz s ON s.id = ru.session_id
+            JOIN quizzes qz ON qz.id = s.quiz_id
+            JOIN questions q ON q.id = ru.question_id
+            WHERE s.user_id = $1 AND qz.mode = 'entrainement'
+            ORDER BY ru.created_at ASC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Supprimer toutes les cartes d'un joueur
+    pub async fn delete_for_user(pool: &PgPool, user_id: Uuid) -> Result<(), sqlx::Error> {
+        sqlx::query("DELETE FROM review_cards WHERE user_id = $1")
+            .bind(user_id)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+}
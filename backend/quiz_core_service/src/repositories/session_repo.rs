@@ -1,7 +1,36 @@
-use crate::models::{ReponseUtilisateur, SessionQuiz};
-use sqlx::PgPool;
+use crate::{
+    dto::Coordinates,
+    models::{ReponseUtilisateur, SessionQuiz},
+};
+use chrono::{DateTime, Utc};
+use sqlx::{PgExecutor, PgPool};
 use uuid::Uuid;
 
+/// Session déjà terminée à enregistrer (partie de compétition)
+pub struct CompletedSession {
+    pub user_id: Uuid,
+    pub quiz_id: Uuid,
+    pub score: i32,
+    pub score_max: i32,
+    pub date_debut: DateTime<Utc>,
+    pub temps_total_sec: i32,
+    pub metadata: serde_json::Value,
+}
+
+/// Réponse d'un joueur à enregistrer
+pub struct NewUserAnswer<'a> {
+    pub session_id: Uuid,
+    pub question_id: Uuid,
+    pub reponse_id: Option<Uuid>,
+    pub reponse_ids: &'a [Uuid],
+    pub valeur_saisie: Option<&'a str>,
+    pub coordinates_cliquees: Option<&'a Coordinates>,
+    pub is_correct: bool,
+    pub points_obtenus: i32,
+    pub temps_reponse_sec: i32,
+    pub metadata: &'a serde_json::Value,
+}
+
 pub struct SessionRepository;
 
 impl SessionRepository {
@@ -10,21 +39,49 @@ impl SessionRepository {
         user_id: Uuid,
         quiz_id: Uuid,
         score_max: i32,
+        metadata: &serde_json::Value,
     ) -> Result<SessionQuiz, sqlx::Error> {
         sqlx::query_as::<_, SessionQuiz>(
             r#"
-            INSERT INTO sessions_quiz (user_id, quiz_id, score_max, date_debut)
-            VALUES ($1, $2, $3, NOW())
+            INSERT INTO sessions_quiz (user_id, quiz_id, score_max, date_debut, metadata)
+            VALUES ($1, $2, $3, NOW(), $4)
             RETURNING *
             "#,
         )
         .bind(user_id)
         .bind(quiz_id)
         .bind(score_max)
+        .bind(metadata)
         .fetch_one(pool)
         .await
     }
 
+    /// Enregistrer une session déjà terminée (partie de compétition)
+    pub async fn create_completed(
+        executor: impl PgExecutor<'_>,
+        session: &CompletedSession,
+    ) -> Result<SessionQuiz, sqlx::Error> {
+        sqlx::query_as::<_, SessionQuiz>(
+            r#"
+            INSERT INTO sessions_quiz (
+                user_id, quiz_id, score, score_max, status,
+                date_debut, date_fin, temps_total_sec, metadata
+            )
+            VALUES ($1, $2, $3, $4, 'termine', $5, NOW(), $6, $7)
+            RETURNING *
+            "#,
+        )
+        .bind(session.user_id)
+        .bind(session.quiz_id)
+        .bind(session.score)
+        .bind(session.score_max)
+        .bind(session.date_debut)
+        .bind(session.temps_total_sec)
+        .bind(&session.metadata)
+        .fetch_one(executor)
+        .await
+    }
+
     pub async fn find_by_id(pool: &PgPool, id: Uuid) -> Result<Option<SessionQuiz>, sqlx::Error> {
         sqlx::query_as::<_, SessionQuiz>("SELECT * FROM sessions_quiz WHERE id = $1")
             .bind(id)
@@ -32,6 +89,40 @@ impl SessionRepository {
             .await
     }
 
+    /// Sessions d'un joueur, filtrées par statut (les plus récentes d'abord)
+    pub async fn find_by_user(
+        pool: &PgPool,
+        user_id: Uuid,
+        status: Option<&str>,
+    ) -> Result<Vec<SessionQuiz>, sqlx::Error> {
+        sqlx::query_as::<_, SessionQuiz>(
+            r#"
+            SELECT * FROM sessions_quiz
+            WHERE user_id = $1 AND ($2::TEXT IS NULL OR status = $2)
+            ORDER BY date_debut DESC
+            "#,
+        )
+        .bind(user_id)
+        .bind(status)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Session d'un joueur sur un quiz (tentative unique du défi du jour)
+    pub async fn find_by_user_and_quiz(
+        pool: &PgPool,
+        user_id: Uuid,
+        quiz_id: Uuid,
+    ) -> Result<Option<SessionQuiz>, sqlx::Error> {
+        sqlx::query_as::<_, SessionQuiz>(
+            "SELECT * FROM sessions_quiz WHERE user_id = $1 AND quiz_id = $2 LIMIT 1",
+        )
+        .bind(user_id)
+        .bind(quiz_id)
+        .fetch_optional(pool)
+        .await
+    }
+
     pub async fn find_active_by_id(
         pool: &PgPool,
         id: Uuid,
@@ -45,20 +136,20 @@ impl SessionRepository {
     }
 
     pub async fn update_score(
-        pool: &PgPool,
+        executor: impl PgExecutor<'_>,
         session_id: Uuid,
         points: i32,
     ) -> Result<(), sqlx::Error> {
         sqlx::query("UPDATE sessions_quiz SET score = score + $1 WHERE id = $2")
             .bind(points)
             .bind(session_id)
-            .execute(pool)
+            .execute(executor)
             .await?;
         Ok(())
     }
 
     pub async fn finalize(
-        pool: &PgPool,
+        executor: impl PgExecutor<'_>,
         session_id: Uuid,
     ) -> Result<Option<SessionQuiz>, sqlx::Error> {
         sqlx::query_as::<_, SessionQuiz>(
@@ -72,46 +163,248 @@ impl SessionRepository {
             "#,
         )
         .bind(session_id)
+        .fetch_optional(executor)
+        .await
+    }
+
+    /// Abandonner une session en cours (score partiel conservé)
+    pub async fn abandon(
+        pool: &PgPool,
+        session_id: Uuid,
+        reason: &str,
+    ) -> Result<Option<SessionQuiz>, sqlx::Error> {
+        sqlx::query_as::<_, SessionQuiz>(
+            r#"
+            UPDATE sessions_quiz
+            SET status = 'abandonne',
+                date_fin = NOW(),
+                temps_total_sec = EXTRACT(EPOCH FROM (NOW() - date_debut))::INTEGER,
+                metadata = metadata || jsonb_build_object('abandon_reason', $2::TEXT)
+            WHERE id = $1 AND status = 'en_cours'
+            RETURNING *
+            "#,
+        )
+        .bind(session_id)
+        .bind(reason)
         .fetch_optional(pool)
         .await
     }
 
-    pub async fn create_user_answer(
+    /// Abandonner les sessions expirées, en une requête :
+    /// - temps du quiz (`quizzes.temps_limite_sec` + grâce) écoulé → "time_limit"
+    /// - aucune activité (démarrage, présentation, réponse) depuis `inactivity_sec` → "inactivity"
+    pub async fn abandon_expired(
+        pool: &PgPool,
+        inactivity_sec: f64,
+        grace_sec: f64,
+    ) -> Result<Vec<SessionQuiz>, sqlx::Error> {
+        sqlx::query_as::<_, SessionQuiz>(
+            r#"
+            WITH expired AS (
+                SELECT s.id,
+                       CASE
+                           WHEN q.temps_limite_sec IS NOT NULL
+                                AND s.date_debut + make_interval(secs => q.temps_limite_sec + $2) <= NOW()
+                           THEN 'time_limit'
+                           ELSE 'inactivity'
+                       END AS reason
+                FROM sessions_quiz s
+                JOIN quizzes q ON q.id = s.quiz_id
+                WHERE s.status = 'en_cours'
+                  AND (
+                      (q.temps_limite_sec IS NOT NULL
+                       AND s.date_debut + make_interval(secs => q.temps_limite_sec + $2) <= NOW())
+                      OR GREATEST(
+                          s.date_debut,
+                          (SELECT MAX(ru.created_at) FROM reponses_utilisateur ru WHERE ru.session_id = s.id),
+                          (SELECT MAX((p.value #>> '{}')::TIMESTAMPTZ)
+                           FROM jsonb_each(COALESCE(s.metadata->'presented_at', '{}'::jsonb)) p)
+                      ) <= NOW() - make_interval(secs => $1)
+                  )
+                FOR UPDATE OF s SKIP LOCKED
+            )
+            UPDATE sessions_quiz s
+            SET status = 'abandonne',
+                date_fin = NOW(),
+                temps_total_sec = EXTRACT(EPOCH FROM (NOW() - s.date_debut))::INTEGER,
+                metadata = s.metadata || jsonb_build_object('abandon_reason', e.reason)
+            FROM expired e
+            WHERE s.id = e.id
+            RETURNING s.*
+            "#,
+        )
+        .bind(inactivity_sec)
+        .bind(grace_sec)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Enregistrer l'heure (serveur) de présentation d'une question
+    /// La première présentation fait foi : la redemander ne remet pas le chrono à zéro
+    pub async fn mark_question_presented(
         pool: &PgPool,
         session_id: Uuid,
         question_id: Uuid,
-        reponse_id: Option<Uuid>,
-        valeur_saisie: Option<&str>,
-        is_correct: bool,
-        points_obtenus: i32,
-        temps_reponse_sec: i32,
+    ) -> Result<Option<SessionQuiz>, sqlx::Error> {
+        sqlx::query_as::<_, SessionQuiz>(
+            r#"
+            UPDATE sessions_quiz
+            SET metadata = jsonb_set(
+                metadata,
+                '{presented_at}',
+                jsonb_build_object($2::TEXT, to_jsonb(NOW()))
+                    || COALESCE(metadata->'presented_at', '{}'::jsonb)
+            )
+            WHERE id = $1 AND status = 'en_cours'
+            RETURNING *
+            "#,
+        )
+        .bind(session_id)
+        .bind(question_id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Enregistrer la consultation de l'indice d'une question (`metadata.hints_used`)
+    /// La première consultation fait foi
+    pub async fn mark_hint_used(
+        pool: &PgPool,
+        session_id: Uuid,
+        question_id: Uuid,
+    ) -> Result<Option<SessionQuiz>, sqlx::Error> {
+        sqlx::query_as::<_, SessionQuiz>(
+            r#"
+            UPDATE sessions_quiz
+            SET metadata = jsonb_set(
+                metadata,
+                '{hints_used}',
+                jsonb_build_object($2::TEXT, to_jsonb(NOW()))
+                    || COALESCE(metadata->'hints_used', '{}'::jsonb)
+            )
+            WHERE id = $1 AND status = 'en_cours'
+            RETURNING *
+            "#,
+        )
+        .bind(session_id)
+        .bind(question_id)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Versions du registry de plugins utilisées par les sessions en cours
+    pub async fn find_active_plugin_versions(pool: &PgPool) -> Result<Vec<i64>, sqlx::Error> {
+        sqlx::query_scalar(
+            r#"
+            SELECT DISTINCT (metadata->>'plugin_version')::BIGINT
+            FROM sessions_quiz
+            WHERE status = 'en_cours' AND metadata ? 'plugin_version'
+            "#,
+        )
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Mode adaptatif : ajouter une question au tirage et ses points au score max
+    /// `expected_len` : taille du tirage lue par l'appelant (None si modifié entre-temps)
+    pub async fn append_drawn_question(
+        pool: &PgPool,
+        session_id: Uuid,
+        drawn: &serde_json::Value,
+        points: i32,
+        expected_len: usize,
+    ) -> Result<Option<SessionQuiz>, sqlx::Error> {
+        sqlx::query_as::<_, SessionQuiz>(
+            r#"
+            UPDATE sessions_quiz
+            SET metadata = jsonb_set(
+                    metadata,
+                    '{question_set}',
+                    COALESCE(metadata->'question_set', '[]'::jsonb) || jsonb_build_array($2::jsonb)
+                ),
+                score_max = score_max + $3
+            WHERE id = $1
+              AND status = 'en_cours'
+              AND jsonb_array_length(COALESCE(metadata->'question_set', '[]'::jsonb)) = $4
+            RETURNING *
+            "#,
+        )
+        .bind(session_id)
+        .bind(drawn)
+        .bind(points)
+        .bind(expected_len as i32)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Enregistrer le rapport détaillé de la session
+    pub async fn set_reponses_detaillees(
+        executor: impl PgExecutor<'_>,
+        session_id: Uuid,
+        report: &serde_json::Value,
+    ) -> Result<SessionQuiz, sqlx::Error> {
+        sqlx::query_as::<_, SessionQuiz>(
+            "UPDATE sessions_quiz SET reponses_detaillees = $2 WHERE id = $1 RETURNING *",
+        )
+        .bind(session_id)
+        .bind(report)
+        .fetch_one(executor)
+        .await
+    }
+
+    /// Fusionner des clés dans `sessions_quiz.metadata`
+    pub async fn merge_metadata(
+        executor: impl PgExecutor<'_>,
+        session_id: Uuid,
+        metadata: &serde_json::Value,
+    ) -> Result<SessionQuiz, sqlx::Error> {
+        sqlx::query_as::<_, SessionQuiz>(
+            "UPDATE sessions_quiz SET metadata = metadata || $2 WHERE id = $1 RETURNING *",
+        )
+        .bind(session_id)
+        .bind(metadata)
+        .fetch_one(executor)
+        .await
+    }
+
+    pub async fn create_user_answer(
+        executor: impl PgExecutor<'_>,
+        answer: &NewUserAnswer<'_>,
     ) -> Result<ReponseUtilisateur, sqlx::Error> {
+        // POINT(x = longitude, y = latitude) ; point(NULL, NULL) donne NULL
         sqlx::query_as::<_, ReponseUtilisateur>(
             r#"
             INSERT INTO reponses_utilisateur (
-                session_id, question_id, reponse_id, valeur_saisie,
-                is_correct, points_obtenus, temps_reponse_sec
+                session_id, question_id, reponse_id, reponse_ids, valeur_saisie,
+                coordinates_cliquees, is_correct, points_obtenus, temps_reponse_sec, metadata
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            VALUES ($1, $2, $3, $4, $5, point($6, $7), $8, $9, $10, $11)
             RETURNING *
             "#,
         )
-        .bind(session_id)
-        .bind(question_id)
-        .bind(reponse_id)
-        .bind(valeur_saisie)
-        .bind(is_correct)
-        .bind(points_obtenus)
-        .bind(temps_reponse_sec)
-        .fetch_one(pool)
+        .bind(answer.session_id)
+        .bind(answer.question_id)
+        .bind(answer.reponse_id)
+        .bind(answer.reponse_ids)
+        .bind(answer.valeur_saisie)
+        .bind(answer.coordinates_cliquees.map(|c| c.longitude))
+        .bind(answer.coordinates_cliquees.map(|c| c.latitude))
+        .bind(answer.is_correct)
+        .bind(answer.points_obtenus)
+        .bind(answer.temps_reponse_sec)
+        .bind(answer.metadata)
+        .fetch_one(executor)
         .await
     }
 
-    pub async fn calculate_max_score(pool: &PgPool, quiz_id: Uuid) -> Result<i32, sqlx::Error> {
+    /// Score maximum d'un ensemble de questions (tirage de la session)
+    pub async fn calculate_max_score(
+        pool: &PgPool,
+        question_ids: &[Uuid],
+    ) -> Result<i32, sqlx::Error> {
         let score_max_i64: i64 = sqlx::query_scalar(
-            "SELECT COALESCE(SUM(points)::BIGINT, 0) FROM questions WHERE quiz_id = $1",
+            "SELECT COALESCE(SUM(points)::BIGINT, 0) FROM questions WHERE id = ANY($1)",
         )
-        .bind(quiz_id)
+        .bind(question_ids)
         .fetch_one(pool)
         .await?;
         Ok(score_max_i64 as i32)
@@ -119,14 +412,14 @@ impl SessionRepository {
 
     /// ✅ AJOUTER : Récupérer toutes les réponses d'une session (ordre chronologique)
     pub async fn find_reponses_by_session(
-        pool: &PgPool,
+        executor: impl PgExecutor<'_>,
         session_id: Uuid,
     ) -> Result<Vec<ReponseUtilisateur>, sqlx::Error> {
         sqlx::query_as::<_, ReponseUtilisateur>(
             "SELECT * FROM reponses_utilisateur WHERE session_id = $1 ORDER BY created_at ASC",
         )
         .bind(session_id)
-        .fetch_all(pool)
+        .fetch_all(executor)
         .await
     }
 }
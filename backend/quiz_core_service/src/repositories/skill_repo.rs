@@ -0,0 +1,50 @@
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+use crate::models::UserSkillRating;
+
+pub struct SkillRepository;
+
+impl SkillRepository {
+    /// Niveau d'un joueur dans un domaine (None s'il n'a jamais joué)
+    pub async fn find(
+        executor: impl PgExecutor<'_>,
+        user_id: Uuid,
+        domain: &str,
+    ) -> Result<Option<UserSkillRating>, sqlx::Error> {
+        sqlx::query_as::<_, UserSkillRating>(
+            "SELECT * FROM user_skill_ratings WHERE user_id = $1 AND domain = $2",
+        )
+        .bind(user_id)
+        .bind(domain)
+        .fetch_optional(executor)
+        .await
+    }
+
+    /// Appliquer une variation de niveau (atomique, crée la ligne au premier passage)
+    pub async fn apply_delta(
+        executor: impl PgExecutor<'_>,
+        user_id: Uuid,
+        domain: &str,
+        initial_rating: f64,
+        delta: f64,
+    ) -> Result<UserSkillRating, sqlx::Error> {
+        sqlx::query_as::<_, UserSkillRating>(
+            r#"
+            INSERT INTO user_skill_ratings (user_id, domain, rating, nb_answers)
+            VALUES ($1, $2, $3 + $4, 1)
+            ON CONFLICT (user_id, domain) DO UPDATE
+            SET rating = user_skill_ratings.rating + $4,
+                nb_answers = user_skill_ratings.nb_answers + 1,
+                updated_at = NOW()
+            RETURNING *
+            "#,
+        )
+        .bind(user_id)
+        .bind(domain)
+        .bind(initial_rating)
+        .bind(delta)
+        .fetch_one(executor)
+        .await
+    }
+}
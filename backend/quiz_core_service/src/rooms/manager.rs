@@ -0,0 +1,598 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use serde_json::json;
+use shared::AppError;
+use sqlx::PgPool;
+use tokio::sync::mpsc;
+use uuid::Uuid;
+
+use super::protocol::{ClientMessage, RoomInfo, RoomStatus, ScoreEntry, ServerMessage};
+use super::room::{Room, RoomAnswer, RoomQuestion};
+use crate::{
+    dto::SubmitAnswerRequest,
+    models::Quiz,
+    plugins::{QuizPlugin, ValidationResult},
+    repositories::{
+        QuizRepository, SessionRepository,
+        session_repo::{CompletedSession, NewUserAnswer},
+    },
+    services::{
+        AchievementService, LeaderboardService, ProgressService, answer_timing::AnswerTiming,
+    },
+};
+
+/// Alphabet des codes de salle (sans 0/O ni 1/I, faciles à confondre)
+const CODE_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+const CODE_LEN: usize = 6;
+
+/// Une salle sans joueur connecté est fermée au-delà de ce délai
+const ROOM_IDLE_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Messages en attente par salle (au-delà, l'envoi attend)
+const COMMAND_CHANNEL_CAPACITY: usize = 256;
+
+/// Tentatives d'enregistrement des résultats d'une partie
+const PERSIST_ATTEMPTS: u32 = 3;
+
+/// Délai avant une nouvelle tentative (doublé à chaque échec)
+const PERSIST_RETRY_DELAY: Duration = Duration::from_millis(500);
+
+enum RoomCommand {
+    Connect {
+        user_id: Uuid,
+        pseudo: String,
+        connection_id: Uuid,
+        sender: mpsc::UnboundedSender<ServerMessage>,
+    },
+    Disconnect {
+        user_id: Uuid,
+        connection_id: Uuid,
+    },
+    Message {
+        user_id: Uuid,
+        message: ClientMessage,
+        received_at: DateTime<Utc>,
+    },
+}
+
+#[derive(Clone)]
+struct RoomHandle {
+    info: RoomInfo,
+    commands: mpsc::Sender<RoomCommand>,
+}
+
+type RoomRegistry = Arc<Mutex<HashMap<String, RoomHandle>>>;
+
+/// Salles de compétition ouvertes, chacune pilotée par sa propre tâche
+#[derive(Clone)]
+pub struct RoomManager {
+    pool: PgPool,
+    rooms: RoomRegistry,
+}
+
+impl RoomManager {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            pool,
+            rooms: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Ouvrir une salle : son code court est unique parmi les salles ouvertes
+    pub fn open(
+        &self,
+        quiz: Quiz,
+        host_id: Uuid,
+        questions: Vec<RoomQuestion>,
+        plugin: Arc<dyn QuizPlugin>,
+    ) -> RoomInfo {
+        let (commands, receiver) = mpsc::channel(COMMAND_CHANNEL_CAPACITY);
+
+        let mut rooms = self.rooms.lock().expect("room registry poisoned");
+        let code = loop {
+            let code = generate_code(&mut rand::thread_rng());
+            if !rooms.contains_key(&code) {
+                break code;
+            }
+        };
+        let room = Room::new(code.clone(), quiz, host_id, questions);
+        let info = room.info();
+        rooms.insert(
+            code,
+            RoomHandle {
+                info: info.clone(),
+                commands,
+            },
+        );
+        drop(rooms);
+
+        tracing::info!(code = %info.code, quiz_id = %info.quiz_id, "Competition room opened");
+
+        let actor = RoomActor {
+            room,
+            plugin,
+            pool: self.pool.clone(),
+            rooms: self.rooms.clone(),
+            connections: HashMap::new(),
+        };
+        tokio::spawn(actor.run(receiver));
+
+        info
+    }
+
+    pub fn info(&self, code: &str) -> Option<RoomInfo> {
+        self.handle(code).map(|handle| handle.info)
+    }
+
+    /// Rejoindre une salle : la connexion reçoit l'état complet, puis les messages de la salle
+    pub async fn connect(
+        &self,
+        code: &str,
+        user_id: Uuid,
+        pseudo: String,
+    ) -> Result<RoomConnection, AppError> {
+        let not_found = || AppError::NotFound(format!("Salle {} introuvable", code));
+        let handle = self.handle(code).ok_or_else(not_found)?;
+
+        let (sender, messages) = mpsc::unbounded_channel();
+        let connection_id = Uuid::new_v4();
+        handle
+            .commands
+            .send(RoomCommand::Connect {
+                user_id,
+                pseudo,
+                connection_id,
+                sender,
+            })
+            .await
+            .map_err(|_| not_found())?;
+
+        Ok(RoomConnection {
+            user_id,
+            connection_id,
+            commands: handle.commands,
+            messages,
+        })
+    }
+
+    fn handle(&self, code: &str) -> Option<RoomHandle> {
+        let code = code.trim().to_uppercase();
+        let rooms = self.rooms.lock().expect("room registry poisoned");
+        rooms.get(&code).cloned()
+    }
+}
+
+fn generate_code(rng: &mut impl Rng) -> String {
+    (0..CODE_LEN)
+        .map(|_| CODE_ALPHABET[rng.gen_range(0..CODE_ALPHABET.len())] as char)
+        .collect()
+}
+
+/// Connexion d'un joueur à une salle (la déconnexion est signalée à sa destruction)
+pub struct RoomConnection {
+    user_id: Uuid,
+    connection_id: Uuid,
+    commands: mpsc::Sender<RoomCommand>,
+    messages: mpsc::UnboundedReceiver<ServerMessage>,
+}
+
+impl RoomConnection {
+    /// Prochain message de la salle (`None` : connexion remplacée ou salle fermée)
+    pub async fn recv(&mut self) -> Option<ServerMessage> {
+        self.messages.recv().await
+    }
+
+    /// Transmettre un message du joueur (`false` si la salle est fermée)
+    pub async fn send(&self, message: ClientMessage) -> bool {
+        self.commands
+            .send(RoomCommand::Message {
+                user_id: self.user_id,
+                message,
+                received_at: Utc::now(),
+            })
+            .await
+            .is_ok()
+    }
+}
+
+impl Drop for RoomConnection {
+    fn drop(&mut self) {
+        let _ = self.commands.try_send(RoomCommand::Disconnect {
+            user_id: self.user_id,
+            connection_id: self.connection_id,
+        });
+    }
+}
+
+/// Partie terminée, à enregistrer (une session terminée par joueur)
+struct FinishedGame {
+    quiz: Quiz,
+    code: String,
+    score_max: i32,
+    started_at: DateTime<Utc>,
+    players: Vec<(ScoreEntry, Vec<RoomAnswer>)>,
+}
+
+/// Tâche propriétaire d'une salle : traite les commandes une à une
+struct RoomActor {
+    room: Room,
+    plugin: Arc<dyn QuizPlugin>,
+    pool: PgPool,
+    rooms: RoomRegistry,
+    /// Connexion active de chaque joueur (une reconnexion remplace la précédente)
+    connections: HashMap<Uuid, (Uuid, mpsc::UnboundedSender<ServerMessage>)>,
+}
+
+impl RoomActor {
+    async fn run(mut self, mut commands: mpsc::Receiver<RoomCommand>) {
+        // Délai d'inactivité : démarre quand la salle se vide (ou à son ouverture)
+        let idle = tokio::time::sleep(ROOM_IDLE_TIMEOUT);
+        tokio::pin!(idle);
+
+        loop {
+            // Fin du temps de réponse de la question en cours
+            let closes_in = self
+                .room
+                .closes_at()
+                .map(|at| (at - Utc::now()).to_std().unwrap_or_default());
+            let was_empty = self.connections.is_empty();
+
+            tokio::select! {
+                command = commands.recv() => match command {
+                    Some(command) => self.handle(command).await,
+                    None => break,
+                },
+                _ = tokio::time::sleep(closes_in.unwrap_or_default()), if closes_in.is_some() => {
+                    self.close_question();
+                }
+                _ = &mut idle, if was_empty => break,
+            }
+
+            if self.room.status() == RoomStatus::Finished && self.connections.is_empty() {
+                break;
+            }
+            if !was_empty && self.connections.is_empty() {
+                idle.as_mut()
+                    .reset(tokio::time::Instant::now() + ROOM_IDLE_TIMEOUT);
+            }
+        }
+
+        self.rooms
+            .lock()
+            .expect("room registry poisoned")
+            .remove(&self.room.code);
+        tracing::info!(code = %self.room.code, "Competition room closed");
+    }
+
+    async fn handle(&mut self, command: RoomCommand) {
+        match command {
+            RoomCommand::Connect {
+                user_id,
+                pseudo,
+                connection_id,
+                sender,
+            } => self.connect(user_id, pseudo, connection_id, sender),
+            RoomCommand::Disconnect {
+                user_id,
+                connection_id,
+            } => self.disconnect(user_id, connection_id),
+            RoomCommand::Message {
+                user_id,
+                message,
+                received_at,
+            } => match message {
+                ClientMessage::Start => self.host_command(user_id, RoomStatus::Lobby).await,
+                ClientMessage::Next => self.host_command(user_id, RoomStatus::Results).await,
+                ClientMessage::Answer(request) => self.answer(user_id, request, received_at).await,
+            },
+        }
+    }
+
+    fn connect(
+        &mut self,
+        user_id: Uuid,
+        pseudo: String,
+        connection_id: Uuid,
+        sender: mpsc::UnboundedSender<ServerMessage>,
+    ) {
+        // Partie terminée : classement final seulement, la connexion est aussitôt fermée
+        if self.room.status() == RoomStatus::Finished {
+            let _ = sender.send(ServerMessage::Joined {
+                user_id,
+                room: self.room.snapshot(user_id),
+            });
+            return;
+        }
+
+        let is_new = self.room.join(user_id, pseudo.clone());
+        // Reconnexion : l'ancienne connexion est remplacée (son canal est fermé)
+        let replaced = self
+            .connections
+            .insert(user_id, (connection_id, sender))
+            .is_some();
+
+        self.send(
+            user_id,
+            ServerMessage::Joined {
+                user_id,
+                room: self.room.snapshot(user_id),
+            },
+        );
+
+        if !replaced {
+            let late = is_new && self.room.status() != RoomStatus::Lobby;
+            self.broadcast_except(
+                user_id,
+                ServerMessage::PlayerJoined {
+                    user_id,
+                    pseudo,
+                    late,
+                },
+            );
+        }
+
+        tracing::debug!(code = %self.room.code, user_id = %user_id, is_new, replaced, "Player connected");
+    }
+
+    fn disconnect(&mut self, user_id: Uuid, connection_id: Uuid) {
+        // Connexion déjà remplacée par une reconnexion
+        if self
+            .connections
+            .get(&user_id)
+            .is_none_or(|(id, _)| *id != connection_id)
+        {
+            return;
+        }
+
+        self.connections.remove(&user_id);
+        self.room.leave(user_id);
+        self.broadcast(ServerMessage::PlayerLeft { user_id });
+
+        if self.room.everyone_answered() {
+            self.close_question();
+        }
+    }
+
+    /// Commandes de l'hôte : lancer la partie puis passer d'une question à l'autre
+    async fn host_command(&mut self, user_id: Uuid, expected: RoomStatus) {
+        if user_id != self.room.host_id {
+            self.send_error(user_id, "Réservé à l'hôte de la salle");
+            return;
+        }
+        if self.room.status() != expected {
+            self.send_error(user_id, "Action impossible à ce stade de la partie");
+            return;
+        }
+
+        match self.room.open_next(Utc::now()) {
+            Some(question) => self.broadcast(question),
+            None => self.finish().await,
+        }
+    }
+
+    /// Réponse d'un joueur : validée et notée par le plugin du domaine
+    async fn answer(
+        &mut self,
+        user_id: Uuid,
+        mut request: SubmitAnswerRequest,
+        received_at: DateTime<Utc>,
+    ) {
+        let question = match self.room.check_answer(user_id, request.question_id) {
+            Ok(question) => question,
+            Err(message) => {
+                self.send_error(user_id, &message);
+                return;
+            }
+        };
+
+        let time_limit = question.time_limit();
+        let presented_at = self.room.presented_at().unwrap_or(received_at);
+        let timing = AnswerTiming::compute(presented_at, received_at, Some(time_limit));
+        let client_temps_reponse_sec = request.temps_reponse_sec;
+        request.temps_reponse_sec = timing.elapsed_sec;
+
+        let validation = if timing.timed_out {
+            ValidationResult::incorrect("Temps écoulé - Réponse hors délai")
+        } else {
+            match self
+                .plugin
+                .validate_answer(&self.pool, &question.question, &request)
+                .await
+            {
+                Ok(validation) => validation,
+                Err(e) => {
+                    let message = e.to_string();
+                    self.send_error(user_id, &message);
+                    return;
+                }
+            }
+        };
+
+        let streak = self.room.player(user_id).map_or(0, |p| p.streak);
+        let points_obtenus = self
+            .plugin
+            .calculate_score(
+                question.question.points,
+                &validation,
+                timing.elapsed_sec,
+                Some(time_limit),
+                streak,
+                false,
+            )
+            .await;
+
+        let mut metadata = validation.metadata.clone();
+        metadata["feedback_message"] = json!(validation.feedback_message);
+        if let Some(partial_score) = validation.partial_score {
+            metadata["partial_score"] = json!(partial_score);
+        }
+        metadata["timing"] = timing.to_metadata(client_temps_reponse_sec);
+
+        let question_id = request.question_id;
+        self.room.record_answer(
+            user_id,
+            RoomAnswer {
+                request,
+                is_correct: validation.is_correct,
+                points_obtenus,
+                temps_reponse_sec: timing.elapsed_sec,
+                metadata,
+            },
+        );
+        self.send(user_id, ServerMessage::AnswerRecorded { question_id });
+
+        if self.room.everyone_answered() {
+            self.close_question();
+        }
+    }
+
+    fn close_question(&mut self) {
+        if let Some(results) = self.room.close_question() {
+            self.broadcast(results);
+        }
+    }
+
+    /// Fin de partie : classement final, puis enregistrement des sessions
+    /// (nouvelles tentatives en cas d'échec, joueurs prévenus si les résultats sont perdus)
+    async fn finish(&mut self) {
+        let scoreboard = self.room.scoreboard();
+        self.broadcast(ServerMessage::Finished {
+            scoreboard: scoreboard.clone(),
+        });
+
+        let game = FinishedGame {
+            quiz: self.room.quiz.clone(),
+            code: self.room.code.clone(),
+            score_max: self.room.score_max(),
+            started_at: self.room.started_at.unwrap_or(self.room.created_at),
+            players: scoreboard
+                .into_iter()
+                .filter_map(|entry| {
+                    let answers = self.room.player(entry.user_id)?.answers.clone();
+                    (!answers.is_empty()).then_some((entry, answers))
+                })
+                .collect(),
+        };
+
+        let mut delay = PERSIST_RETRY_DELAY;
+        for attempt in 1..=PERSIST_ATTEMPTS {
+            match persist_game(&self.pool, &game).await {
+                Ok(()) => return,
+                Err(e) if attempt < PERSIST_ATTEMPTS => {
+                    tracing::warn!(code = %game.code, attempt, error = %e, "Failed to save competition results, retrying");
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                }
+                Err(e) => {
+                    tracing::error!(code = %game.code, error = %e, "Failed to save competition results");
+                    self.broadcast(ServerMessage::Error {
+                        message: "Les résultats de la partie n'ont pas pu être enregistrés"
+                            .to_string(),
+                    });
+                }
+            }
+        }
+    }
+
+    fn send(&self, user_id: Uuid, message: ServerMessage) {
+        if let Some((_, sender)) = self.connections.get(&user_id) {
+            let _ = sender.send(message);
+        }
+    }
+
+    fn send_error(&self, user_id: Uuid, message: &str) {
+        self.send(
+            user_id,
+            ServerMessage::Error {
+                message: message.to_string(),
+            },
+        );
+    }
+
+    fn broadcast(&self, message: ServerMessage) {
+        for (_, sender) in self.connections.values() {
+            let _ = sender.send(message.clone());
+        }
+    }
+
+    fn broadcast_except(&self, excluded: Uuid, message: ServerMessage) {
+        for (user_id, (_, sender)) in &self.connections {
+            if *user_id != excluded {
+                let _ = sender.send(message.clone());
+            }
+        }
+    }
+}
+
+/// Une session terminée par joueur ayant répondu (historique, statistiques du quiz)
+/// Une seule transaction : une nouvelle tentative ne duplique pas les sessions
+async fn persist_game(pool: &PgPool, game: &FinishedGame) -> Result<(), AppError> {
+    let nb_players = game.players.len();
+    let mut tx = pool.begin().await?;
+
+    for (entry, answers) in &game.players {
+        let session = SessionRepository::create_completed(
+            &mut *tx,
+            &CompletedSession {
+                user_id: entry.user_id,
+                quiz_id: game.quiz.id,
+                score: entry.score,
+                score_max: game.score_max,
+                date_debut: game.started_at,
+                temps_total_sec: entry.temps_total_sec,
+                metadata: json!({
+                    "kind": "competition",
+                    "room_code": game.code,
+                    "rank": entry.rank,
+                    "nb_players": nb_players,
+                }),
+            },
+        )
+        .await?;
+
+        let mut saved = Vec::with_capacity(answers.len());
+        for answer in answers {
+            let request = &answer.request;
+            let reponse_ids: Vec<Uuid> = match &request.reponse_ids {
+                Some(ids) => ids.clone(),
+                None => request.reponse_id.into_iter().collect(),
+            };
+            let reponse = SessionRepository::create_user_answer(
+                &mut *tx,
+                &NewUserAnswer {
+                    session_id: session.id,
+                    question_id: request.question_id,
+                    reponse_id: request.reponse_id,
+                    reponse_ids: &reponse_ids,
+                    valeur_saisie: request.valeur_saisie.as_deref(),
+                    coordinates_cliquees: request.coordinates_cliquees.as_ref(),
+                    is_correct: answer.is_correct,
+                    points_obtenus: answer.points_obtenus,
+                    temps_reponse_sec: answer.temps_reponse_sec,
+                    metadata: &answer.metadata,
+                },
+            )
+            .await?;
+            saved.push(reponse);
+        }
+
+        QuizRepository::record_session_score(
+            &mut *tx,
+            game.quiz.id,
+            session.pourcentage.unwrap_or(0.0),
+        )
+        .await?;
+        LeaderboardService::record_session(&mut tx, &session, &game.quiz.domain).await?;
+        AchievementService::on_session_finished(&mut tx, &session, &game.quiz.domain, &saved)
+            .await?;
+        ProgressService::record_session(&mut tx, &session, &game.quiz, &saved).await?;
+    }
+
+    tx.commit().await?;
+    tracing::info!(code = %game.code, players = nb_players, "Competition results saved");
+    Ok(())
+}
@@ -0,0 +1,9 @@
+mod manager;
+mod protocol;
+mod room;
+mod socket;
+
+pub use manager::RoomManager;
+pub use protocol::RoomInfo;
+pub use room::RoomQuestion;
+pub use socket::serve_socket;
@@ -0,0 +1,122 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::dto::SubmitAnswerRequest;
+
+/// Messages envoyés par un joueur sur le WebSocket (`{"type": "...", ...}`)
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ClientMessage {
+    /// Hôte : lancer la partie (première question)
+    Start,
+    /// Hôte : question suivante (ou fin de partie après la dernière)
+    Next,
+    /// Réponse à la question en cours (même format que `POST /sessions/:id/answers`)
+    Answer(SubmitAnswerRequest),
+}
+
+/// Messages diffusés par la salle
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ServerMessage {
+    /// Connexion (ou reconnexion) acceptée : état complet de la salle
+    Joined {
+        user_id: Uuid,
+        room: RoomSnapshot,
+    },
+    PlayerJoined {
+        user_id: Uuid,
+        pseudo: String,
+        /// Arrivée après le début de la partie
+        late: bool,
+    },
+    PlayerLeft {
+        user_id: Uuid,
+    },
+    /// Question poussée à tous les joueurs en même temps
+    Question {
+        index: usize,
+        total: usize,
+        question: serde_json::Value,
+        deadline: DateTime<Utc>,
+    },
+    /// Réponse enregistrée (la correction arrive avec les résultats de la question)
+    AnswerRecorded {
+        question_id: Uuid,
+    },
+    /// Fin de la question : correction et classement en direct
+    QuestionResults {
+        question_id: Uuid,
+        correct_answers: serde_json::Value,
+        explanation: Option<String>,
+        results: Vec<PlayerResult>,
+        scoreboard: Vec<ScoreEntry>,
+    },
+    Finished {
+        scoreboard: Vec<ScoreEntry>,
+    },
+    Error {
+        message: String,
+    },
+}
+
+/// Phase de la partie
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoomStatus {
+    Lobby,
+    Question,
+    Results,
+    Finished,
+}
+
+/// État de la salle envoyé à la connexion (rattrapage des retardataires et reconnexions)
+#[derive(Debug, Clone, Serialize)]
+pub struct RoomSnapshot {
+    pub code: String,
+    pub quiz_id: Uuid,
+    pub titre: String,
+    pub host_id: Uuid,
+    pub status: RoomStatus,
+    pub question_index: Option<usize>,
+    pub total_questions: usize,
+    /// Question en cours (phase `question` uniquement)
+    pub current_question: Option<serde_json::Value>,
+    pub deadline: Option<DateTime<Utc>>,
+    /// Le joueur a déjà répondu à la question en cours
+    pub answered: bool,
+    pub scoreboard: Vec<ScoreEntry>,
+}
+
+/// Ligne du classement (ex aequo : même score et même temps cumulé)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScoreEntry {
+    pub rank: usize,
+    pub user_id: Uuid,
+    pub pseudo: String,
+    pub score: i32,
+    pub correct_answers: i32,
+    pub temps_total_sec: i32,
+    pub connected: bool,
+}
+
+/// Résultat d'un joueur pour une question
+#[derive(Debug, Clone, Serialize)]
+pub struct PlayerResult {
+    pub user_id: Uuid,
+    pub answered: bool,
+    pub is_correct: bool,
+    pub points_obtenus: i32,
+}
+
+/// Salle ouverte, telle que renvoyée par l'API REST
+#[derive(Debug, Clone, Serialize)]
+pub struct RoomInfo {
+    pub code: String,
+    pub quiz_id: Uuid,
+    pub titre: String,
+    pub host_id: Uuid,
+    pub total_questions: usize,
+    pub created_at: DateTime<Utc>,
+}
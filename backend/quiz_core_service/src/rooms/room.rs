@@ -0,0 +1,476 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+
+use super::protocol::{
+    PlayerResult, RoomInfo, RoomSnapshot, RoomStatus, ScoreEntry, ServerMessage,
+};
+use crate::{
+    dto::SubmitAnswerRequest,
+    models::{Question, Quiz, Reponse},
+    services::{
+        QuestionService,
+        answer_timing::{LATENCY_ALLOWANCE_MS, TIME_LIMIT_GRACE_SEC},
+        session_report,
+    },
+};
+
+/// Temps de réponse d'une question sans `temps_limite_sec`
+pub const DEFAULT_QUESTION_SEC: i32 = 20;
+
+/// Question de la partie : modèle serveur et version envoyée aux joueurs
+pub struct RoomQuestion {
+    pub question: Question,
+    pub reponses: Vec<Reponse>,
+    payload: serde_json::Value,
+}
+
+impl RoomQuestion {
+    /// `order` : ordre d'affichage des réponses (tirage)
+    pub fn new(question: Question, reponses: Vec<Reponse>, order: Option<&[Uuid]>) -> Self {
+        let mut dto = QuestionService::to_client_dto(question.clone(), reponses.clone(), order);
+        // Pas d'indice en compétition, l'explication arrive avec les résultats
+        dto.has_hint = false;
+        dto.explanation = None;
+
+        Self {
+            payload: serde_json::to_value(dto).unwrap_or_default(),
+            question,
+            reponses,
+        }
+    }
+
+    pub fn time_limit(&self) -> i32 {
+        self.question
+            .temps_limite_sec
+            .unwrap_or(DEFAULT_QUESTION_SEC)
+    }
+}
+
+/// Réponse d'un joueur, conservée pour l'enregistrement en fin de partie
+#[derive(Debug, Clone)]
+pub struct RoomAnswer {
+    pub request: SubmitAnswerRequest,
+    pub is_correct: bool,
+    pub points_obtenus: i32,
+    pub temps_reponse_sec: i32,
+    pub metadata: serde_json::Value,
+}
+
+/// Joueur de la salle (conservé à la déconnexion pour permettre la reconnexion)
+#[derive(Debug, Clone)]
+pub struct Player {
+    pub pseudo: String,
+    pub connected: bool,
+    pub score: i32,
+    pub correct_answers: i32,
+    pub streak: i32,
+    pub temps_total_sec: i32,
+    pub answers: Vec<RoomAnswer>,
+}
+
+/// État d'une partie : joueurs, question en cours et scores
+pub struct Room {
+    pub code: String,
+    pub quiz: Quiz,
+    pub host_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    /// Première question lancée
+    pub started_at: Option<DateTime<Utc>>,
+    questions: Vec<RoomQuestion>,
+    players: HashMap<Uuid, Player>,
+    status: RoomStatus,
+    current: Option<usize>,
+    presented_at: Option<DateTime<Utc>>,
+    answered: HashSet<Uuid>,
+}
+
+impl Room {
+    pub fn new(code: String, quiz: Quiz, host_id: Uuid, questions: Vec<RoomQuestion>) -> Self {
+        Self {
+            code,
+            quiz,
+            host_id,
+            created_at: Utc::now(),
+            started_at: None,
+            questions,
+            players: HashMap::new(),
+            status: RoomStatus::Lobby,
+            current: None,
+            presented_at: None,
+            answered: HashSet::new(),
+        }
+    }
+
+    pub fn info(&self) -> RoomInfo {
+        RoomInfo {
+            code: self.code.clone(),
+            quiz_id: self.quiz.id,
+            titre: self.quiz.titre.clone(),
+            host_id: self.host_id,
+            total_questions: self.questions.len(),
+            created_at: self.created_at,
+        }
+    }
+
+    pub fn status(&self) -> RoomStatus {
+        self.status
+    }
+
+    pub fn player(&self, user_id: Uuid) -> Option<&Player> {
+        self.players.get(&user_id)
+    }
+
+    /// Rejoindre la salle (ou la rejoindre à nouveau) : `true` pour un nouveau joueur
+    pub fn join(&mut self, user_id: Uuid, pseudo: String) -> bool {
+        match self.players.get_mut(&user_id) {
+            Some(player) => {
+                player.connected = true;
+                player.pseudo = pseudo;
+                false
+            }
+            None => {
+                self.players.insert(
+                    user_id,
+                    Player {
+                        pseudo,
+                        connected: true,
+                        score: 0,
+                        correct_answers: 0,
+                        streak: 0,
+                        temps_total_sec: 0,
+                        answers: Vec::new(),
+                    },
+                );
+                true
+            }
+        }
+    }
+
+    pub fn leave(&mut self, user_id: Uuid) {
+        if let Some(player) = self.players.get_mut(&user_id) {
+            player.connected = false;
+        }
+    }
+
+    /// Passer à la question suivante ; `None` (partie terminée) après la dernière
+    pub fn open_next(&mut self, now: DateTime<Utc>) -> Option<ServerMessage> {
+        let next = self.current.map_or(0, |index| index + 1);
+        if next >= self.questions.len() {
+            self.status = RoomStatus::Finished;
+            self.current = None;
+            self.presented_at = None;
+            return None;
+        }
+
+        self.started_at.get_or_insert(now);
+        self.status = RoomStatus::Question;
+        self.current = Some(next);
+        self.presented_at = Some(now);
+        self.answered.clear();
+        self.question_message()
+    }
+
+    /// Question en cours, telle que poussée aux joueurs
+    pub fn question_message(&self) -> Option<ServerMessage> {
+        let question = self.current_question()?;
+        Some(ServerMessage::Question {
+            index: self.current?,
+            total: self.questions.len(),
+            question: question.payload.clone(),
+            deadline: self.deadline()?,
+        })
+    }
+
+    pub fn current_question(&self) -> Option<&RoomQuestion> {
+        if self.status != RoomStatus::Question {
+            return None;
+        }
+        self.questions.get(self.current?)
+    }
+
+    pub fn presented_at(&self) -> Option<DateTime<Utc>> {
+        self.presented_at
+    }
+
+    /// Fin du temps de réponse annoncée aux joueurs
+    pub fn deadline(&self) -> Option<DateTime<Utc>> {
+        let question = self.current_question()?;
+        Some(self.presented_at? + Duration::seconds(i64::from(question.time_limit())))
+    }
+
+    /// Clôture effective : délai de grâce et latence tolérée en plus
+    pub fn closes_at(&self) -> Option<DateTime<Utc>> {
+        Some(
+            self.deadline()?
+                + Duration::seconds(TIME_LIMIT_GRACE_SEC)
+                + Duration::milliseconds(LATENCY_ALLOWANCE_MS),
+        )
+    }
+
+    /// Vérifier qu'un joueur peut répondre à la question en cours
+    pub fn check_answer(&self, user_id: Uuid, question_id: Uuid) -> Result<&RoomQuestion, String> {
+        if !self.players.contains_key(&user_id) {
+            return Err("Joueur inconnu dans cette salle".to_string());
+        }
+        let question = self
+            .current_question()
+            .ok_or_else(|| "Aucune question en cours".to_string())?;
+        if question.question.id != question_id {
+            return Err("Cette question n'est plus en cours".to_string());
+        }
+        if self.answered.contains(&user_id) {
+            return Err("Question déjà répondue".to_string());
+        }
+        Ok(question)
+    }
+
+    pub fn record_answer(&mut self, user_id: Uuid, answer: RoomAnswer) {
+        let Some(player) = self.players.get_mut(&user_id) else {
+            return;
+        };
+        player.score += answer.points_obtenus;
+        player.temps_total_sec += answer.temps_reponse_sec;
+        if answer.is_correct {
+            player.correct_answers += 1;
+            player.streak += 1;
+        } else {
+            player.streak = 0;
+        }
+        player.answers.push(answer);
+        self.answered.insert(user_id);
+    }
+
+    /// Tous les joueurs connectés ont répondu : inutile d'attendre la fin du temps
+    pub fn everyone_answered(&self) -> bool {
+        self.status == RoomStatus::Question
+            && self
+                .players
+                .iter()
+                .filter(|(_, p)| p.connected)
+                .all(|(id, _)| self.answered.contains(id))
+    }
+
+    /// Clore la question en cours : correction et classement
+    pub fn close_question(&mut self) -> Option<ServerMessage> {
+        let index = self.current?;
+        if self.status != RoomStatus::Question {
+            return None;
+        }
+        self.status = RoomStatus::Results;
+
+        let question = &self.questions[index];
+        let question_id = question.question.id;
+
+        let mut results = Vec::with_capacity(self.players.len());
+        for (user_id, player) in self.players.iter_mut() {
+            let answer = self
+                .answered
+                .contains(user_id)
+                .then(|| player.answers.last())
+                .flatten();
+            // Pas de réponse : la série est rompue
+            if answer.is_none() {
+                player.streak = 0;
+            }
+            results.push(PlayerResult {
+                user_id: *user_id,
+                answered: answer.is_some(),
+                is_correct: answer.is_some_and(|a| a.is_correct),
+                points_obtenus: answer.map_or(0, |a| a.points_obtenus),
+            });
+        }
+
+        Some(ServerMessage::QuestionResults {
+            question_id,
+            correct_answers: session_report::correct_answers(
+                &question.question,
+                &question.reponses,
+            ),
+            explanation: question.question.explanation.clone(),
+            results,
+            scoreboard: self.scoreboard(),
+        })
+    }
+
+    /// Classement : score décroissant, puis temps cumulé croissant
+    pub fn scoreboard(&self) -> Vec<ScoreEntry> {
+        let mut players: Vec<(&Uuid, &Player)> = self.players.iter().collect();
+        players.sort_by(|(_, a), (_, b)| {
+            b.score
+                .cmp(&a.score)
+                .then(a.temps_total_sec.cmp(&b.temps_total_sec))
+                .then_with(|| a.pseudo.cmp(&b.pseudo))
+        });
+
+        let mut scoreboard: Vec<ScoreEntry> = Vec::with_capacity(players.len());
+        for (position, (user_id, player)) in players.into_iter().enumerate() {
+            let rank = match scoreboard.last() {
+                Some(previous)
+                    if previous.score == player.score
+                        && previous.temps_total_sec == player.temps_total_sec =>
+                {
+                    previous.rank
+                }
+                _ => position + 1,
+            };
+            scoreboard.push(ScoreEntry {
+                rank,
+                user_id: *user_id,
+                pseudo: player.pseudo.clone(),
+                score: player.score,
+                correct_answers: player.correct_answers,
+                temps_total_sec: player.temps_total_sec,
+                connected: player.connected,
+            });
+        }
+        scoreboard
+    }
+
+    /// Score maximum de la partie
+    pub fn score_max(&self) -> i32 {
+        self.questions.iter().map(|q| q.question.points).sum()
+    }
+
+    /// État complet pour un joueur qui (re)joint la salle
+    pub fn snapshot(&self, user_id: Uuid) -> RoomSnapshot {
+        RoomSnapshot {
+            code: self.code.clone(),
+            quiz_id: self.quiz.id,
+            titre: self.quiz.titre.clone(),
+            host_id: self.host_id,
+            status: self.status,
+            question_index: self.current,
+            total_questions: self.questions.len(),
+            current_question: self.current_question().map(|q| q.payload.clone()),
+            deadline: self.deadline(),
+            answered: self.status == RoomStatus::Question && self.answered.contains(&user_id),
+            scoreboard: self.scoreboard(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+    use serde_json::json;
+
+    fn quiz() -> Quiz {
+        Quiz {
+            nb_questions: 2,
+            ..fixtures::quiz("geography", "competition")
+        }
+    }
+
+    fn question() -> RoomQuestion {
+        let question = Question {
+            question_data: json!({"text": "Capitale de l'Italie ?"}),
+            temps_limite_sec: Some(15),
+            hint: Some("Elle a sept collines".to_string()),
+            explanation: Some("Rome".to_string()),
+            ..fixtures::question("capitales", "saisie_texte")
+        };
+        RoomQuestion::new(question, Vec::new(), None)
+    }
+
+    fn answer(is_correct: bool, points: i32, seconds: i32) -> RoomAnswer {
+        RoomAnswer {
+            request: serde_json::from_value(json!({"question_id": Uuid::nil()})).unwrap(),
+            is_correct,
+            points_obtenus: points,
+            temps_reponse_sec: seconds,
+            metadata: json!({}),
+        }
+    }
+
+    #[test]
+    fn test_payload_hides_hint_and_explanation() {
+        let question = question();
+        assert_eq!(question.payload["has_hint"], false);
+        assert!(question.payload["explanation"].is_null());
+        assert_eq!(question.time_limit(), 15);
+    }
+
+    #[test]
+    fn test_waits_only_for_connected_players() {
+        let (host, alice, bob) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut room = Room::new("ABC234".into(), quiz(), host, vec![question(), question()]);
+        room.join(host, "Hôte".into());
+        room.join(alice, "Alice".into());
+        room.open_next(Utc::now()).unwrap();
+
+        room.record_answer(host, answer(true, 10, 3));
+        assert!(!room.everyone_answered());
+
+        // Alice déconnectée : on n'attend plus que les joueurs présents
+        room.leave(alice);
+        assert!(room.everyone_answered());
+
+        // Retardataire : doit répondre à la question en cours
+        assert!(room.join(bob, "Bob".into()));
+        assert!(!room.everyone_answered());
+        assert_eq!(
+            room.snapshot(bob).current_question,
+            room.current_question().map(|q| q.payload.clone())
+        );
+
+        // Reconnexion : le joueur est conservé
+        assert!(!room.join(alice, "Alice".into()));
+        assert_eq!(room.players.len(), 3);
+        assert!(room.player(alice).is_some_and(|p| p.connected));
+    }
+
+    #[test]
+    fn test_answer_checks() {
+        let (host, stranger) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut room = Room::new("ABC234".into(), quiz(), host, vec![question()]);
+        room.join(host, "Hôte".into());
+        let question_id = room.questions[0].question.id;
+
+        assert!(room.check_answer(host, question_id).is_err()); // pas encore lancée
+        room.open_next(Utc::now()).unwrap();
+        assert!(room.check_answer(stranger, question_id).is_err());
+        assert!(room.check_answer(host, Uuid::new_v4()).is_err());
+        assert!(room.check_answer(host, question_id).is_ok());
+
+        room.record_answer(host, answer(true, 10, 2));
+        assert!(room.check_answer(host, question_id).is_err());
+
+        room.close_question().unwrap();
+        assert!(room.open_next(Utc::now()).is_none());
+        assert_eq!(room.status(), RoomStatus::Finished);
+    }
+
+    #[test]
+    fn test_scoreboard_ranks_and_ties() {
+        let ids: Vec<Uuid> = (0..4).map(|_| Uuid::new_v4()).collect();
+        let mut room = Room::new("ABC234".into(), quiz(), ids[0], vec![question()]);
+        for (i, id) in ids.iter().enumerate() {
+            room.join(*id, format!("joueur{i}"));
+        }
+        room.open_next(Utc::now()).unwrap();
+        room.record_answer(ids[0], answer(true, 10, 8));
+        room.record_answer(ids[1], answer(true, 10, 4));
+        room.record_answer(ids[2], answer(true, 10, 8));
+        room.leave(ids[3]);
+
+        let Some(ServerMessage::QuestionResults {
+            results,
+            scoreboard,
+            ..
+        }) = room.close_question()
+        else {
+            panic!("résultats attendus");
+        };
+        assert_eq!(results.iter().filter(|r| r.answered).count(), 3);
+
+        let ranks: Vec<(Uuid, usize)> = scoreboard.iter().map(|e| (e.user_id, e.rank)).collect();
+        assert_eq!(ranks[0], (ids[1], 1)); // même score, plus rapide
+        assert_eq!(ranks[1].1, 2);
+        assert_eq!(ranks[2].1, 2); // ex aequo
+        assert_eq!(ranks[3], (ids[3], 4));
+        assert!(!scoreboard[3].connected);
+    }
+}
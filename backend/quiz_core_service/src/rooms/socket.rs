@@ -0,0 +1,307 @@
+use axum::extract::ws::{Message, WebSocket};
+use futures::{SinkExt, StreamExt};
+
+use super::manager::RoomConnection;
+use super::protocol::{ClientMessage, ServerMessage};
+
+/// Relayer un WebSocket vers sa salle : JSON texte dans les deux sens
+pub async fn serve_socket(socket: WebSocket, mut connection: RoomConnection) {
+    let (mut sink, mut stream) = socket.split();
+
+    loop {
+        tokio::select! {
+            outgoing = connection.recv() => {
+                // Connexion remplacée (reconnexion ailleurs) ou salle fermée
+                let Some(message) = outgoing else { break };
+                let Ok(text) = serde_json::to_string(&message) else { continue };
+                if sink.send(Message::Text(text)).await.is_err() {
+                    break;
+                }
+            }
+            incoming = stream.next() => match incoming {
+                Some(Ok(Message::Text(text))) => match serde_json::from_str::<ClientMessage>(&text) {
+                    Ok(message) => {
+                        if !connection.send(message).await {
+                            break;
+                        }
+                    }
+                    Err(e) => {
+                        let error = ServerMessage::Error {
+                            message: format!("Message invalide : {}", e),
+                        };
+                        let Ok(text) = serde_json::to_string(&error) else { continue };
+                        if sink.send(Message::Text(text)).await.is_err() {
+                            break;
+                        }
+                    }
+                },
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                // Ping/pong gérés par axum, binaire ignoré
+                Some(Ok(_)) => {}
+            }
+        }
+    }
+
+    let _ = sink.send(Message::Close(None)).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use futures::{SinkExt, StreamExt};
+    use serde_json::{Value, json};
+    use shared::AppError;
+    use sqlx::{PgPool, postgres::PgPoolOptions};
+    use tokio::net::TcpStream;
+    use tokio_tungstenite::{
+        MaybeTlsStream, WebSocketStream, connect_async,
+        tungstenite::{Message as WsMessage, client::IntoClientRequest},
+    };
+    use uuid::Uuid;
+
+    use crate::{
+        AppState,
+        dto::SubmitAnswerRequest,
+        events::EventBus,
+        models::{Question, Quiz, fixtures},
+        plugins::{PluginManager, QuizPlugin, ValidationResult, WasmLimits, WasmRuntime},
+        rooms::{RoomManager, RoomQuestion},
+        routes,
+        services::certificate::CertificateSigner,
+    };
+
+    type Client = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+    /// Plugin de test : "Rome" est la bonne réponse, sans accès à la base
+    struct CapitalPlugin;
+
+    #[async_trait]
+    impl QuizPlugin for CapitalPlugin {
+        fn domain_name(&self) -> &str {
+            "capitales"
+        }
+
+        async fn validate_answer(
+            &self,
+            _pool: &PgPool,
+            _question: &Question,
+            answer: &SubmitAnswerRequest,
+        ) -> Result<ValidationResult, AppError> {
+            Ok(match answer.valeur_saisie.as_deref() {
+                Some("Rome") => ValidationResult::correct("Bravo"),
+                _ => ValidationResult::incorrect("Non"),
+            })
+        }
+    }
+
+    fn quiz() -> Quiz {
+        Quiz {
+            nb_questions: 2,
+            ..fixtures::quiz("capitales", "competition")
+        }
+    }
+
+    fn question(ordre: i32) -> RoomQuestion {
+        let question = Question {
+            ordre,
+            question_data: json!({"text": "Capitale de l'Italie ?"}),
+            temps_limite_sec: Some(30),
+            explanation: Some("Rome est la capitale depuis 1871.".to_string()),
+            ..fixtures::question("capitales", "saisie_texte")
+        };
+        RoomQuestion::new(question, Vec::new(), None)
+    }
+
+    /// Serveur en mémoire (aucune base : l'enregistrement final échoue sans bloquer la partie)
+    async fn start_server() -> (String, RoomManager) {
+        let pool = PgPoolOptions::new()
+            .acquire_timeout(Duration::from_millis(200))
+            .connect_lazy("postgres://quiz@127.0.0.1:1/quiz")
+            .unwrap();
+        let wasm_runtime = WasmRuntime::new(WasmLimits {
+            fuel: 1_000_000,
+            max_memory_bytes: 1024 * 1024,
+            timeout: Duration::from_millis(100),
+        })
+        .unwrap();
+        let rooms = RoomManager::new(pool.clone());
+        let state = AppState {
+            pool,
+            plugin_manager: Arc::new(PluginManager::new(
+                Vec::new(),
+                wasm_runtime,
+                PathBuf::from("plugins"),
+            )),
+            events: EventBus::new(),
+            certificates: CertificateSigner::new(b"test"),
+            rooms: rooms.clone(),
+        };
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            axum::serve(listener, routes::create_router(state))
+                .await
+                .unwrap();
+        });
+        (format!("ws://{}", addr), rooms)
+    }
+
+    async fn join(base: &str, code: &str, user_id: Uuid, pseudo: &str) -> Client {
+        let mut request = format!("{}/api/v1/rooms/{}/ws?pseudo={}", base, code, pseudo)
+            .into_client_request()
+            .unwrap();
+        request
+            .headers_mut()
+            .insert("X-User-Id", user_id.to_string().parse().unwrap());
+        let (client, _) = connect_async(request).await.unwrap();
+        client
+    }
+
+    async fn send(client: &mut Client, message: Value) {
+        client
+            .send(WsMessage::Text(message.to_string()))
+            .await
+            .unwrap();
+    }
+
+    /// Prochain message du type attendu (les autres sont ignorés)
+    async fn recv(client: &mut Client, kind: &str) -> Value {
+        tokio::time::timeout(Duration::from_secs(5), async {
+            loop {
+                let message = client.next().await.unwrap().unwrap();
+                if let WsMessage::Text(text) = message {
+                    let value: Value = serde_json::from_str(&text).unwrap();
+                    if value["type"] == kind {
+                        return value;
+                    }
+                }
+            }
+        })
+        .await
+        .unwrap_or_else(|_| panic!("message '{}' non reçu", kind))
+    }
+
+    #[tokio::test]
+    async fn test_competition_over_websocket() {
+        let (base, rooms) = start_server().await;
+        let (host, alice) = (Uuid::new_v4(), Uuid::new_v4());
+        let info = rooms.open(
+            quiz(),
+            host,
+            vec![question(1), question(2)],
+            Arc::new(CapitalPlugin),
+        );
+
+        let mut host_ws = join(&base, &info.code, host, "Hote").await;
+        assert_eq!(
+            recv(&mut host_ws, "joined").await["room"]["status"],
+            "lobby"
+        );
+        // Code insensible à la casse
+        let mut alice_ws = join(&base, &info.code.to_lowercase(), alice, "Alice").await;
+        recv(&mut alice_ws, "joined").await;
+        assert_eq!(
+            recv(&mut host_ws, "player_joined").await["user_id"],
+            alice.to_string()
+        );
+
+        // Seul l'hôte lance la partie
+        send(&mut alice_ws, json!({"type": "start"})).await;
+        recv(&mut alice_ws, "error").await;
+        send(&mut host_ws, json!({"type": "start"})).await;
+
+        // Question poussée à tous
+        let question = recv(&mut alice_ws, "question").await;
+        assert_eq!(
+            recv(&mut host_ws, "question").await["question"],
+            question["question"]
+        );
+        assert!(question["question"]["explanation"].is_null());
+        let question_id = question["question"]["id"].clone();
+
+        send(
+            &mut alice_ws,
+            json!({"type": "answer", "question_id": question_id, "valeur_saisie": "Rome"}),
+        )
+        .await;
+        recv(&mut alice_ws, "answer_recorded").await;
+        send(
+            &mut host_ws,
+            json!({"type": "answer", "question_id": question_id, "valeur_saisie": "Milan"}),
+        )
+        .await;
+
+        // Tout le monde a répondu : correction et classement sans attendre la fin du temps
+        let results = recv(&mut host_ws, "question_results").await;
+        assert_eq!(results["explanation"], "Rome est la capitale depuis 1871.");
+        assert_eq!(results["scoreboard"][0]["user_id"], alice.to_string());
+        assert!(results["scoreboard"][0]["score"].as_i64().unwrap() > 0);
+        assert_eq!(results["scoreboard"][1]["score"], 0);
+        recv(&mut alice_ws, "question_results").await;
+
+        send(&mut host_ws, json!({"type": "next"})).await;
+        recv(&mut alice_ws, "question").await;
+    }
+
+    #[tokio::test]
+    async fn test_late_joiner_and_reconnect() {
+        let (base, rooms) = start_server().await;
+        let (host, bob) = (Uuid::new_v4(), Uuid::new_v4());
+        let info = rooms.open(quiz(), host, vec![question(1)], Arc::new(CapitalPlugin));
+
+        let mut host_ws = join(&base, &info.code, host, "Hote").await;
+        recv(&mut host_ws, "joined").await;
+        send(&mut host_ws, json!({"type": "start"})).await;
+        let question_id = recv(&mut host_ws, "question").await["question"]["id"].clone();
+
+        // Retardataire : rattrape la question en cours
+        let mut bob_ws = join(&base, &info.code, bob, "Bob").await;
+        let snapshot = recv(&mut bob_ws, "joined").await["room"].clone();
+        assert_eq!(snapshot["status"], "question");
+        assert_eq!(snapshot["current_question"]["id"], question_id);
+        assert_eq!(snapshot["answered"], false);
+        assert_eq!(recv(&mut host_ws, "player_joined").await["late"], true);
+
+        send(
+            &mut bob_ws,
+            json!({"type": "answer", "question_id": question_id, "valeur_saisie": "Rome"}),
+        )
+        .await;
+        recv(&mut bob_ws, "answer_recorded").await;
+
+        // Reconnexion : l'ancienne connexion est fermée, la réponse et le score sont conservés
+        let mut bob_again = join(&base, &info.code, bob, "Bob").await;
+        let snapshot = recv(&mut bob_again, "joined").await["room"].clone();
+        assert_eq!(snapshot["answered"], true);
+        let closed = tokio::time::timeout(Duration::from_secs(5), async {
+            while let Some(Ok(message)) = bob_ws.next().await {
+                if message.is_close() {
+                    break;
+                }
+            }
+        })
+        .await;
+        assert!(closed.is_ok());
+
+        // Question close dès que l'hôte répond ; fin de partie après la dernière
+        send(
+            &mut host_ws,
+            json!({"type": "answer", "question_id": question_id, "valeur_saisie": "Rome"}),
+        )
+        .await;
+        let results = recv(&mut bob_again, "question_results").await;
+        assert_eq!(results["results"].as_array().unwrap().len(), 2);
+        send(&mut host_ws, json!({"type": "next"})).await;
+        let finished = recv(&mut bob_again, "finished").await;
+        assert_eq!(finished["scoreboard"].as_array().unwrap().len(), 2);
+
+        // Base injoignable : échec de l'enregistrement signalé après les nouvelles tentatives
+        let error = recv(&mut bob_again, "error").await;
+        assert!(error["message"].as_str().unwrap().contains("enregistrés"));
+    }
+}
@@ -1,11 +1,15 @@
 use axum::{
     Router,
-    routing::{get, post},
+    routing::{get, post, put},
 };
 
 use crate::{
     AppState,
-    handlers::{question_handler::*, quiz_handler::*, reponse_handler::*, session_handler::*},
+    handlers::{
+        achievement_handler::*, daily_challenge_handler::*, leaderboard_handler::*,
+        plugin_handler::*, progress_handler::*, question_handler::*, quiz_handler::*,
+        reponse_handler::*, review_handler::*, room_handler::*, session_handler::*,
+    },
 };
 
 pub fn create_router(app_state: AppState) -> Router {
@@ -23,6 +27,10 @@ pub fn create_router(app_state: AppState) -> Router {
             get(get_questions_by_quiz_handler),
         )
         .route("/api/v1/questions", post(create_question_handler))
+        .route(
+            "/api/v1/domains/:domain/question-schemas",
+            get(get_question_schemas_handler),
+        )
         .route(
             "/api/v1/questions/:id",
             get(get_question_by_id_handler)
@@ -51,6 +59,35 @@ pub fn create_router(app_state: AppState) -> Router {
             post(start_session_handler),
         )
         .route("/api/v1/sessions/:session_id", get(get_session_handler))
+        .route(
+            "/api/v1/sessions/:session_id/certificate",
+            get(get_session_certificate_handler),
+        )
+        .route(
+            "/api/v1/certificates/verify",
+            post(verify_certificate_handler),
+        )
+        .route(
+            "/api/v1/sessions/:session_id/report",
+            get(get_session_report_handler),
+        )
+        .route(
+            "/api/v1/sessions/:session_id/resume",
+            get(resume_session_handler),
+        )
+        .route("/api/v1/users/me/sessions", get(get_my_sessions_handler))
+        .route(
+            "/api/v1/sessions/:session_id/next",
+            get(next_question_handler),
+        )
+        .route(
+            "/api/v1/sessions/:session_id/questions/:question_id/present",
+            post(present_question_handler),
+        )
+        .route(
+            "/api/v1/sessions/:session_id/questions/:question_id/hint",
+            post(use_hint_handler),
+        )
         .route(
             "/api/v1/sessions/:session_id/answers",
             post(submit_answer_handler),
@@ -59,5 +96,98 @@ pub fn create_router(app_state: AppState) -> Router {
             "/api/v1/sessions/:session_id/finalize",
             post(finalize_session_handler),
         )
+        .route(
+            "/api/v1/sessions/:session_id/abandon",
+            post(abandon_session_handler),
+        )
+        // Révisions espacées
+        .route(
+            "/api/v1/domains/:domain/reviews",
+            post(start_review_session_handler),
+        )
+        .route(
+            "/api/v1/users/:user_id/reviews/due",
+            get(get_due_counts_handler),
+        )
+        // Salles de compétition (WebSocket)
+        .route("/api/v1/quizzes/:quiz_id/rooms", post(create_room_handler))
+        .route("/api/v1/rooms/:code", get(get_room_handler))
+        .route("/api/v1/rooms/:code/ws", get(room_socket_handler))
+        // Classements (?period=all_time|weekly|daily&date=AAAA-MM-JJ)
+        .route(
+            "/api/v1/quizzes/:quiz_id/leaderboard",
+            get(get_quiz_leaderboard_handler),
+        )
+        .route(
+            "/api/v1/quizzes/:quiz_id/leaderboard/me",
+            get(get_my_quiz_rank_handler),
+        )
+        .route(
+            "/api/v1/domains/:domain/leaderboard",
+            get(get_domain_leaderboard_handler),
+        )
+        .route(
+            "/api/v1/domains/:domain/leaderboard/me",
+            get(get_my_domain_rank_handler),
+        )
+        // Défi du jour (un par domaine et par jour, UTC)
+        .route(
+            "/api/v1/domains/:domain/daily-challenge",
+            get(get_daily_challenge_handler),
+        )
+        .route(
+            "/api/v1/domains/:domain/daily-challenge/sessions",
+            post(start_daily_challenge_handler),
+        )
+        .route(
+            "/api/v1/domains/:domain/daily-challenge/leaderboard",
+            get(get_daily_challenge_leaderboard_handler),
+        )
+        .route(
+            "/api/v1/domains/:domain/daily-challenge/leaderboard/me",
+            get(get_my_daily_challenge_rank_handler),
+        )
+        .route(
+            "/api/v1/domains/:domain/daily-challenges",
+            get(get_daily_challenge_history_handler),
+        )
+        // Succès
+        .route(
+            "/api/v1/users/:user_id/achievements",
+            get(get_user_achievements_handler),
+        )
+        // Progression (XP, niveaux, série quotidienne)
+        .route("/api/v1/users/:user_id/progress", get(get_progress_handler))
+        .route(
+            "/api/v1/users/:user_id/progress/timezone",
+            put(set_timezone_handler),
+        )
+        // Admin : plugins de domaine
+        .route("/api/v1/admin/plugins", get(list_plugins_handler))
+        .route("/api/v1/admin/plugins/reload", post(reload_plugins_handler))
+        .route(
+            "/api/v1/admin/domains/:domain/enable",
+            post(enable_domain_handler),
+        )
+        .route(
+            "/api/v1/admin/domains/:domain/disable",
+            post(disable_domain_handler),
+        )
+        .route(
+            "/api/v1/admin/domains/:domain/config",
+            put(update_domain_config_handler),
+        )
+        .route(
+            "/api/v1/admin/users/:user_id/reviews/rebuild",
+            post(rebuild_reviews_handler),
+        )
+        .route(
+            "/api/v1/admin/users/:user_id/progress/rebuild",
+            post(rebuild_progress_handler),
+        )
+        .route(
+            "/api/v1/admin/progress/rebuild",
+            post(rebuild_all_progress_handler),
+        )
         .with_state(app_state)
 }
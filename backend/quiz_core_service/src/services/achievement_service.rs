@@ -0,0 +1,181 @@
+use shared::AppError;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use super::achievements::{self, AchievementProgress, AchievementRule, QuizCompletion};
+use crate::{
+    models::{Achievement, ReponseUtilisateur, SessionQuiz, UserAchievement},
+    plugins::PluginRegistry,
+    repositories::AchievementRepository,
+};
+
+/// Événement déclenchant l'évaluation des règles
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Trigger {
+    Answer,
+    Finalize,
+}
+
+pub struct AchievementService;
+
+impl AchievementService {
+    /// Ajouter au catalogue les succès déclarés par les plugins chargés
+    pub async fn sync_plugin_catalog(
+        pool: &PgPool,
+        registry: &PluginRegistry,
+    ) -> Result<usize, AppError> {
+        let mut synced = 0;
+        for domain in registry.list_domains() {
+            let Some(plugin) = registry.get(&domain) else {
+                continue;
+            };
+            for definition in plugin.achievements() {
+                AchievementRepository::upsert_definition(pool, &domain, &definition).await?;
+                synced += 1;
+            }
+        }
+        Ok(synced)
+    }
+
+    /// Après une réponse : règles de série et de rapidité
+    /// (dans la transaction d'enregistrement de la réponse)
+    pub async fn on_answer(
+        conn: &mut PgConnection,
+        session: &SessionQuiz,
+        domain: &str,
+        streak: i32,
+    ) -> Result<Vec<Achievement>, AppError> {
+        Self::evaluate(conn, session, domain, streak, Trigger::Answer).await
+    }
+
+    /// Session terminée : toutes les règles
+    pub async fn on_session_finished(
+        conn: &mut PgConnection,
+        session: &SessionQuiz,
+        domain: &str,
+        answers: &[ReponseUtilisateur],
+    ) -> Result<Vec<Achievement>, AppError> {
+        let streak = achievements::best_streak(answers);
+        Self::evaluate(conn, session, domain, streak, Trigger::Finalize).await
+    }
+
+    /// Succès débloqués pendant une session (rapport de session)
+    pub async fn session_unlocks(
+        pool: &PgPool,
+        session_id: Uuid,
+    ) -> Result<Vec<UserAchievement>, AppError> {
+        Ok(AchievementRepository::find_by_session(pool, session_id).await?)
+    }
+
+    /// Catalogue complet avec les succès débloqués par le joueur
+    pub async fn list_user_achievements(
+        pool: &PgPool,
+        user_id: Uuid,
+    ) -> Result<Vec<UserAchievement>, AppError> {
+        Ok(AchievementRepository::find_for_user(pool, user_id).await?)
+    }
+
+    async fn evaluate(
+        conn: &mut PgConnection,
+        session: &SessionQuiz,
+        domain: &str,
+        streak: i32,
+        trigger: Trigger,
+    ) -> Result<Vec<Achievement>, AppError> {
+        let candidates: Vec<(Achievement, AchievementRule)> =
+            AchievementRepository::find_locked(&mut *conn, session.user_id, domain)
+                .await?
+                .into_iter()
+                .filter_map(|achievement| {
+                    match serde_json::from_value::<AchievementRule>(achievement.rule.clone()) {
+                        Ok(rule) => Some((achievement, rule)),
+                        Err(e) => {
+                            tracing::warn!(code = %achievement.code, error = %e, "Invalid achievement rule");
+                            None
+                        }
+                    }
+                })
+                .filter(|(_, rule)| trigger == Trigger::Finalize || rule.on_answer())
+                .collect();
+
+        let mut unlocked = Vec::new();
+        // Succès généraux (tous domaines), puis succès du domaine
+        for scope in [None, Some(domain)] {
+            let rules: Vec<&(Achievement, AchievementRule)> = candidates
+                .iter()
+                .filter(|(achievement, _)| achievement.domain.as_deref() == scope)
+                .collect();
+            if rules.is_empty() {
+                continue;
+            }
+
+            let progress =
+                Self::load_progress(conn, session.user_id, scope, streak, &rules).await?;
+            for (achievement, rule) in rules {
+                if rule.is_met(&progress)
+                    && AchievementRepository::unlock(
+                        &mut *conn,
+                        session.user_id,
+                        &achievement.code,
+                        session.id,
+                    )
+                    .await?
+                {
+                    tracing::info!(
+                        user_id = %session.user_id,
+                        code = %achievement.code,
+                        "Achievement unlocked"
+                    );
+                    unlocked.push(achievement.clone());
+                }
+            }
+        }
+
+        Ok(unlocked)
+    }
+
+    /// Charger uniquement les compteurs utiles aux règles évaluées
+    async fn load_progress(
+        conn: &mut PgConnection,
+        user_id: Uuid,
+        domain: Option<&str>,
+        streak: i32,
+        rules: &[&(Achievement, AchievementRule)],
+    ) -> Result<AchievementProgress, AppError> {
+        let needs =
+            |predicate: fn(&AchievementRule) -> bool| rules.iter().any(|(_, rule)| predicate(rule));
+        let mut progress = AchievementProgress {
+            streak,
+            ..Default::default()
+        };
+
+        if needs(|rule| {
+            matches!(
+                rule,
+                AchievementRule::SessionsCompleted { .. } | AchievementRule::PerfectSessions { .. }
+            )
+        }) {
+            (progress.sessions_completed, progress.perfect_sessions) =
+                AchievementRepository::count_sessions(&mut *conn, user_id, domain).await?;
+        }
+        if needs(|rule| matches!(rule, AchievementRule::FastAnswers { .. })) {
+            progress.fast_answers = AchievementRepository::count_fast_answers(
+                &mut *conn,
+                user_id,
+                domain,
+                achievements::FAST_ANSWER_RATIO,
+            )
+            .await?;
+        }
+        if needs(|rule| matches!(rule, AchievementRule::CompleteQuizzes { .. })) {
+            progress.quizzes =
+                AchievementRepository::find_quiz_completions(&mut *conn, user_id, domain)
+                    .await?
+                    .into_iter()
+                    .map(|(scope, completed)| QuizCompletion { scope, completed })
+                    .collect();
+        }
+
+        Ok(progress)
+    }
+}
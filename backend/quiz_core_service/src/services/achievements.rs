@@ -0,0 +1,180 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::ReponseUtilisateur;
+
+/// Réponse "éclair" : moins de 30 % du temps imparti (seuil du badge ⚡ du barème par défaut)
+pub const FAST_ANSWER_RATIO: f64 = 0.3;
+
+/// Règle de déblocage d'un succès, stockée dans `achievements.rule`
+/// (ex: `{"type": "perfect_sessions", "count": 10}`)
+///
+/// Un succès rattaché à un domaine n'est évalué que sur les sessions de ce domaine.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AchievementRule {
+    /// N sessions terminées
+    SessionsCompleted { count: i64 },
+    /// N sessions terminées à 100 %
+    PerfectSessions { count: i64 },
+    /// N bonnes réponses d'affilée dans une session
+    AnswerStreak { count: i32 },
+    /// N bonnes réponses en moins de 30 % du temps imparti
+    FastAnswers { count: i64 },
+    /// Tous les quiz actifs terminés au moins une fois (éventuellement d'une portée, ex: "france")
+    CompleteQuizzes { scope: Option<String> },
+}
+
+impl AchievementRule {
+    /// Règle réévaluée après chaque réponse (toutes le sont à la finalisation)
+    pub fn on_answer(&self) -> bool {
+        matches!(self, Self::AnswerStreak { .. } | Self::FastAnswers { .. })
+    }
+
+    pub fn is_met(&self, progress: &AchievementProgress) -> bool {
+        match self {
+            Self::SessionsCompleted { count } => progress.sessions_completed >= *count,
+            Self::PerfectSessions { count } => progress.perfect_sessions >= *count,
+            Self::AnswerStreak { count } => progress.streak >= *count,
+            Self::FastAnswers { count } => progress.fast_answers >= *count,
+            Self::CompleteQuizzes { scope } => {
+                let quizzes: Vec<&QuizCompletion> = progress
+                    .quizzes
+                    .iter()
+                    .filter(|quiz| {
+                        scope
+                            .as_deref()
+                            .is_none_or(|scope| quiz.scope.eq_ignore_ascii_case(scope))
+                    })
+                    .collect();
+                !quizzes.is_empty() && quizzes.iter().all(|quiz| quiz.completed)
+            }
+        }
+    }
+}
+
+/// Succès déclaré par un plugin (ajouté au catalogue au chargement des plugins)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AchievementDefinition {
+    pub code: String,
+    pub titre: String,
+    pub description: String,
+    pub icon: Option<String>,
+    pub rule: AchievementRule,
+}
+
+/// Progression d'un joueur, tous domaines confondus ou sur un domaine
+#[derive(Debug, Default)]
+pub struct AchievementProgress {
+    pub sessions_completed: i64,
+    pub perfect_sessions: i64,
+    pub fast_answers: i64,
+    /// Série de bonnes réponses de la session en cours d'évaluation
+    pub streak: i32,
+    pub quizzes: Vec<QuizCompletion>,
+}
+
+/// Quiz actif et s'il a déjà été terminé par le joueur
+#[derive(Debug, Clone)]
+pub struct QuizCompletion {
+    pub scope: String,
+    pub completed: bool,
+}
+
+/// Plus longue série de bonnes réponses d'une session
+pub fn best_streak(answers: &[ReponseUtilisateur]) -> i32 {
+    let mut best = 0;
+    let mut current = 0;
+    for answer in answers {
+        current = if answer.is_correct { current + 1 } else { 0 };
+        best = best.max(current);
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    fn quiz(scope: &str, completed: bool) -> QuizCompletion {
+        QuizCompletion {
+            scope: scope.to_string(),
+            completed,
+        }
+    }
+
+    #[test]
+    fn test_counters() {
+        let progress = AchievementProgress {
+            sessions_completed: 12,
+            perfect_sessions: 10,
+            fast_answers: 19,
+            streak: 5,
+            ..Default::default()
+        };
+        assert!(AchievementRule::PerfectSessions { count: 10 }.is_met(&progress));
+        assert!(AchievementRule::AnswerStreak { count: 5 }.is_met(&progress));
+        assert!(!AchievementRule::FastAnswers { count: 20 }.is_met(&progress));
+        assert!(!AchievementRule::SessionsCompleted { count: 50 }.is_met(&progress));
+    }
+
+    #[test]
+    fn test_complete_quizzes_by_scope() {
+        let rule = AchievementRule::CompleteQuizzes {
+            scope: Some("france".to_string()),
+        };
+        let mut progress = AchievementProgress {
+            quizzes: vec![
+                quiz("france", true),
+                quiz("France", false),
+                quiz("europe", false),
+            ],
+            ..Default::default()
+        };
+        assert!(!rule.is_met(&progress));
+
+        progress.quizzes[1].completed = true;
+        assert!(rule.is_met(&progress));
+        assert!(!AchievementRule::CompleteQuizzes { scope: None }.is_met(&progress));
+
+        // Aucun quiz dans la portée : rien à débloquer
+        progress.quizzes.clear();
+        assert!(!rule.is_met(&progress));
+    }
+
+    #[test]
+    fn test_rule_from_catalog_json() {
+        let rule: AchievementRule =
+            serde_json::from_value(json!({"type": "answer_streak", "count": 5})).unwrap();
+        assert_eq!(rule, AchievementRule::AnswerStreak { count: 5 });
+        assert!(rule.on_answer());
+
+        let rule: AchievementRule =
+            serde_json::from_value(json!({"type": "complete_quizzes", "scope": "france"})).unwrap();
+        assert!(!rule.on_answer());
+    }
+
+    #[test]
+    fn test_best_streak() {
+        let answers: Vec<ReponseUtilisateur> = [true, true, false, true, true, true, false]
+            .into_iter()
+            .map(|is_correct| ReponseUtilisateur {
+                id: Uuid::new_v4(),
+                session_id: Uuid::nil(),
+                question_id: Uuid::new_v4(),
+                reponse_id: None,
+                reponse_ids: vec![],
+                valeur_saisie: None,
+                is_correct,
+                points_obtenus: 0,
+                temps_reponse_sec: 5,
+                metadata: json!({}),
+                created_at: Utc::now(),
+            })
+            .collect();
+        assert_eq!(best_streak(&answers), 3);
+        assert_eq!(best_streak(&[]), 0);
+    }
+}
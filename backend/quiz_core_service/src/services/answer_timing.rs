@@ -0,0 +1,87 @@
+use chrono::{DateTime, Utc};
+use serde_json::json;
+
+/// Latence réseau tolérée, déduite du temps mesuré côté serveur
+pub const LATENCY_ALLOWANCE_MS: i64 = 1_000;
+
+/// Délai de grâce après `temps_limite_sec` avant de considérer la réponse hors délai
+pub const TIME_LIMIT_GRACE_SEC: i64 = 2;
+
+/// Temps de réponse mesuré par le serveur (présentation → soumission)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnswerTiming {
+    pub presented_at: DateTime<Utc>,
+    pub answered_at: DateTime<Utc>,
+    /// Temps retenu pour le score (latence déduite), en secondes arrondies au supérieur
+    pub elapsed_sec: i32,
+    /// Réponse arrivée après `temps_limite_sec` + grâce
+    pub timed_out: bool,
+}
+
+impl AnswerTiming {
+    pub fn compute(
+        presented_at: DateTime<Utc>,
+        answered_at: DateTime<Utc>,
+        time_limit: Option<i32>,
+    ) -> Self {
+        let elapsed_ms = (answered_at - presented_at).num_milliseconds();
+        let adjusted_ms = (elapsed_ms - LATENCY_ALLOWANCE_MS).max(0);
+
+        let timed_out = time_limit
+            .is_some_and(|limit| adjusted_ms > (limit as i64 + TIME_LIMIT_GRACE_SEC) * 1000);
+
+        Self {
+            presented_at,
+            answered_at,
+            elapsed_sec: ((adjusted_ms + 999) / 1000).min(i32::MAX as i64) as i32,
+            timed_out,
+        }
+    }
+
+    /// Détails conservés dans `reponses_utilisateur.metadata` (audit)
+    pub fn to_metadata(self, client_temps_reponse_sec: i32) -> serde_json::Value {
+        json!({
+            "presented_at": self.presented_at,
+            "answered_at": self.answered_at,
+            "elapsed_sec": self.elapsed_sec,
+            "client_temps_reponse_sec": client_temps_reponse_sec,
+            "latency_allowance_ms": LATENCY_ALLOWANCE_MS,
+            "timed_out": self.timed_out,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn timing(elapsed_ms: i64, limit: Option<i32>) -> AnswerTiming {
+        let presented_at = Utc::now();
+        AnswerTiming::compute(
+            presented_at,
+            presented_at + Duration::milliseconds(elapsed_ms),
+            limit,
+        )
+    }
+
+    #[test]
+    fn test_latency_allowance() {
+        assert_eq!(timing(800, Some(15)).elapsed_sec, 0);
+        assert_eq!(timing(4_200, Some(15)).elapsed_sec, 4);
+        assert!(!timing(4_200, Some(15)).timed_out);
+    }
+
+    #[test]
+    fn test_timeout_after_grace() {
+        // 15 s + 2 s de grâce + 1 s de latence
+        assert!(!timing(18_000, Some(15)).timed_out);
+        assert!(timing(18_500, Some(15)).timed_out);
+        assert!(!timing(600_000, None).timed_out);
+    }
+
+    #[test]
+    fn test_clock_skew() {
+        assert_eq!(timing(-500, Some(15)).elapsed_sec, 0);
+    }
+}
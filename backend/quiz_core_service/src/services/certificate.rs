@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use uuid::Uuid;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Contenu certifié d'un résultat d'examen
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CertificatePayload {
+    pub session_id: Uuid,
+    pub user_id: Uuid,
+    pub quiz_id: Uuid,
+    pub quiz_titre: String,
+    pub score: i32,
+    pub score_max: i32,
+    pub pourcentage: f64,
+    pub score_minimum_success: i32,
+    pub passed: bool,
+    pub issued_at: DateTime<Utc>,
+}
+
+/// Certificat de résultat : contenu + signature HMAC-SHA256 (hexadécimal)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResultCertificate {
+    pub payload: CertificatePayload,
+    pub signature: String,
+}
+
+/// Signe et vérifie les certificats avec le secret du service (`CERTIFICATE_SECRET`)
+#[derive(Clone)]
+pub struct CertificateSigner {
+    secret: Arc<[u8]>,
+}
+
+impl std::fmt::Debug for CertificateSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("CertificateSigner(***)")
+    }
+}
+
+impl CertificateSigner {
+    pub fn new(secret: &[u8]) -> Self {
+        Self {
+            secret: Arc::from(secret),
+        }
+    }
+
+    pub fn sign(&self, payload: CertificatePayload) -> ResultCertificate {
+        let signature = hex::encode(self.mac(&payload).finalize().into_bytes());
+        ResultCertificate { payload, signature }
+    }
+
+    /// Vérifier qu'un certificat a bien été émis par ce service et n'a pas été modifié
+    pub fn verify(&self, certificate: &ResultCertificate) -> bool {
+        match hex::decode(&certificate.signature) {
+            Ok(signature) => self
+                .mac(&certificate.payload)
+                .verify_slice(&signature)
+                .is_ok(),
+            Err(_) => false,
+        }
+    }
+
+    /// HMAC du contenu sérialisé (ordre des champs fixé par la structure)
+    fn mac(&self, payload: &CertificatePayload) -> HmacSha256 {
+        let mut mac =
+            HmacSha256::new_from_slice(&self.secret).expect("HMAC accepte toute taille de clé");
+        mac.update(&serde_json::to_vec(payload).unwrap_or_default());
+        mac
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payload() -> CertificatePayload {
+        CertificatePayload {
+            session_id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            quiz_id: Uuid::new_v4(),
+            quiz_titre: "Examen blanc".to_string(),
+            score: 36,
+            score_max: 40,
+            pourcentage: 90.0,
+            score_minimum_success: 87,
+            passed: true,
+            issued_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let signer = CertificateSigner::new(b"secret");
+        let certificate = signer.sign(payload());
+        assert_eq!(certificate.signature.len(), 64);
+        assert!(signer.verify(&certificate));
+
+        // Aller-retour JSON (certificat présenté par un tiers)
+        let json = serde_json::to_string(&certificate).unwrap();
+        let parsed: ResultCertificate = serde_json::from_str(&json).unwrap();
+        assert!(signer.verify(&parsed));
+    }
+
+    #[test]
+    fn test_tampered_certificate_is_rejected() {
+        let signer = CertificateSigner::new(b"secret");
+        let mut certificate = signer.sign(payload());
+        certificate.payload.passed = false;
+        assert!(!signer.verify(&certificate));
+
+        let certificate = signer.sign(payload());
+        assert!(!CertificateSigner::new(b"autre").verify(&certificate));
+
+        let mut certificate = signer.sign(payload());
+        certificate.signature = "pas-hexa".to_string();
+        assert!(!signer.verify(&certificate));
+    }
+}
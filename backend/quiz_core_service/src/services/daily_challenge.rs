@@ -0,0 +1,176 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use super::question_draw::DrawnQuestion;
+use crate::models::{Question, Reponse};
+
+/// Nombre de questions d'un défi du jour
+pub const DAILY_CHALLENGE_SIZE: usize = 10;
+
+/// Parmi les questions les plus proches de la difficulté visée, une est tirée au sort
+const CLOSEST_CANDIDATES: usize = 3;
+
+/// Graine du tirage : identique pour tous les joueurs d'un domaine, un jour donné
+pub fn seed(domain: &str, date: NaiveDate) -> u64 {
+    let digest = Sha256::digest(format!("{}:{}", domain, date).as_bytes());
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&digest[..8]);
+    u64::from_be_bytes(bytes)
+}
+
+/// Choisir `count` questions : les catégories sont servies à tour de rôle (ordre tiré au sort)
+/// et la difficulté visée (`difficulty_rating`) progresse du plus facile au plus difficile
+///
+/// Les questions doivent être fournies dans un ordre stable (même tirage pour une même graine).
+pub fn pick_questions<R: Rng>(
+    rng: &mut R,
+    questions: &[(Question, Vec<Reponse>)],
+    count: usize,
+) -> Vec<DrawnQuestion> {
+    let count = count.min(questions.len());
+    if count == 0 {
+        return Vec::new();
+    }
+
+    // Difficulté visée pour chaque position : quantiles des difficultés du domaine
+    let mut ratings: Vec<f64> = questions.iter().map(|(q, _)| q.difficulty_rating).collect();
+    ratings.sort_by(f64::total_cmp);
+    let targets: Vec<f64> = (0..count)
+        .map(|position| {
+            let quantile = if count == 1 {
+                0.5
+            } else {
+                position as f64 / (count - 1) as f64
+            };
+            ratings[((ratings.len() - 1) as f64 * quantile).round() as usize]
+        })
+        .collect();
+
+    let mut groups: BTreeMap<Option<String>, Vec<&(Question, Vec<Reponse>)>> = BTreeMap::new();
+    for item in questions {
+        groups
+            .entry(item.0.category.clone())
+            .or_default()
+            .push(item);
+    }
+    let mut categories: Vec<Option<String>> = groups.keys().cloned().collect();
+    categories.shuffle(rng);
+
+    let mut selected = Vec::with_capacity(count);
+    let mut turn = 0;
+    for target in targets {
+        // Prochaine catégorie ayant encore des questions (il en reste : count <= total)
+        let group = loop {
+            let category = &categories[turn % categories.len()];
+            turn += 1;
+            if let Some(group) = groups.get_mut(category).filter(|g| !g.is_empty()) {
+                break group;
+            }
+        };
+        group.sort_by(|a, b| {
+            (a.0.difficulty_rating - target)
+                .abs()
+                .total_cmp(&(b.0.difficulty_rating - target).abs())
+        });
+        let index = rng.gen_range(0..group.len().min(CLOSEST_CANDIDATES));
+        selected.push(group.remove(index));
+    }
+
+    selected
+        .into_iter()
+        .map(|(question, reponses)| {
+            let mut reponse_ids: Vec<Uuid> = reponses.iter().map(|r| r.id).collect();
+            reponse_ids.shuffle(rng);
+            DrawnQuestion {
+                question_id: question.id,
+                reponse_ids,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn question(category: &str, difficulty_rating: f64) -> (Question, Vec<Reponse>) {
+        let question = Question {
+            temps_limite_sec: Some(30),
+            difficulty_rating,
+            ..fixtures::question(category, "qcm")
+        };
+        (question, Vec::new())
+    }
+
+    fn pool() -> Vec<(Question, Vec<Reponse>)> {
+        let mut questions = Vec::new();
+        for (index, category) in ["fleuves", "reliefs", "villes"].iter().enumerate() {
+            for level in 0..5 {
+                questions.push(question(
+                    category,
+                    1200.0 + 150.0 * level as f64 + index as f64,
+                ));
+            }
+        }
+        questions
+    }
+
+    fn find_question(questions: &[(Question, Vec<Reponse>)], id: Uuid) -> &Question {
+        &questions.iter().find(|(q, _)| q.id == id).unwrap().0
+    }
+
+    #[test]
+    fn test_seed_is_stable_per_domain_and_day() {
+        let day = NaiveDate::from_ymd_opt(2025, 11, 12).unwrap();
+        assert_eq!(seed("geography", day), seed("geography", day));
+        assert_ne!(
+            seed("geography", day),
+            seed("geography", day.succ_opt().unwrap())
+        );
+        assert_ne!(seed("geography", day), seed("code_route", day));
+    }
+
+    #[test]
+    fn test_same_seed_same_challenge() {
+        let questions = pool();
+        let first = pick_questions(&mut StdRng::seed_from_u64(42), &questions, 6);
+        let second = pick_questions(&mut StdRng::seed_from_u64(42), &questions, 6);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_categories_and_difficulty_progression() {
+        let questions = pool();
+        let drawn = pick_questions(&mut StdRng::seed_from_u64(7), &questions, 6);
+        assert_eq!(drawn.len(), 6);
+
+        // Catégories à tour de rôle : chacune deux fois
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        for q in &drawn {
+            let category = find_question(&questions, q.question_id).category.clone();
+            *counts.entry(category.unwrap()).or_default() += 1;
+        }
+        assert!(counts.values().all(|count| *count == 2));
+
+        // Du plus facile au plus difficile
+        let first = find_question(&questions, drawn[0].question_id).difficulty_rating;
+        let last = find_question(&questions, drawn[5].question_id).difficulty_rating;
+        assert!(first < last);
+    }
+
+    #[test]
+    fn test_small_domain() {
+        let questions = vec![question("fleuves", 1500.0), question("fleuves", 1600.0)];
+        let drawn = pick_questions(&mut StdRng::seed_from_u64(1), &questions, 10);
+        assert_eq!(drawn.len(), 2);
+        assert!(pick_questions(&mut StdRng::seed_from_u64(1), &[], 10).is_empty());
+    }
+}
@@ -0,0 +1,236 @@
+use chrono::{NaiveDate, Utc};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use serde_json::json;
+use shared::AppError;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::daily_challenge::{self, DAILY_CHALLENGE_SIZE};
+use super::leaderboard::LeaderboardScope;
+use super::leaderboard_service::LeaderboardService;
+use super::question_draw::DrawnQuestion;
+use crate::{
+    dto::{
+        daily_challenge_dto::{DailyChallengeQuery, DailyChallengeResponse},
+        leaderboard_dto::{LeaderboardQuery, LeaderboardResponse, MyRankResponse},
+    },
+    models::{DailyChallengeSummary, Quiz, SessionQuiz},
+    plugins::PluginManager,
+    repositories::{QuestionRepository, QuizRepository, SessionRepository},
+};
+
+/// Nombre de défis passés renvoyés par défaut / au maximum
+const DEFAULT_HISTORY_SIZE: i64 = 30;
+const MAX_HISTORY_SIZE: i64 = 365;
+
+pub struct DailyChallengeService;
+
+impl DailyChallengeService {
+    /// Défi d'un jour : celui du jour est généré au premier appel, les défis passés sont consultables
+    pub async fn get_challenge(
+        pool: &PgPool,
+        plugin_manager: &PluginManager,
+        domain: &str,
+        date: Option<NaiveDate>,
+    ) -> Result<DailyChallengeResponse, AppError> {
+        let today = Utc::now().date_naive();
+        let date = date.unwrap_or(today);
+        if date > today {
+            return Err(AppError::BadRequest(
+                "Le défi de cette date n'est pas encore disponible".to_string(),
+            ));
+        }
+
+        let quiz = if date == today {
+            Self::find_or_generate(pool, plugin_manager, domain, date).await?
+        } else {
+            Self::find_challenge(pool, domain, date).await?
+        };
+        Ok(DailyChallengeResponse { date, quiz })
+    }
+
+    /// Démarrer le défi du jour (une seule tentative par joueur)
+    pub async fn start_challenge(
+        pool: &PgPool,
+        plugin_manager: &PluginManager,
+        domain: &str,
+        user_id: Uuid,
+    ) -> Result<SessionQuiz, AppError> {
+        let date = Utc::now().date_naive();
+        let quiz = Self::find_or_generate(pool, plugin_manager, domain, date).await?;
+
+        if SessionRepository::find_by_user_and_quiz(pool, user_id, quiz.id)
+            .await?
+            .is_some()
+        {
+            return Err(AppError::BadRequest(
+                "Défi du jour déjà joué : une seule tentative par jour".to_string(),
+            ));
+        }
+
+        let question_set: Vec<DrawnQuestion> = quiz
+            .metadata
+            .get("question_set")
+            .and_then(|set| serde_json::from_value(set.clone()).ok())
+            .unwrap_or_default();
+        let question_ids: Vec<Uuid> = question_set.iter().map(|q| q.question_id).collect();
+        let score_max = SessionRepository::calculate_max_score(pool, &question_ids).await?;
+
+        let session = SessionRepository::create(
+            pool,
+            user_id,
+            quiz.id,
+            score_max,
+            &json!({
+                "plugin_version": plugin_manager.current().version(),
+                "question_set": question_set,
+                "kind": "daily_challenge",
+                "challenge_date": date,
+            }),
+        )
+        .await
+        .map_err(|e| match e {
+            // Deux démarrages simultanés : l'index unique garde la tentative unique
+            sqlx::Error::Database(db) if db.is_unique_violation() => AppError::BadRequest(
+                "Défi du jour déjà joué : une seule tentative par jour".to_string(),
+            ),
+            e => AppError::Database(e),
+        })?;
+
+        tracing::info!(user_id = %user_id, domain = %domain, date = %date, "Daily challenge started");
+        Ok(session)
+    }
+
+    /// Classement d'un défi (meilleur score, puis temps)
+    pub async fn get_leaderboard(
+        pool: &PgPool,
+        domain: &str,
+        query: &DailyChallengeQuery,
+    ) -> Result<LeaderboardResponse, AppError> {
+        let quiz = Self::find_challenge(pool, domain, Self::date(query)).await?;
+        LeaderboardService::get_leaderboard(
+            pool,
+            LeaderboardScope::Quiz,
+            &quiz.id.to_string(),
+            &Self::leaderboard_query(query),
+        )
+        .await
+    }
+
+    /// Rang du joueur dans le classement d'un défi
+    pub async fn get_my_rank(
+        pool: &PgPool,
+        domain: &str,
+        user_id: Uuid,
+        query: &DailyChallengeQuery,
+    ) -> Result<MyRankResponse, AppError> {
+        let quiz = Self::find_challenge(pool, domain, Self::date(query)).await?;
+        LeaderboardService::get_my_rank(
+            pool,
+            LeaderboardScope::Quiz,
+            &quiz.id.to_string(),
+            user_id,
+            &Self::leaderboard_query(query),
+        )
+        .await
+    }
+
+    /// Historique des défis d'un domaine
+    pub async fn history(
+        pool: &PgPool,
+        domain: &str,
+        user_id: Option<Uuid>,
+        query: &DailyChallengeQuery,
+    ) -> Result<Vec<DailyChallengeSummary>, AppError> {
+        let limit = query
+            .limit
+            .unwrap_or(DEFAULT_HISTORY_SIZE)
+            .clamp(1, MAX_HISTORY_SIZE);
+        Ok(QuizRepository::find_daily_challenges(pool, domain, user_id, limit).await?)
+    }
+
+    /// Session de défi du jour (`metadata.kind = "daily_challenge"`) : questions de plusieurs quiz
+    pub fn is_daily_challenge_session(session: &SessionQuiz) -> bool {
+        session.metadata.get("kind").and_then(|k| k.as_str()) == Some("daily_challenge")
+    }
+
+    async fn find_challenge(
+        pool: &PgPool,
+        domain: &str,
+        date: NaiveDate,
+    ) -> Result<Quiz, AppError> {
+        QuizRepository::find_daily_challenge(pool, domain, date)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!("Aucun défi du jour pour {} le {}", domain, date))
+            })
+    }
+
+    /// Tirage déterministe (graine : domaine + date), enregistré au premier appel
+    async fn find_or_generate(
+        pool: &PgPool,
+        plugin_manager: &PluginManager,
+        domain: &str,
+        date: NaiveDate,
+    ) -> Result<Quiz, AppError> {
+        if !plugin_manager.current().has_domain(domain) {
+            return Err(AppError::BadRequest(format!(
+                "Le domaine {} est désactivé",
+                domain
+            )));
+        }
+        if let Some(quiz) = QuizRepository::find_daily_challenge(pool, domain, date).await? {
+            return Ok(quiz);
+        }
+
+        let questions = QuestionRepository::find_by_domain_with_reponses(pool, domain).await?;
+        let seed = daily_challenge::seed(domain, date);
+        let question_set = daily_challenge::pick_questions(
+            &mut StdRng::seed_from_u64(seed),
+            &questions,
+            DAILY_CHALLENGE_SIZE,
+        );
+        if question_set.is_empty() {
+            return Err(AppError::NotFound(format!(
+                "Aucune question disponible pour le domaine {}",
+                domain
+            )));
+        }
+
+        let created = QuizRepository::create_daily_challenge(
+            pool,
+            domain,
+            date,
+            question_set.len() as i32,
+            &json!({
+                "kind": "daily_challenge",
+                "date": date,
+                "seed": seed.to_string(),
+                "question_set": question_set,
+            }),
+        )
+        .await?;
+
+        match created {
+            Some(quiz) => {
+                tracing::info!(domain = %domain, date = %date, "Daily challenge generated");
+                Ok(quiz)
+            }
+            // Généré entre-temps par une requête concurrente
+            None => Self::find_challenge(pool, domain, date).await,
+        }
+    }
+
+    fn date(query: &DailyChallengeQuery) -> NaiveDate {
+        query.date.unwrap_or_else(|| Utc::now().date_naive())
+    }
+
+    fn leaderboard_query(query: &DailyChallengeQuery) -> LeaderboardQuery {
+        LeaderboardQuery {
+            limit: query.limit,
+            offset: query.offset,
+            ..Default::default()
+        }
+    }
+}
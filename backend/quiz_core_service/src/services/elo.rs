@@ -0,0 +1,117 @@
+use rand::Rng;
+use uuid::Uuid;
+
+/// Classement initial (joueur ou question)
+pub const INITIAL_RATING: f64 = 1500.0;
+
+/// Probabilité de réussite visée pour la question suivante (mode adaptatif)
+pub const TARGET_SUCCESS: f64 = 0.7;
+
+/// Facteur K du joueur : plus fort tant que son niveau est peu connu
+const K_USER_PROVISIONAL: f64 = 40.0;
+const K_USER: f64 = 20.0;
+const PROVISIONAL_ANSWERS: i32 = 20;
+
+/// Facteur K des questions : plus faible, elles sont jouées par beaucoup de joueurs
+const K_QUESTION: f64 = 16.0;
+
+/// Nombre de candidates les plus proches de la cible entre lesquelles on tire au sort
+const PICK_POOL: usize = 3;
+
+/// Probabilité de réussite d'un joueur de niveau `skill` sur une question `difficulty`
+pub fn expected_success(skill: f64, difficulty: f64) -> f64 {
+    1.0 / (1.0 + 10f64.powf((difficulty - skill) / 400.0))
+}
+
+/// Variations de classement après une réponse
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RatingDelta {
+    pub skill: f64,
+    pub difficulty: f64,
+}
+
+/// Calculer les variations après une réponse
+/// `outcome` : 1.0 correct, 0.0 incorrect, score partiel entre les deux
+pub fn rating_delta(skill: f64, nb_answers: i32, difficulty: f64, outcome: f64) -> RatingDelta {
+    let surprise = outcome.clamp(0.0, 1.0) - expected_success(skill, difficulty);
+    let k_user = if nb_answers < PROVISIONAL_ANSWERS {
+        K_USER_PROVISIONAL
+    } else {
+        K_USER
+    };
+
+    RatingDelta {
+        skill: k_user * surprise,
+        // Une question réussie "contre toute attente" est plus facile que prévu
+        difficulty: -K_QUESTION * surprise,
+    }
+}
+
+/// Choisir la question suivante : celle dont la réussite attendue est la plus
+/// proche de `TARGET_SUCCESS` (tirage parmi les plus proches pour varier)
+pub fn pick_next<R: Rng>(rng: &mut R, skill: f64, candidates: &[(Uuid, f64)]) -> Option<Uuid> {
+    let mut ranked: Vec<(Uuid, f64)> = candidates
+        .iter()
+        .map(|(id, difficulty)| {
+            let gap = (expected_success(skill, *difficulty) - TARGET_SUCCESS).abs();
+            (*id, gap)
+        })
+        .collect();
+    ranked.sort_by(|a, b| a.1.total_cmp(&b.1));
+    ranked.truncate(PICK_POOL);
+
+    if ranked.is_empty() {
+        return None;
+    }
+    Some(ranked[rng.gen_range(0..ranked.len())].0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn test_expected_success() {
+        assert!((expected_success(1500.0, 1500.0) - 0.5).abs() < 1e-9);
+        // 400 points d'écart : 10 contre 1
+        assert!((expected_success(1900.0, 1500.0) - 10.0 / 11.0).abs() < 1e-9);
+        assert!(expected_success(1300.0, 1500.0) < 0.5);
+    }
+
+    #[test]
+    fn test_rating_delta() {
+        let delta = rating_delta(1500.0, 0, 1500.0, 1.0);
+        assert_eq!(delta.skill, 20.0);
+        assert_eq!(delta.difficulty, -8.0);
+
+        // Échec sur une question facile : le joueur perd, la question monte
+        let delta = rating_delta(1500.0, 50, 1100.0, 0.0);
+        assert!(delta.skill < 0.0 && delta.difficulty > 0.0);
+
+        // Score partiel égal à l'attendu : aucun changement
+        let delta = rating_delta(1500.0, 50, 1500.0, 0.5);
+        assert_eq!(delta.skill, 0.0);
+    }
+
+    #[test]
+    fn test_pick_next_targets_success_rate() {
+        let easy = Uuid::new_v4();
+        let candidates: Vec<(Uuid, f64)> = [2400.0, 2200.0, 2000.0, 1350.0]
+            .iter()
+            .map(|d| (Uuid::new_v4(), *d))
+            .chain(std::iter::once((easy, 1353.0)))
+            .collect();
+
+        // Réussite attendue ~0.7 à 1500 contre ~1353 : la question facile fait partie du lot
+        let mut rng = StdRng::seed_from_u64(7);
+        let picks: Vec<Uuid> = (0..30)
+            .filter_map(|_| pick_next(&mut rng, 1500.0, &candidates))
+            .collect();
+        assert!(picks.contains(&easy));
+        assert!(picks.iter().all(|id| *id != candidates[0].0));
+
+        assert_eq!(pick_next(&mut rng, 1500.0, &[]), None);
+    }
+}
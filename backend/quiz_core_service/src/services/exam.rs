@@ -0,0 +1,132 @@
+use serde_json::json;
+
+use crate::models::{Quiz, ReponseUtilisateur};
+use crate::plugins::SessionVerdict;
+
+/// Taux de réussite d'un examen : bonnes réponses (score partiel compris) sur les
+/// questions tirées, sans les bonus de vitesse ni de série du score
+pub fn correctness_percentage(answers: &[ReponseUtilisateur], nb_questions: usize) -> f64 {
+    if nb_questions == 0 {
+        return 0.0;
+    }
+    let credit: f64 = answers
+        .iter()
+        .map(|answer| {
+            match answer
+                .metadata
+                .get("partial_score")
+                .and_then(|s| s.as_f64())
+            {
+                Some(partial) => partial.clamp(0.0, 1.0),
+                None if answer.is_correct => 1.0,
+                None => 0.0,
+            }
+        })
+        .sum();
+    (credit / nb_questions as f64 * 100.0).min(100.0)
+}
+
+/// Verdict d'examen : taux de réussite ≥ `score_minimum_success`,
+/// et règles propres au domaine si le plugin en a (ex: nombre de fautes au code)
+pub fn exam_verdict(
+    quiz: &Quiz,
+    pourcentage: f64,
+    plugin_verdict: Option<SessionVerdict>,
+) -> SessionVerdict {
+    let threshold = f64::from(quiz.score_minimum_success);
+    let threshold_passed = pourcentage >= threshold;
+
+    let mut details = json!({
+        "pourcentage": pourcentage,
+        "score_minimum_success": quiz.score_minimum_success,
+    });
+
+    let (passed, message) = match plugin_verdict {
+        Some(verdict) => {
+            details["domain"] = verdict.details;
+            match (threshold_passed, verdict.passed) {
+                (true, true) => (true, verdict.message),
+                (_, false) => (false, verdict.message),
+                (false, true) => (false, threshold_message(pourcentage, threshold)),
+            }
+        }
+        None if threshold_passed => (
+            true,
+            format!(
+                "Examen réussi : {:.0} % (minimum {:.0} %)",
+                pourcentage, threshold
+            ),
+        ),
+        None => (false, threshold_message(pourcentage, threshold)),
+    };
+
+    SessionVerdict {
+        passed,
+        message,
+        details,
+    }
+}
+
+fn threshold_message(pourcentage: f64, threshold: f64) -> String {
+    format!(
+        "Examen échoué : {:.0} % (minimum requis : {:.0} %)",
+        pourcentage, threshold
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures::{self, answer};
+
+    fn quiz(score_minimum_success: i32) -> Quiz {
+        Quiz {
+            score_minimum_success,
+            ..fixtures::quiz("code_route", "examen")
+        }
+    }
+
+    #[test]
+    fn test_correctness_ignores_bonuses() {
+        // 2 bonnes réponses rapides sur 4 questions : 50 %, quels que soient les points
+        let answers = vec![
+            answer(true, json!({})),
+            answer(true, json!({})),
+            answer(false, json!({})),
+        ];
+        assert_eq!(correctness_percentage(&answers, 4), 50.0);
+        assert!(!exam_verdict(&quiz(60), correctness_percentage(&answers, 4), None).passed);
+
+        let partial = vec![answer(true, json!({"partial_score": 0.5}))];
+        assert_eq!(correctness_percentage(&partial, 2), 25.0);
+        assert_eq!(correctness_percentage(&[], 0), 0.0);
+    }
+
+    #[test]
+    fn test_threshold_only() {
+        assert!(exam_verdict(&quiz(50), 50.0, None).passed);
+        let verdict = exam_verdict(&quiz(50), 49.9, None);
+        assert!(!verdict.passed);
+        assert_eq!(verdict.details["score_minimum_success"], 50);
+    }
+
+    #[test]
+    fn test_plugin_rules_also_apply() {
+        let plugin_failed = SessionVerdict {
+            passed: false,
+            message: "Examen échoué : 5 fautes".to_string(),
+            details: json!({"errors": 5}),
+        };
+        let verdict = exam_verdict(&quiz(50), 87.5, Some(plugin_failed));
+        assert!(!verdict.passed);
+        assert_eq!(verdict.message, "Examen échoué : 5 fautes");
+        assert_eq!(verdict.details["domain"]["errors"], 5);
+
+        let plugin_passed = SessionVerdict {
+            passed: true,
+            message: "Examen réussi avec 2 faute(s)".to_string(),
+            details: json!({}),
+        };
+        assert!(!exam_verdict(&quiz(99), 95.0, Some(plugin_passed)).passed);
+    }
+}
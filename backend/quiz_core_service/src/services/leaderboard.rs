@@ -0,0 +1,113 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use serde::Serialize;
+
+/// Début de la fenêtre "all_time"
+pub const ALL_TIME_START: NaiveDate = NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+
+/// Classement : par quiz (meilleure session) ou par domaine (cumul des sessions)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaderboardScope {
+    Quiz,
+    Domain,
+}
+
+impl LeaderboardScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Quiz => "quiz",
+            Self::Domain => "domain",
+        }
+    }
+}
+
+/// Fenêtre de temps d'un classement (jours et semaines en UTC, semaine du lundi)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Period {
+    AllTime,
+    Weekly,
+    Daily,
+}
+
+impl Period {
+    pub const ALL: [Period; 3] = [Period::AllTime, Period::Weekly, Period::Daily];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::AllTime => "all_time",
+            Self::Weekly => "weekly",
+            Self::Daily => "daily",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "all_time" => Some(Self::AllTime),
+            "weekly" => Some(Self::Weekly),
+            "daily" => Some(Self::Daily),
+            _ => None,
+        }
+    }
+
+    /// Premier jour de la fenêtre contenant `date`
+    pub fn start(&self, date: NaiveDate) -> NaiveDate {
+        match self {
+            Self::AllTime => ALL_TIME_START,
+            Self::Weekly => date - Duration::days(i64::from(date.weekday().num_days_from_monday())),
+            Self::Daily => date,
+        }
+    }
+}
+
+/// Fenêtres auxquelles contribue une session terminée à `achieved_at`
+pub fn windows(achieved_at: DateTime<Utc>) -> Vec<(Period, NaiveDate)> {
+    let date = achieved_at.date_naive();
+    Period::ALL
+        .iter()
+        .map(|period| (*period, period.start(date)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_period_start() {
+        // Mercredi 12 novembre 2025
+        let wednesday = date(2025, 11, 12);
+        assert_eq!(Period::Daily.start(wednesday), wednesday);
+        assert_eq!(Period::Weekly.start(wednesday), date(2025, 11, 10));
+        assert_eq!(Period::Weekly.start(date(2025, 11, 10)), date(2025, 11, 10));
+        assert_eq!(Period::Weekly.start(date(2025, 11, 16)), date(2025, 11, 10));
+        assert_eq!(Period::AllTime.start(wednesday), ALL_TIME_START);
+    }
+
+    #[test]
+    fn test_windows_in_utc() {
+        // Dimanche 23h30 UTC : encore dans la semaine qui se termine
+        let achieved_at = Utc.with_ymd_and_hms(2025, 11, 16, 23, 30, 0).unwrap();
+        assert_eq!(
+            windows(achieved_at),
+            vec![
+                (Period::AllTime, ALL_TIME_START),
+                (Period::Weekly, date(2025, 11, 10)),
+                (Period::Daily, date(2025, 11, 16)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        for period in Period::ALL {
+            assert_eq!(Period::parse(period.as_str()), Some(period));
+        }
+        assert_eq!(Period::parse("monthly"), None);
+    }
+}
@@ -0,0 +1,150 @@
+use chrono::{NaiveDate, Utc};
+use shared::AppError;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use super::leaderboard::{self, LeaderboardScope, Period};
+use super::review_service::ReviewService;
+use crate::{
+    dto::leaderboard_dto::{LeaderboardQuery, LeaderboardResponse, MyRankResponse},
+    models::SessionQuiz,
+    repositories::{LeaderboardRepository, leaderboard_repo::LeaderboardSession},
+};
+
+/// Taille de page par défaut / maximale
+const DEFAULT_LIMIT: i64 = 20;
+const MAX_LIMIT: i64 = 100;
+
+/// Nombre de voisins renvoyés de chaque côté du joueur
+const AROUND_RADIUS: i64 = 5;
+
+pub struct LeaderboardService;
+
+impl LeaderboardService {
+    /// Reporter une session terminée dans les classements du quiz et du domaine
+    pub async fn record_session(
+        conn: &mut PgConnection,
+        session: &SessionQuiz,
+        domain: &str,
+    ) -> Result<(), AppError> {
+        // Les révisions mélangent plusieurs quiz : hors classement
+        if session.status != "termine" || ReviewService::is_review_session(session) {
+            return Ok(());
+        }
+
+        let entry = LeaderboardSession {
+            session_id: session.id,
+            user_id: session.user_id,
+            score: session.score,
+            temps_total_sec: session.temps_total_sec.unwrap_or(0),
+            achieved_at: session.date_fin.unwrap_or_else(Utc::now),
+        };
+        let (periods, period_starts): (Vec<String>, Vec<NaiveDate>) =
+            leaderboard::windows(entry.achieved_at)
+                .into_iter()
+                .map(|(period, start)| (period.as_str().to_string(), start))
+                .unzip();
+
+        LeaderboardRepository::record_best(
+            &mut *conn,
+            session.quiz_id,
+            &entry,
+            &periods,
+            &period_starts,
+        )
+        .await?;
+        LeaderboardRepository::record_cumulative(conn, domain, &entry, &periods, &period_starts)
+            .await?;
+
+        tracing::debug!(session_id = %session.id, "Leaderboards updated");
+        Ok(())
+    }
+
+    /// Page d'un classement
+    pub async fn get_leaderboard(
+        pool: &PgPool,
+        scope: LeaderboardScope,
+        key: &str,
+        query: &LeaderboardQuery,
+    ) -> Result<LeaderboardResponse, AppError> {
+        let (period, period_start) = Self::window(query)?;
+        let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+        let offset = query.offset.unwrap_or(0).max(0);
+
+        let entries = LeaderboardRepository::find_top(
+            pool,
+            scope.as_str(),
+            key,
+            period.as_str(),
+            period_start,
+            limit,
+            offset,
+        )
+        .await?;
+        let total_players =
+            LeaderboardRepository::count(pool, scope.as_str(), key, period.as_str(), period_start)
+                .await?;
+
+        Ok(LeaderboardResponse {
+            scope: scope.as_str().to_string(),
+            key: key.to_string(),
+            period: period.as_str().to_string(),
+            period_start,
+            total_players,
+            entries,
+        })
+    }
+
+    /// Rang du joueur et les 5 joueurs de part et d'autre
+    pub async fn get_my_rank(
+        pool: &PgPool,
+        scope: LeaderboardScope,
+        key: &str,
+        user_id: Uuid,
+        query: &LeaderboardQuery,
+    ) -> Result<MyRankResponse, AppError> {
+        let (period, period_start) = Self::window(query)?;
+
+        let entries = LeaderboardRepository::find_around(
+            pool,
+            scope.as_str(),
+            key,
+            period.as_str(),
+            period_start,
+            user_id,
+            AROUND_RADIUS,
+        )
+        .await?;
+        let total_players =
+            LeaderboardRepository::count(pool, scope.as_str(), key, period.as_str(), period_start)
+                .await?;
+        let rank = entries
+            .iter()
+            .find(|entry| entry.user_id == user_id)
+            .map(|entry| entry.rank);
+
+        Ok(MyRankResponse {
+            scope: scope.as_str().to_string(),
+            key: key.to_string(),
+            period: period.as_str().to_string(),
+            period_start,
+            total_players,
+            rank,
+            entries,
+        })
+    }
+
+    fn window(query: &LeaderboardQuery) -> Result<(Period, NaiveDate), AppError> {
+        let period = match query.period.as_deref() {
+            None => Period::AllTime,
+            Some(value) => Period::parse(value).ok_or_else(|| {
+                AppError::BadRequest(format!(
+                    "Invalid period '{}' (all_time, weekly, daily)",
+                    value
+                ))
+            })?,
+        };
+        let date = query.date.unwrap_or_else(|| Utc::now().date_naive());
+        Ok((period, period.start(date)))
+    }
+}
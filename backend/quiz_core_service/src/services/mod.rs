@@ -1,7 +1,33 @@
+pub mod achievement_service;
+pub mod achievements;
+pub mod answer_timing;
+pub mod certificate;
+pub mod daily_challenge;
+pub mod daily_challenge_service;
+pub mod elo;
+pub mod exam;
+pub mod leaderboard;
+pub mod leaderboard_service;
+pub mod plugin_service;
+pub mod progress_service;
+pub mod progression;
+pub mod question_draw;
 pub mod question_service;
 pub mod quiz_service;
 pub(crate) mod reponse_service;
+pub mod review_service;
+pub mod room_service;
+pub mod session_expiry;
+pub mod session_report;
 pub mod session_service;
+pub mod spaced_repetition;
 
+pub use achievement_service::AchievementService;
+pub use daily_challenge_service::DailyChallengeService;
+pub use leaderboard_service::LeaderboardService;
+pub use plugin_service::PluginService;
+pub use progress_service::ProgressService;
 pub use question_service::QuestionService;
+pub use review_service::ReviewService;
+pub use room_service::RoomService;
 pub use session_service::SessionService;
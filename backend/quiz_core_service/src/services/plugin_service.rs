@@ -0,0 +1,111 @@
+use std::collections::BTreeMap;
+
+use shared::AppError;
+use sqlx::PgPool;
+
+use crate::{
+    dto::plugin_dto::{PluginInfo, PluginListResponse},
+    plugins::{DomainConfig, PluginManager},
+    repositories::DomainRepository,
+};
+
+pub struct PluginService;
+
+impl PluginService {
+    /// Lister les domaines et le plugin chargé pour chacun
+    pub async fn list(
+        pool: &PgPool,
+        plugin_manager: &PluginManager,
+    ) -> Result<PluginListResponse, AppError> {
+        let registry = plugin_manager.current();
+        let domains = DomainRepository::find_all(pool).await?;
+
+        let plugins = domains
+            .into_iter()
+            .map(|domain| match registry.get(&domain.name) {
+                Some(plugin) => PluginInfo {
+                    domain: domain.name.clone(),
+                    display_name: plugin.display_name().to_string(),
+                    description: plugin.description().to_string(),
+                    is_active: domain.is_active.unwrap_or(false),
+                    loaded: true,
+                },
+                None => PluginInfo {
+                    display_name: domain.display_name,
+                    description: domain.description.unwrap_or_default(),
+                    is_active: domain.is_active.unwrap_or(false),
+                    domain: domain.name,
+                    loaded: false,
+                },
+            })
+            .collect();
+
+        Ok(PluginListResponse {
+            registry_version: registry.version(),
+            plugins,
+        })
+    }
+
+    /// Activer/désactiver un domaine puis recharger le registry
+    pub async fn set_domain_active(
+        pool: &PgPool,
+        plugin_manager: &PluginManager,
+        domain: &str,
+        is_active: bool,
+    ) -> Result<PluginListResponse, AppError> {
+        DomainRepository::set_active(pool, domain, is_active)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Domain {} not found", domain)))?;
+
+        tracing::info!(domain = %domain, is_active, "Domain activation changed");
+
+        plugin_manager.reload(pool).await?;
+        Self::list(pool, plugin_manager).await
+    }
+
+    /// Remplacer `domains.config` d'un domaine puis recharger le registry
+    /// Règles hors bornes (pénalité d'indice, multiplicateurs...) refusées avant écriture
+    pub async fn update_domain_config(
+        pool: &PgPool,
+        plugin_manager: &PluginManager,
+        domain: &str,
+        config: serde_json::Value,
+    ) -> Result<PluginListResponse, AppError> {
+        DomainConfig::from_json(&config).map_err(|e| {
+            AppError::BadRequest(format!(
+                "Configuration du domaine {} invalide : {}",
+                domain, e
+            ))
+        })?;
+
+        DomainRepository::update_config(pool, domain, &config)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Domain {} not found", domain)))?;
+
+        tracing::info!(domain = %domain, "Domain config updated");
+
+        plugin_manager.reload(pool).await?;
+        Self::list(pool, plugin_manager).await
+    }
+
+    /// Recharger les plugins (modules WASM, domains.config) sans redémarrer
+    pub async fn reload(
+        pool: &PgPool,
+        plugin_manager: &PluginManager,
+    ) -> Result<PluginListResponse, AppError> {
+        plugin_manager.reload(pool).await?;
+        Self::list(pool, plugin_manager).await
+    }
+
+    /// Schémas JSON de `question_data` d'un domaine, par type de question
+    pub fn question_schemas(
+        plugin_manager: &PluginManager,
+        domain: &str,
+    ) -> Result<BTreeMap<String, serde_json::Value>, AppError> {
+        let registry = plugin_manager.current();
+        let plugin = registry
+            .get(domain)
+            .ok_or_else(|| AppError::NotFound(format!("No plugin found for domain: {}", domain)))?;
+        Ok(plugin.question_schemas())
+    }
+}
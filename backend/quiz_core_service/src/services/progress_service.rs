@@ -0,0 +1,188 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+use chrono_tz::Tz;
+use shared::AppError;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use super::progression::{self, StreakState};
+use crate::{
+    dto::progress_dto::{DomainMastery, ProgressResponse, StreakProgress},
+    models::{Quiz, ReponseUtilisateur, SessionQuiz, UserProgress},
+    repositories::ProgressRepository,
+};
+
+pub struct ProgressService;
+
+impl ProgressService {
+    /// Ajouter une session terminée à la progression du joueur (XP, maîtrise, série)
+    pub async fn record_session(
+        conn: &mut PgConnection,
+        session: &SessionQuiz,
+        quiz: &Quiz,
+        answers: &[ReponseUtilisateur],
+    ) -> Result<(), AppError> {
+        if session.status != "termine" {
+            return Ok(());
+        }
+
+        let xp = progression::session_xp(
+            session.pourcentage.unwrap_or(0.0),
+            &quiz.niveau_difficulte,
+            &quiz.mode,
+        );
+
+        let progress = ProgressRepository::find(&mut *conn, session.user_id).await?;
+        let timezone = Self::user_timezone(progress.as_ref());
+        let mut streak = progress.as_ref().map(Self::streak).unwrap_or_default();
+        streak.record(progression::local_day(
+            session.date_fin.unwrap_or_else(Utc::now),
+            timezone,
+        ));
+
+        ProgressRepository::add_session(&mut *conn, session.user_id, xp, &streak).await?;
+        let correct = answers.iter().filter(|a| a.is_correct).count();
+        ProgressRepository::add_domain(
+            conn,
+            session.user_id,
+            &quiz.domain,
+            xp,
+            answers.len() as i32,
+            correct as i32,
+        )
+        .await?;
+
+        tracing::debug!(session_id = %session.id, xp, streak = streak.current, "Progress updated");
+        Ok(())
+    }
+
+    /// Niveau, série et maîtrise par domaine
+    pub async fn get_progress(pool: &PgPool, user_id: Uuid) -> Result<ProgressResponse, AppError> {
+        let progress = ProgressRepository::find(pool, user_id).await?;
+        let domains = ProgressRepository::find_domains(pool, user_id).await?;
+
+        let timezone = Self::user_timezone(progress.as_ref());
+        let today = progression::local_day(Utc::now(), timezone);
+        let streak = progress.as_ref().map(Self::streak).unwrap_or_default();
+
+        Ok(ProgressResponse {
+            user_id,
+            level: progression::level_for(progress.as_ref().map(|p| p.xp).unwrap_or(0)),
+            sessions_count: progress.as_ref().map(|p| p.sessions_count).unwrap_or(0),
+            streak: StreakProgress {
+                current: streak.current_on(today),
+                best: streak.best,
+                grace_tokens: streak.grace_tokens,
+                last_played_on: streak.last_played_on,
+                played_today: streak.last_played_on == Some(today),
+                timezone: timezone.name().to_string(),
+            },
+            domains: domains
+                .into_iter()
+                .map(|domain| {
+                    let accuracy = if domain.answers_count > 0 {
+                        f64::from(domain.correct_count) / f64::from(domain.answers_count)
+                    } else {
+                        0.0
+                    };
+                    DomainMastery {
+                        mastery: progression::mastery(domain.xp, accuracy),
+                        domain: domain.domain,
+                        xp: domain.xp,
+                        answers_count: domain.answers_count,
+                        accuracy,
+                    }
+                })
+                .collect(),
+        })
+    }
+
+    /// Changer de fuseau : les jours de la série sont recomptés
+    pub async fn set_timezone(
+        pool: &PgPool,
+        user_id: Uuid,
+        timezone: &str,
+    ) -> Result<ProgressResponse, AppError> {
+        let timezone: Tz = timezone.parse().map_err(|_| {
+            AppError::BadRequest(format!(
+                "Unknown timezone '{}' (ex: Europe/Paris)",
+                timezone
+            ))
+        })?;
+        ProgressRepository::set_timezone(pool, user_id, timezone.name()).await?;
+        Self::rebuild_user(pool, user_id).await?;
+        Self::get_progress(pool, user_id).await
+    }
+
+    /// Recalculer la progression d'un joueur depuis ses sessions et réponses
+    pub async fn rebuild_user(pool: &PgPool, user_id: Uuid) -> Result<i64, AppError> {
+        let progress = ProgressRepository::find(pool, user_id).await?;
+        let timezone = Self::user_timezone(progress.as_ref());
+        let sessions = ProgressRepository::find_finished_sessions(pool, user_id).await?;
+
+        let mut xp = 0;
+        let mut domain_xp: HashMap<String, i64> = HashMap::new();
+        let mut streak = StreakState::default();
+        for session in &sessions {
+            let session_xp = progression::session_xp(
+                session.pourcentage.unwrap_or(0.0),
+                &session.niveau_difficulte,
+                &session.mode,
+            );
+            xp += session_xp;
+            *domain_xp.entry(session.domain.clone()).or_default() += session_xp;
+            streak.record(progression::local_day(session.date_fin, timezone));
+        }
+
+        ProgressRepository::replace(pool, user_id, xp, sessions.len() as i32, &streak).await?;
+        ProgressRepository::delete_domains(pool, user_id).await?;
+        for (domain, answers, correct) in
+            ProgressRepository::count_domain_answers(pool, user_id).await?
+        {
+            let xp = domain_xp.remove(&domain).unwrap_or(0);
+            ProgressRepository::add_domain(
+                pool,
+                user_id,
+                &domain,
+                xp,
+                answers as i32,
+                correct as i32,
+            )
+            .await?;
+        }
+        // Domaines joués sans réponse enregistrée
+        for (domain, xp) in domain_xp {
+            ProgressRepository::add_domain(pool, user_id, &domain, xp, 0, 0).await?;
+        }
+
+        tracing::info!(user_id = %user_id, xp, sessions = sessions.len(), "Progress rebuilt");
+        Ok(xp)
+    }
+
+    /// Recalculer la progression de tous les joueurs
+    pub async fn rebuild_all(pool: &PgPool) -> Result<usize, AppError> {
+        let user_ids = ProgressRepository::find_user_ids(pool).await?;
+        for user_id in &user_ids {
+            Self::rebuild_user(pool, *user_id).await?;
+        }
+        tracing::info!(users = user_ids.len(), "Progress rebuilt for all users");
+        Ok(user_ids.len())
+    }
+
+    /// Fuseau enregistré (UTC par défaut ou si invalide)
+    fn user_timezone(progress: Option<&UserProgress>) -> Tz {
+        progress
+            .and_then(|p| p.timezone.parse().ok())
+            .unwrap_or(Tz::UTC)
+    }
+
+    fn streak(progress: &UserProgress) -> StreakState {
+        StreakState {
+            current: progress.current_streak,
+            best: progress.best_streak,
+            grace_tokens: progress.grace_tokens,
+            last_played_on: progress.last_played_on,
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::Tz;
+use serde::Serialize;
+
+/// XP nécessaire pour passer du niveau n au niveau n+1 : `LEVEL_BASE_XP × n`
+pub const LEVEL_BASE_XP: i64 = 100;
+
+/// Un jeton de grâce est gagné tous les 7 jours de série (1 au maximum) ;
+/// il rattrape automatiquement une journée manquée
+pub const GRACE_TOKEN_EVERY_DAYS: i32 = 7;
+pub const MAX_GRACE_TOKENS: i32 = 1;
+
+/// XP d'une session terminée : 10 + jusqu'à 90 selon le pourcentage,
+/// multiplié par la difficulté du quiz et par son mode
+pub fn session_xp(pourcentage: f64, niveau_difficulte: &str, mode: &str) -> i64 {
+    let base = 10.0 + pourcentage.clamp(0.0, 100.0) * 0.9;
+    let difficulty = match niveau_difficulte {
+        "moyen" => 1.5,
+        "difficile" => 2.0,
+        _ => 1.0,
+    };
+    let mode = match mode {
+        "decouverte" => 0.75,
+        "examen" => 1.5,
+        "competition" => 1.25,
+        _ => 1.0,
+    };
+    (base * difficulty * mode).round() as i64
+}
+
+/// Niveau atteint et avancement vers le suivant
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LevelProgress {
+    pub level: i32,
+    pub xp: i64,
+    /// XP accumulée depuis le début du niveau
+    pub level_xp: i64,
+    /// XP à accumuler dans ce niveau pour passer au suivant
+    pub next_level_xp: i64,
+}
+
+pub fn level_for(xp: i64) -> LevelProgress {
+    let mut level = 1;
+    let mut remaining = xp.max(0);
+    while remaining >= LEVEL_BASE_XP * i64::from(level) {
+        remaining -= LEVEL_BASE_XP * i64::from(level);
+        level += 1;
+    }
+    LevelProgress {
+        level,
+        xp,
+        level_xp: remaining,
+        next_level_xp: LEVEL_BASE_XP * i64::from(level),
+    }
+}
+
+/// Maîtrise d'un domaine : XP du domaine et taux de bonnes réponses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MasteryTier {
+    Novice,
+    Apprenti,
+    Confirme,
+    Expert,
+    Maitre,
+}
+
+/// Paliers : (niveau, XP minimale, précision minimale)
+const MASTERY_TIERS: [(MasteryTier, i64, f64); 4] = [
+    (MasteryTier::Maitre, 3500, 0.85),
+    (MasteryTier::Expert, 1500, 0.70),
+    (MasteryTier::Confirme, 600, 0.50),
+    (MasteryTier::Apprenti, 200, 0.0),
+];
+
+pub fn mastery(xp: i64, accuracy: f64) -> MasteryTier {
+    MASTERY_TIERS
+        .iter()
+        .find(|(_, min_xp, min_accuracy)| xp >= *min_xp && accuracy >= *min_accuracy)
+        .map(|(tier, _, _)| *tier)
+        .unwrap_or(MasteryTier::Novice)
+}
+
+/// Jour local d'une session dans le fuseau du joueur
+pub fn local_day(at: DateTime<Utc>, timezone: Tz) -> NaiveDate {
+    at.with_timezone(&timezone).date_naive()
+}
+
+/// Série de jours joués (au moins une session terminée par jour local)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StreakState {
+    pub current: i32,
+    pub best: i32,
+    pub grace_tokens: i32,
+    pub last_played_on: Option<NaiveDate>,
+}
+
+impl StreakState {
+    /// Prendre en compte une journée jouée (jours reçus dans l'ordre chronologique)
+    pub fn record(&mut self, day: NaiveDate) {
+        let gap = self.last_played_on.map(|last| (day - last).num_days());
+        match gap {
+            // Déjà compté ou antérieur
+            Some(gap) if gap <= 0 => return,
+            Some(1) => self.current += 1,
+            // Un seul jour manqué : rattrapé par un jeton
+            Some(2) if self.grace_tokens > 0 => {
+                self.grace_tokens -= 1;
+                self.current += 1;
+            }
+            _ => {
+                self.current = 1;
+                self.grace_tokens = 0;
+            }
+        }
+
+        if self.current % GRACE_TOKEN_EVERY_DAYS == 0 {
+            self.grace_tokens = (self.grace_tokens + 1).min(MAX_GRACE_TOKENS);
+        }
+        self.best = self.best.max(self.current);
+        self.last_played_on = Some(day);
+    }
+
+    /// Série encore valable aujourd'hui (jouer aujourd'hui la prolonge)
+    pub fn current_on(&self, today: NaiveDate) -> i32 {
+        match self.last_played_on.map(|last| (today - last).num_days()) {
+            Some(0 | 1) => self.current,
+            Some(2) if self.grace_tokens > 0 => self.current,
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 11, d).unwrap()
+    }
+
+    #[test]
+    fn test_session_xp() {
+        assert_eq!(session_xp(100.0, "facile", "entrainement"), 100);
+        assert_eq!(session_xp(0.0, "facile", "entrainement"), 10);
+        assert_eq!(session_xp(100.0, "difficile", "examen"), 300);
+        assert_eq!(session_xp(50.0, "moyen", "decouverte"), 62);
+    }
+
+    #[test]
+    fn test_levels() {
+        assert_eq!(level_for(0).level, 1);
+        assert_eq!(level_for(99).level, 1);
+        // 100 XP pour le niveau 2, puis 200 pour le niveau 3
+        let progress = level_for(250);
+        assert_eq!(progress.level, 2);
+        assert_eq!(progress.level_xp, 150);
+        assert_eq!(progress.next_level_xp, 200);
+        assert_eq!(level_for(300).level, 3);
+    }
+
+    #[test]
+    fn test_mastery_requires_accuracy() {
+        assert_eq!(mastery(0, 1.0), MasteryTier::Novice);
+        assert_eq!(mastery(250, 0.2), MasteryTier::Apprenti);
+        assert_eq!(mastery(2000, 0.6), MasteryTier::Confirme);
+        assert_eq!(mastery(2000, 0.75), MasteryTier::Expert);
+        assert_eq!(mastery(5000, 0.9), MasteryTier::Maitre);
+    }
+
+    #[test]
+    fn test_local_day() {
+        // 23h30 UTC le 9 novembre : déjà le 10 à Paris, encore le 9 à New York
+        let at = Utc.with_ymd_and_hms(2025, 11, 9, 23, 30, 0).unwrap();
+        assert_eq!(local_day(at, chrono_tz::Europe::Paris), day(10));
+        assert_eq!(local_day(at, chrono_tz::America::New_York), day(9));
+    }
+
+    #[test]
+    fn test_streak_and_reset() {
+        let mut streak = StreakState::default();
+        for d in [1, 2, 2, 3] {
+            streak.record(day(d));
+        }
+        assert_eq!(streak.current, 3);
+        assert_eq!(streak.current_on(day(4)), 3);
+
+        // Deux jours manqués sans jeton : la série repart de 1
+        streak.record(day(6));
+        assert_eq!(streak.current, 1);
+        assert_eq!(streak.best, 3);
+        assert_eq!(streak.current_on(day(9)), 0);
+    }
+
+    #[test]
+    fn test_grace_token_bridges_one_missed_day() {
+        let mut streak = StreakState::default();
+        for d in 1..=7 {
+            streak.record(day(d));
+        }
+        assert_eq!(streak.grace_tokens, 1);
+        // Le 8 est manqué : toujours en cours le 9 grâce au jeton
+        assert_eq!(streak.current_on(day(9)), 7);
+
+        streak.record(day(9));
+        assert_eq!(streak.current, 8);
+        assert_eq!(streak.grace_tokens, 0);
+
+        // Plus de jeton : un nouveau jour manqué casse la série
+        streak.record(day(9) + Duration::days(2));
+        assert_eq!(streak.current, 1);
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::BTreeMap;
+
+use rand::Rng;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::models::{Question, Reponse};
+
+/// Question tirée pour une session, avec l'ordre d'affichage de ses réponses
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DrawnQuestion {
+    pub question_id: Uuid,
+    pub reponse_ids: Vec<Uuid>,
+}
+
+/// Critère de stratification du tirage (`quizzes.metadata.stratify_by`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stratify {
+    Category,
+    Subcategory,
+}
+
+impl Stratify {
+    pub fn from_quiz_metadata(metadata: &serde_json::Value) -> Option<Self> {
+        match metadata.get("stratify_by").and_then(|v| v.as_str()) {
+            Some("category") => Some(Self::Category),
+            Some("subcategory") => Some(Self::Subcategory),
+            _ => None,
+        }
+    }
+
+    fn key(&self, question: &Question) -> Option<String> {
+        match self {
+            Self::Category => question.category.clone(),
+            Self::Subcategory => question.subcategory.clone(),
+        }
+    }
+}
+
+/// Tirer `count` questions au hasard (toutes si le quiz en a moins)
+/// Stratifié : chaque groupe est représenté au prorata de sa taille (plus forts restes)
+/// Les réponses de chaque question sont mélangées
+pub fn draw_questions<R: Rng>(
+    rng: &mut R,
+    questions: &[(Question, Vec<Reponse>)],
+    count: usize,
+    stratify: Option<Stratify>,
+) -> Vec<DrawnQuestion> {
+    let count = count.min(questions.len());
+
+    let mut selected: Vec<&(Question, Vec<Reponse>)> = match stratify {
+        None => questions.choose_multiple(rng, count).collect(),
+        Some(stratify) => {
+            let mut groups: BTreeMap<Option<String>, Vec<&(Question, Vec<Reponse>)>> =
+                BTreeMap::new();
+            for item in questions {
+                groups.entry(stratify.key(&item.0)).or_default().push(item);
+            }
+
+            // Quotas proportionnels, le reliquat aux plus forts restes
+            let total = questions.len();
+            let mut quotas: Vec<(usize, usize, &Vec<_>)> = groups
+                .values()
+                .map(|group| {
+                    let exact = count * group.len();
+                    (exact / total, exact % total, group)
+                })
+                .collect();
+            let assigned: usize = quotas.iter().map(|(quota, _, _)| quota).sum();
+            let mut by_remainder: Vec<usize> = (0..quotas.len()).collect();
+            by_remainder.sort_by(|a, b| quotas[*b].1.cmp(&quotas[*a].1));
+            for index in by_remainder.into_iter().take(count - assigned) {
+                quotas[index].0 += 1;
+            }
+
+            quotas
+                .into_iter()
+                .flat_map(|(quota, _, group)| group.choose_multiple(rng, quota).copied())
+                .collect()
+        }
+    };
+
+    selected.shuffle(rng);
+
+    selected
+        .into_iter()
+        .map(|(question, reponses)| {
+            let mut reponse_ids: Vec<Uuid> = reponses.iter().map(|r| r.id).collect();
+            reponse_ids.shuffle(rng);
+            DrawnQuestion {
+                question_id: question.id,
+                reponse_ids,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures::{self, reponse};
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn question(category: &str) -> (Question, Vec<Reponse>) {
+        let question = Question {
+            temps_limite_sec: Some(15),
+            ..fixtures::question(category, "qcm")
+        };
+        let reponses = (1..=4)
+            .map(|ordre| {
+                reponse(
+                    question.id,
+                    &format!("Réponse {}", ordre),
+                    ordre == 1,
+                    ordre,
+                )
+            })
+            .collect();
+        (question, reponses)
+    }
+
+    #[test]
+    fn test_draw_count_and_uniqueness() {
+        let questions: Vec<_> = (0..10).map(|_| question("fleuves")).collect();
+        let mut rng = StdRng::seed_from_u64(7);
+
+        let drawn = draw_questions(&mut rng, &questions, 4, None);
+        assert_eq!(drawn.len(), 4);
+        let mut ids: Vec<Uuid> = drawn.iter().map(|d| d.question_id).collect();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), 4);
+        assert!(drawn.iter().all(|d| d.reponse_ids.len() == 4));
+
+        // Pas plus de questions que le quiz n'en contient
+        assert_eq!(draw_questions(&mut rng, &questions, 40, None).len(), 10);
+    }
+
+    #[test]
+    fn test_stratified_draw() {
+        let mut questions: Vec<_> = (0..6).map(|_| question("fleuves")).collect();
+        questions.extend((0..3).map(|_| question("reliefs")));
+        questions.extend((0..1).map(|_| question("regions")));
+        let mut rng = StdRng::seed_from_u64(42);
+
+        let drawn = draw_questions(&mut rng, &questions, 5, Some(Stratify::Category));
+        let count = |category: &str| {
+            drawn
+                .iter()
+                .filter(|d| {
+                    questions.iter().any(|(q, _)| {
+                        q.id == d.question_id && q.category.as_deref() == Some(category)
+                    })
+                })
+                .count()
+        };
+
+        assert_eq!(drawn.len(), 5);
+        assert_eq!(count("fleuves"), 3);
+        // 1.5 et 0.5 : le reliquat va au plus fort reste
+        assert_eq!(count("reliefs") + count("regions"), 2);
+        assert!(count("reliefs") >= 1);
+    }
+}
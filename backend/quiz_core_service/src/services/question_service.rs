@@ -1,14 +1,22 @@
-use shared::AppError;
+use rand::seq::SliceRandom;
+use shared::{AppError, FieldError};
 use sqlx::PgPool;
 use uuid::Uuid;
 
 use crate::dto::{QuestionWithReponses, ReponseDto};
 use crate::{
     dto::question_dto::{CreateQuestionRequest, UpdateQuestionRequest},
-    models::Question,
-    repositories::question_repo::QuestionRepository,
+    models::{Question, Reponse},
+    plugins::{PluginManager, validate_question_data},
+    repositories::{QuizRepository, ReponseRepository, question_repo::QuestionRepository},
 };
 
+/// Seul mode où les indices sont proposés
+const HINT_MODE: &str = "decouverte";
+
+/// Mode sans explication avant la fin
+const EXAM_MODE: &str = "examen";
+
 pub struct QuestionService;
 
 impl QuestionService {
@@ -18,57 +26,222 @@ impl QuestionService {
     ) -> Result<Vec<QuestionWithReponses>, AppError> {
         let questions_with_reponses =
             QuestionRepository::find_by_quiz_id_with_reponses(pool, quiz_id).await?;
+        let mode = QuizRepository::find_by_id(pool, quiz_id)
+            .await?
+            .map(|quiz| quiz.mode)
+            .unwrap_or_default();
 
         // Convertir en DTO
         let result = questions_with_reponses
             .into_iter()
             .map(|(question, reponses)| {
-                QuestionWithReponses {
-                    id: question.id,
-                    quiz_id: question.quiz_id,
-                    ordre: question.ordre,
-                    category: question.category,
-                    subcategory: question.subcategory,
-                    type_question: question.type_question.clone(),
-                    question_data: question.question_data.clone(),
-                    media_url: question.media_url.clone(),
-                    target_id: question.target_id,
-                    points: question.points,
-                    temps_limite_sec: question.temps_limite_sec,
-                    hint: question.hint.clone(),
-                    explanation: question.explanation.clone(),
-                    metadata: question.metadata.clone(),
-                    total_attempts: question.total_attempts,
-                    correct_attempts: question.correct_attempts,
-                    created_at: question.created_at,
-                    updated_at: question.updated_at,
-                    reponses: reponses
-                        .into_iter()
-                        .map(|r| ReponseDto {
-                            id: r.id,
-                            valeur: r.valeur,
-                            is_correct: None, // ❌ NE PAS exposer is_correct au client !
-                            ordre: r.ordre,
-                        })
-                        .collect(),
-                }
+                Self::for_mode(Self::to_client_dto(question, reponses, None), &mode)
             })
             .collect();
 
         Ok(result)
     }
 
+    /// Question seule telle que la voit un joueur (même DTO que la liste du quiz)
+    pub async fn get_for_client(pool: &PgPool, id: Uuid) -> Result<QuestionWithReponses, AppError> {
+        let question = Self::get_by_id(pool, id).await?;
+        let reponses = ReponseRepository::find_by_question_id(pool, id).await?;
+        let mode = QuizRepository::find_by_id(pool, question.quiz_id)
+            .await?
+            .map(|quiz| quiz.mode)
+            .unwrap_or_default();
+
+        Ok(Self::for_mode(
+            Self::to_client_dto(question, reponses, None),
+            &mode,
+        ))
+    }
+
+    /// Question prête pour le client (sans la solution)
+    /// `order` : ordre d'affichage des réponses tiré pour la session (aléatoire sinon)
+    pub fn to_client_dto(
+        question: Question,
+        reponses: Vec<Reponse>,
+        order: Option<&[Uuid]>,
+    ) -> QuestionWithReponses {
+        QuestionWithReponses {
+            id: question.id,
+            quiz_id: question.quiz_id,
+            ordre: question.ordre,
+            category: question.category,
+            subcategory: question.subcategory,
+            question_data: Self::question_data_for_client(
+                &question.type_question,
+                &question.question_data,
+            ),
+            reponses: Self::reponses_for_client(&question.type_question, reponses, order),
+            type_question: question.type_question,
+            media_url: question.media_url,
+            target_id: question.target_id,
+            points: question.points,
+            temps_limite_sec: question.temps_limite_sec,
+            has_hint: question.hint.is_some(),
+            explanation: question.explanation,
+            metadata: question.metadata,
+            total_attempts: question.total_attempts,
+            correct_attempts: question.correct_attempts,
+            created_at: question.created_at,
+            updated_at: question.updated_at,
+        }
+    }
+
+    /// Adapter une question au mode du quiz :
+    /// indice disponible seulement en découverte, pas d'explication en examen
+    pub fn for_mode(mut question: QuestionWithReponses, mode: &str) -> QuestionWithReponses {
+        if mode != HINT_MODE {
+            question.has_hint = false;
+        }
+        if mode == EXAM_MODE {
+            question.explanation = None;
+        }
+        question
+    }
+
+    /// Retirer de `question_data` les champs qui révèlent la solution
+    /// Pour "numerique", la valeur attendue et les tolérances restent côté serveur
+    fn question_data_for_client(
+        type_question: &str,
+        question_data: &serde_json::Value,
+    ) -> serde_json::Value {
+        let mut data = question_data.clone();
+        if type_question == "numerique"
+            && let Some(map) = data.as_object_mut()
+        {
+            for key in [
+                "expected_value",
+                "absolute_tolerance",
+                "relative_tolerance",
+                "partial_range",
+            ] {
+                map.remove(key);
+            }
+        }
+        data
+    }
+
+    /// Convertir les réponses pour le client sans révéler la solution
+    /// Pour "ordre", `reponses.ordre` EST la solution : on mélange et on renumérote
+    /// Pour "association", chaque colonne est mélangée et la clé de paire reste cachée
+    fn reponses_for_client(
+        type_question: &str,
+        mut reponses: Vec<Reponse>,
+        order: Option<&[Uuid]>,
+    ) -> Vec<ReponseDto> {
+        let is_association = type_question == "association";
+        let hide_order = type_question == "ordre" || is_association;
+        match order {
+            Some(order) => reponses.sort_by_key(|r| order.iter().position(|id| *id == r.id)),
+            None if hide_order => reponses.shuffle(&mut rand::thread_rng()),
+            None => {}
+        }
+
+        reponses
+            .into_iter()
+            .enumerate()
+            .map(|(index, r)| ReponseDto {
+                id: r.id,
+                valeur: r.valeur,
+                is_correct: None, // ❌ NE PAS exposer is_correct au client !
+                ordre: if hide_order {
+                    index as i32 + 1
+                } else {
+                    r.ordre
+                },
+                colonne: if is_association {
+                    r.metadata
+                        .get("colonne")
+                        .and_then(|c| c.as_str())
+                        .map(str::to_string)
+                } else {
+                    None
+                },
+            })
+            .collect()
+    }
+
     pub async fn get_by_id(pool: &PgPool, id: Uuid) -> Result<Question, AppError> {
         QuestionRepository::find_by_id(pool, id)
             .await?
             .ok_or_else(|| AppError::NotFound(format!("Question with id {} not found", id)))
     }
 
+    /// Valider `question_data` avec le schéma déclaré par le plugin du domaine du quiz
+    /// (et la présence de l'explication si le domaine l'exige)
+    /// Les réponses, créées ensuite, sont contrôlées par `ReponseService` (éléments d'un
+    /// "ordre", paires d'une "association")
+    async fn validate_question(
+        pool: &PgPool,
+        plugin_manager: &PluginManager,
+        quiz_id: Uuid,
+        type_question: &str,
+        question_data: &serde_json::Value,
+        explanation: Option<&str>,
+    ) -> Result<(), AppError> {
+        let quiz = QuizRepository::find_by_id(pool, quiz_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Quiz with id {} not found", quiz_id)))?;
+
+        let plugin_registry = plugin_manager.current();
+        let plugin = plugin_registry.get(&quiz.domain).ok_or_else(|| {
+            AppError::BadRequest(format!("Le domaine {} est désactivé", quiz.domain))
+        })?;
+
+        let schemas = plugin.question_schemas();
+        let schema = schemas.get(type_question).ok_or_else(|| {
+            AppError::Validation(vec![FieldError {
+                field: "type_question".to_string(),
+                message: format!(
+                    "Type de question '{}' non supporté par le domaine {}",
+                    type_question, quiz.domain
+                ),
+            }])
+        })?;
+
+        if let Some(error) =
+            Self::missing_explanation(plugin.explanation_required(), &quiz.domain, explanation)
+        {
+            return Err(AppError::Validation(vec![error]));
+        }
+
+        validate_question_data(schema, question_data).map_err(AppError::Validation)
+    }
+
+    /// Erreur sur `explanation` si le domaine l'exige et qu'elle est absente ou vide
+    fn missing_explanation(
+        required: bool,
+        domain: &str,
+        explanation: Option<&str>,
+    ) -> Option<FieldError> {
+        (required && explanation.is_none_or(|e| e.trim().is_empty())).then(|| FieldError {
+            field: "explanation".to_string(),
+            message: format!(
+                "Explication obligatoire pour les questions du domaine {}",
+                domain
+            ),
+        })
+    }
+
     /// ✅ Créer question (avec category/subcategory)
     pub async fn create(
         pool: &PgPool,
+        plugin_manager: &PluginManager,
         request: CreateQuestionRequest,
     ) -> Result<Question, AppError> {
+        Self::validate_question(
+            pool,
+            plugin_manager,
+            request.quiz_id,
+            &request.type_question,
+            &request.question_data,
+            request.explanation.as_deref(),
+        )
+        .await?;
+
         let question = QuestionRepository::create(
             pool,
             request.quiz_id,
@@ -92,9 +265,21 @@ impl QuestionService {
     /// ✅ Mettre à jour question
     pub async fn update(
         pool: &PgPool,
+        plugin_manager: &PluginManager,
         id: Uuid,
         request: UpdateQuestionRequest,
     ) -> Result<Question, AppError> {
+        let existing = Self::get_by_id(pool, id).await?;
+        Self::validate_question(
+            pool,
+            plugin_manager,
+            existing.quiz_id,
+            &request.type_question,
+            &request.question_data,
+            request.explanation.as_deref(),
+        )
+        .await?;
+
         let question = QuestionRepository::update(
             pool,
             id,
@@ -120,3 +305,84 @@ impl QuestionService {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures;
+
+    #[test]
+    fn test_missing_explanation_is_a_field_error() {
+        let error = QuestionService::missing_explanation(true, "code_route", Some("  ")).unwrap();
+        assert_eq!(error.field, "explanation");
+        assert!(QuestionService::missing_explanation(true, "code_route", None).is_some());
+        assert!(
+            QuestionService::missing_explanation(true, "code_route", Some("Priorité à droite"))
+                .is_none()
+        );
+        assert!(QuestionService::missing_explanation(false, "geography", None).is_none());
+    }
+
+    #[test]
+    fn test_client_dto_hides_numeric_answer() {
+        let question = Question {
+            question_data: serde_json::json!({
+                "text": "Altitude du mont Blanc ?",
+                "expected_value": 4808,
+                "unit": "m",
+                "absolute_tolerance": 50,
+                "relative_tolerance": 0.01,
+                "partial_range": 3
+            }),
+            ..fixtures::question("reliefs", "numerique")
+        };
+
+        let dto = QuestionService::for_mode(
+            QuestionService::to_client_dto(question, Vec::new(), None),
+            "entrainement",
+        );
+        let payload = serde_json::to_value(&dto).unwrap();
+        assert_eq!(payload["question_data"]["text"], "Altitude du mont Blanc ?");
+        assert_eq!(payload["question_data"]["unit"], "m");
+        for key in [
+            "expected_value",
+            "absolute_tolerance",
+            "relative_tolerance",
+            "partial_range",
+        ] {
+            assert!(
+                payload["question_data"].get(key).is_none(),
+                "{} exposé",
+                key
+            );
+        }
+        assert!(!payload["question_data"].to_string().contains("4808"));
+    }
+
+    #[test]
+    fn test_client_dto_hides_hint_and_exam_explanation() {
+        let question = Question {
+            hint: Some("Le plus haut sommet des Alpes".to_string()),
+            explanation: Some("4 808 m au dernier relevé".to_string()),
+            ..fixtures::question("reliefs", "qcm")
+        };
+
+        let exam = QuestionService::for_mode(
+            QuestionService::to_client_dto(question.clone(), Vec::new(), None),
+            "examen",
+        );
+        let payload = serde_json::to_value(&exam).unwrap();
+        assert!(payload.get("hint").is_none());
+        assert_eq!(payload["has_hint"], false);
+        assert!(payload["explanation"].is_null());
+
+        // En découverte : l'indice est signalé mais son texte reste derrière /hint
+        let decouverte = QuestionService::for_mode(
+            QuestionService::to_client_dto(question, Vec::new(), None),
+            "decouverte",
+        );
+        let payload = serde_json::to_value(&decouverte).unwrap();
+        assert_eq!(payload["has_hint"], true);
+        assert!(!payload.to_string().contains("Le plus haut sommet"));
+    }
+}
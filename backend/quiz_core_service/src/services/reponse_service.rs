@@ -1,7 +1,8 @@
 use crate::{
     dto::{CreateBulkReponsesRequest, CreateReponseRequest, UpdateReponseRequest},
     models::Reponse,
-    repositories::{QuestionRepository, ReponseRepository},
+    plugins::{ReponseSpec, validate_reponse, validate_reponses},
+    repositories::{QuestionRepository, ReponseRepository, reponse_repo::ReponseFields},
 };
 use shared::AppError;
 use sqlx::PgPool;
@@ -36,7 +37,7 @@ impl ReponseService {
     /// Créer une nouvelle réponse
     pub async fn create(pool: &PgPool, request: CreateReponseRequest) -> Result<Reponse, AppError> {
         // Vérifier que la question existe
-        QuestionRepository::find_by_id(pool, request.question_id)
+        let question = QuestionRepository::find_by_id(pool, request.question_id)
             .await?
             .ok_or_else(|| {
                 AppError::NotFound(format!(
@@ -52,22 +53,41 @@ impl ReponseService {
             ));
         }
 
-        // Vérifier qu'il n'y a pas déjà trop de réponses (max 6 pour un QCM par exemple)
+        // Position (ordre) ou colonne/paire (association) selon le type de question
+        let spec = ReponseSpec {
+            ordre: request.ordre,
+            metadata: request.metadata.as_ref(),
+        };
+        if let Some(error) = validate_reponse(&question.type_question, 0, &spec) {
+            return Err(AppError::Validation(vec![error]));
+        }
+
+        // Vérifier qu'il n'y a pas déjà trop de réponses (max 6 pour un QCM par exemple,
+        // 12 pour une association : 6 paires = 2 colonnes)
+        let max_reponses = if question.type_question == "association" {
+            12
+        } else {
+            6
+        };
         let count = ReponseRepository::count_by_question(pool, request.question_id).await?;
-        if count >= 6 {
-            return Err(AppError::BadRequest(
-                "Nombre maximum de réponses atteint (6)".to_string(),
-            ));
+        if count >= max_reponses {
+            return Err(AppError::BadRequest(format!(
+                "Nombre maximum de réponses atteint ({})",
+                max_reponses
+            )));
         }
 
         let reponse = ReponseRepository::create(
             pool,
             request.question_id,
-            request.valeur.as_deref(),
-            request.region_id,
-            request.is_correct,
-            request.ordre,
-            request.tolerance_meters,
+            &ReponseFields {
+                valeur: request.valeur.as_deref(),
+                region_id: request.region_id,
+                is_correct: request.is_correct,
+                ordre: request.ordre,
+                tolerance_meters: request.tolerance_meters,
+                metadata: request.metadata.as_ref(),
+            },
         )
         .await?;
 
@@ -81,7 +101,7 @@ impl ReponseService {
         request: UpdateReponseRequest,
     ) -> Result<Reponse, AppError> {
         // Vérifier que la réponse existe
-        let _existing = ReponseRepository::find_by_id(pool, id)
+        let existing = ReponseRepository::find_by_id(pool, id)
             .await?
             .ok_or_else(|| AppError::NotFound(format!("Reponse with id {} not found", id)))?;
 
@@ -92,14 +112,28 @@ impl ReponseService {
             ));
         }
 
+        // `metadata` absent : la valeur existante est conservée
+        if let Some(question) = QuestionRepository::find_by_id(pool, existing.question_id).await? {
+            let spec = ReponseSpec {
+                ordre: request.ordre,
+                metadata: request.metadata.as_ref().or(Some(&existing.metadata)),
+            };
+            if let Some(error) = validate_reponse(&question.type_question, 0, &spec) {
+                return Err(AppError::Validation(vec![error]));
+            }
+        }
+
         let reponse = ReponseRepository::update(
             pool,
             id,
-            request.valeur.as_deref(),
-            request.region_id,
-            request.is_correct,
-            request.ordre,
-            request.tolerance_meters,
+            &ReponseFields {
+                valeur: request.valeur.as_deref(),
+                region_id: request.region_id,
+                is_correct: request.is_correct,
+                ordre: request.ordre,
+                tolerance_meters: request.tolerance_meters,
+                metadata: request.metadata.as_ref(),
+            },
         )
         .await?
         .ok_or_else(|| AppError::NotFound(format!("Reponse with id {} not found", id)))?;
@@ -125,17 +159,42 @@ impl ReponseService {
         question_id: Uuid,
         request: CreateBulkReponsesRequest,
     ) -> Result<Vec<Reponse>, AppError> {
+        let question = QuestionRepository::find_by_id(pool, question_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!("Question with id {} not found", question_id))
+            })?;
+
+        // Jeu complet (réponses existantes + nouvelles) pour "ordre" et "association"
+        let existing = ReponseRepository::find_by_question_id(pool, question_id).await?;
+        let specs: Vec<ReponseSpec> = request
+            .reponses
+            .iter()
+            .map(|item| ReponseSpec {
+                ordre: item.ordre,
+                metadata: item.metadata.as_ref(),
+            })
+            .chain(existing.iter().map(|r| ReponseSpec {
+                ordre: Some(r.ordre),
+                metadata: Some(&r.metadata),
+            }))
+            .collect();
+        validate_reponses(&question.type_question, &specs).map_err(AppError::Validation)?;
+
         let mut reponses = Vec::new();
 
         for item in request.reponses {
             let reponse = ReponseRepository::create(
                 pool,
                 question_id,
-                item.valeur.as_deref(),
-                item.region_id,
-                item.is_correct,
-                item.ordre,
-                item.tolerance_meters,
+                &ReponseFields {
+                    valeur: item.valeur.as_deref(),
+                    region_id: item.region_id,
+                    is_correct: item.is_correct,
+                    ordre: item.ordre,
+                    tolerance_meters: item.tolerance_meters,
+                    metadata: item.metadata.as_ref(),
+                },
             )
             .await?;
 
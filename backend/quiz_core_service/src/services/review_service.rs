@@ -0,0 +1,167 @@
+use chrono::{DateTime, Utc};
+use rand::seq::SliceRandom;
+use serde_json::json;
+use shared::AppError;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use super::question_draw::DrawnQuestion;
+use super::spaced_repetition::{CardSchedule, quality_from_answer};
+use crate::{
+    dto::review_dto::{DueCount, DueCountsResponse, StartReviewRequest},
+    models::{ReviewCard, SessionQuiz},
+    plugins::PluginManager,
+    repositories::{QuizRepository, ReponseRepository, ReviewRepository, SessionRepository},
+};
+
+/// Nombre de cartes d'une session de révision par défaut / au maximum
+const DEFAULT_REVIEW_SIZE: i64 = 20;
+const MAX_REVIEW_SIZE: i64 = 100;
+
+pub struct ReviewService;
+
+impl ReviewService {
+    /// Mettre à jour la carte d'une question après une réponse d'entraînement
+    /// (dans la transaction d'enregistrement de la réponse)
+    pub async fn record_answer(
+        conn: &mut PgConnection,
+        user_id: Uuid,
+        question_id: Uuid,
+        quality: u8,
+        reviewed_at: DateTime<Utc>,
+    ) -> Result<ReviewCard, AppError> {
+        let schedule = ReviewRepository::find(&mut *conn, user_id, question_id)
+            .await?
+            .map(|card| Self::schedule(&card))
+            .unwrap_or_default()
+            .review(quality);
+
+        let card =
+            ReviewRepository::upsert(conn, user_id, question_id, &schedule, reviewed_at).await?;
+        Ok(card)
+    }
+
+    /// Session de révision : cartes échues du domaine, tous quiz confondus
+    pub async fn start_review_session(
+        pool: &PgPool,
+        plugin_manager: &PluginManager,
+        domain: &str,
+        request: StartReviewRequest,
+    ) -> Result<SessionQuiz, AppError> {
+        let plugin_registry = plugin_manager.current();
+        if !plugin_registry.has_domain(domain) {
+            return Err(AppError::BadRequest(format!(
+                "Le domaine {} est désactivé",
+                domain
+            )));
+        }
+
+        let limit = request
+            .limit
+            .unwrap_or(DEFAULT_REVIEW_SIZE)
+            .clamp(1, MAX_REVIEW_SIZE);
+        let question_ids =
+            ReviewRepository::find_due_question_ids(pool, request.user_id, domain, limit).await?;
+        if question_ids.is_empty() {
+            return Err(AppError::BadRequest(
+                "Aucune carte à réviser dans ce domaine".to_string(),
+            ));
+        }
+
+        let mut question_set = Vec::with_capacity(question_ids.len());
+        for question_id in &question_ids {
+            let mut reponse_ids: Vec<Uuid> =
+                ReponseRepository::find_by_question_id(pool, *question_id)
+                    .await?
+                    .into_iter()
+                    .map(|r| r.id)
+                    .collect();
+            reponse_ids.shuffle(&mut rand::thread_rng());
+            question_set.push(DrawnQuestion {
+                question_id: *question_id,
+                reponse_ids,
+            });
+        }
+
+        let quiz = QuizRepository::find_or_create_review_quiz(pool, domain).await?;
+        let score_max = SessionRepository::calculate_max_score(pool, &question_ids).await?;
+
+        let session = SessionRepository::create(
+            pool,
+            request.user_id,
+            quiz.id,
+            score_max,
+            &json!({
+                "plugin_version": plugin_registry.version(),
+                "question_set": question_set,
+                "kind": "review",
+            }),
+        )
+        .await?;
+
+        Ok(session)
+    }
+
+    /// Nombre de cartes à réviser par domaine ("12 cartes à réviser")
+    pub async fn due_counts(pool: &PgPool, user_id: Uuid) -> Result<DueCountsResponse, AppError> {
+        let domains: Vec<DueCount> = ReviewRepository::count_due_by_domain(pool, user_id)
+            .await?
+            .into_iter()
+            .map(|(domain, due_count)| DueCount { domain, due_count })
+            .collect();
+
+        Ok(DueCountsResponse {
+            user_id,
+            total: domains.iter().map(|d| d.due_count).sum(),
+            domains,
+        })
+    }
+
+    /// Reconstruire les cartes d'un joueur en rejouant son historique d'entraînement
+    pub async fn rebuild_user(pool: &PgPool, user_id: Uuid) -> Result<usize, AppError> {
+        let history = ReviewRepository::find_history(pool, user_id).await?;
+
+        let mut cards: Vec<(Uuid, CardSchedule, DateTime<Utc>)> = Vec::new();
+        for entry in history {
+            let quality = quality_from_answer(
+                entry.is_correct,
+                entry.partial_score.map(|s| s as f32),
+                entry.temps_reponse_sec,
+                entry.temps_limite_sec,
+            );
+            match cards.iter_mut().find(|(id, _, _)| *id == entry.question_id) {
+                Some((_, schedule, reviewed_at)) => {
+                    *schedule = schedule.review(quality);
+                    *reviewed_at = entry.created_at;
+                }
+                None => cards.push((
+                    entry.question_id,
+                    CardSchedule::default().review(quality),
+                    entry.created_at,
+                )),
+            }
+        }
+
+        ReviewRepository::delete_for_user(pool, user_id).await?;
+        for (question_id, schedule, reviewed_at) in &cards {
+            ReviewRepository::upsert(pool, user_id, *question_id, schedule, *reviewed_at).await?;
+        }
+
+        tracing::info!(user_id = %user_id, cards = cards.len(), "Review cards rebuilt");
+        Ok(cards.len())
+    }
+
+    /// Session de révision (`metadata.kind = "review"`) : questions de plusieurs quiz
+    pub fn is_review_session(session: &SessionQuiz) -> bool {
+        session.metadata.get("kind").and_then(|k| k.as_str()) == Some("review")
+    }
+
+    fn schedule(card: &ReviewCard) -> CardSchedule {
+        CardSchedule {
+            ease_factor: card.ease_factor,
+            interval_days: card.interval_days,
+            repetitions: card.repetitions,
+            lapses: card.lapses,
+        }
+    }
+}
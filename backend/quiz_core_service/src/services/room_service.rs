@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+use shared::AppError;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::question_draw::{self, Stratify};
+use crate::{
+    plugins::PluginManager,
+    repositories::{QuestionRepository, QuizRepository},
+    rooms::{RoomInfo, RoomManager, RoomQuestion},
+};
+
+/// Mode de quiz joué en salle, en temps réel
+const COMPETITION_MODE: &str = "competition";
+
+pub struct RoomService;
+
+impl RoomService {
+    /// Ouvrir une salle de compétition : questions tirées une fois pour tous les joueurs
+    pub async fn create_room(
+        pool: &PgPool,
+        plugin_manager: &PluginManager,
+        rooms: &RoomManager,
+        quiz_id: Uuid,
+        host_id: Uuid,
+    ) -> Result<RoomInfo, AppError> {
+        let quiz = QuizRepository::find_by_id(pool, quiz_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound(format!("Quiz with id {} not found", quiz_id)))?;
+
+        if !quiz.is_active {
+            return Err(AppError::BadRequest("Ce quiz n'est plus actif".to_string()));
+        }
+        if quiz.mode != COMPETITION_MODE {
+            return Err(AppError::BadRequest(format!(
+                "Salles réservées aux quiz en mode '{}'",
+                COMPETITION_MODE
+            )));
+        }
+
+        // Plugin figé à l'ouverture (un rechargement n'affecte pas la partie)
+        let plugin = plugin_manager
+            .current()
+            .get(&quiz.domain)
+            .cloned()
+            .ok_or_else(|| {
+                AppError::BadRequest(format!("Le domaine {} est désactivé", quiz.domain))
+            })?;
+
+        let questions = QuestionRepository::find_by_quiz_id_with_reponses(pool, quiz_id).await?;
+        let total = (quiz.nb_questions.max(0) as usize).min(questions.len());
+        if total == 0 {
+            return Err(AppError::BadRequest(
+                "Ce quiz ne contient aucune question".to_string(),
+            ));
+        }
+
+        let drawn = question_draw::draw_questions(
+            &mut rand::thread_rng(),
+            &questions,
+            total,
+            Stratify::from_quiz_metadata(&quiz.metadata),
+        );
+        let mut by_id: HashMap<Uuid, _> = questions
+            .into_iter()
+            .map(|(question, reponses)| (question.id, (question, reponses)))
+            .collect();
+        let room_questions = drawn
+            .iter()
+            .filter_map(|drawn| {
+                let (question, reponses) = by_id.remove(&drawn.question_id)?;
+                Some(RoomQuestion::new(
+                    question,
+                    reponses,
+                    Some(&drawn.reponse_ids),
+                ))
+            })
+            .collect();
+
+        Ok(rooms.open(quiz, host_id, room_questions, plugin))
+    }
+}
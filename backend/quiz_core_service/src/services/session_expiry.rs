@@ -0,0 +1,29 @@
+use std::time::Duration;
+
+use sqlx::PgPool;
+use tokio::task::JoinHandle;
+
+use super::SessionService;
+use crate::events::EventBus;
+
+/// Tâche de fond : abandonne périodiquement les sessions expirées
+pub fn spawn(
+    pool: PgPool,
+    events: EventBus,
+    check_interval: Duration,
+    inactivity_timeout: Duration,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(check_interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            match SessionService::expire_sessions(&pool, &events, inactivity_timeout).await {
+                Ok(0) => {}
+                Ok(count) => tracing::info!(count, "Expired sessions abandoned"),
+                Err(e) => tracing::error!(error = ?e, "Session expiry failed"),
+            }
+        }
+    })
+}
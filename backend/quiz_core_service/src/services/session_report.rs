@@ -0,0 +1,263 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::models::{Question, Reponse, ReponseUtilisateur};
+use crate::plugins::QuizPlugin;
+
+/// Catégorie des questions sans `category`
+const UNCATEGORIZED: &str = "autre";
+
+/// Réponse donnée par le joueur
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserAnswer {
+    pub reponse_id: Option<Uuid>,
+    pub reponse_ids: Vec<Uuid>,
+    pub valeur_saisie: Option<String>,
+}
+
+/// Ligne du rapport de session (stockée dans `sessions_quiz.reponses_detaillees`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestionReport {
+    pub question_id: Uuid,
+    pub position: usize,
+    pub category: Option<String>,
+    pub subcategory: Option<String>,
+    pub type_question: String,
+    pub question_text: Option<String>,
+    pub answered: bool,
+    pub user_answer: Option<UserAnswer>,
+    pub correct_answers: serde_json::Value,
+    pub is_correct: bool,
+    pub points_obtenus: i32,
+    pub points_max: i32,
+    pub temps_reponse_sec: Option<i32>,
+    pub speed_badge: Option<String>,
+    pub explanation: Option<String>,
+    /// Bonnes réponses consécutives après cette question
+    pub streak: i32,
+}
+
+/// Taux de réussite d'une catégorie ("fleuves 3/3")
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CategoryAccuracy {
+    pub category: String,
+    pub correct: usize,
+    pub total: usize,
+    pub accuracy: f64,
+}
+
+/// Construire le rapport, dans l'ordre du tirage (`questions`)
+/// Les questions sans réponse y figurent comme fausses
+pub fn build_report(
+    plugin: &dyn QuizPlugin,
+    questions: &[(Question, Vec<Reponse>)],
+    answers: &[ReponseUtilisateur],
+) -> Vec<QuestionReport> {
+    let streaks = running_streaks(answers);
+
+    questions
+        .iter()
+        .enumerate()
+        .map(|(index, (question, reponses))| {
+            let answer = answers.iter().find(|a| a.question_id == question.id);
+            QuestionReport {
+                question_id: question.id,
+                position: index + 1,
+                category: question.category.clone(),
+                subcategory: question.subcategory.clone(),
+                type_question: question.type_question.clone(),
+                question_text: question
+                    .question_data
+                    .get("text")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string),
+                answered: answer.is_some(),
+                user_answer: answer.map(|a| UserAnswer {
+                    reponse_id: a.reponse_id,
+                    reponse_ids: a.reponse_ids.clone(),
+                    valeur_saisie: a.valeur_saisie.clone(),
+                }),
+                correct_answers: correct_answers(question, reponses),
+                is_correct: answer.is_some_and(|a| a.is_correct),
+                points_obtenus: answer.map(|a| a.points_obtenus).unwrap_or(0),
+                points_max: question.points,
+                temps_reponse_sec: answer.map(|a| a.temps_reponse_sec),
+                speed_badge: answer.filter(|a| a.is_correct).and_then(|a| {
+                    plugin.speed_badge(a.temps_reponse_sec, question.temps_limite_sec)
+                }),
+                explanation: answer
+                    .and_then(|a| a.metadata.get("explanation"))
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string)
+                    .or_else(|| question.explanation.clone()),
+                streak: streaks.get(&question.id).copied().unwrap_or(0),
+            }
+        })
+        .collect()
+}
+
+/// Streak après chaque réponse, dans l'ordre chronologique des réponses
+fn running_streaks(answers: &[ReponseUtilisateur]) -> BTreeMap<Uuid, i32> {
+    let mut chronological: Vec<&ReponseUtilisateur> = answers.iter().collect();
+    chronological.sort_by_key(|a| a.created_at);
+
+    let mut streak = 0;
+    chronological
+        .into_iter()
+        .map(|answer| {
+            streak = if answer.is_correct { streak + 1 } else { 0 };
+            (answer.question_id, streak)
+        })
+        .collect()
+}
+
+/// Solution d'une question, selon son type
+pub fn correct_answers(question: &Question, reponses: &[Reponse]) -> serde_json::Value {
+    let choix = |r: &Reponse| json!({ "id": r.id, "valeur": r.valeur });
+
+    match question.type_question.as_str() {
+        "numerique" => json!({
+            "expected_value": question.question_data.get("expected_value"),
+            "unit": question.question_data.get("unit"),
+        }),
+        // L'ordre attendu est celui de `reponses.ordre`
+        "ordre" => {
+            let mut sorted: Vec<&Reponse> = reponses.iter().collect();
+            sorted.sort_by_key(|r| r.ordre);
+            json!(sorted.into_iter().map(choix).collect::<Vec<_>>())
+        }
+        // Paires : éléments gauche/droite partageant la même clé `paire`
+        "association" => {
+            let mut paires: BTreeMap<String, (Option<&Reponse>, Option<&Reponse>)> =
+                BTreeMap::new();
+            for reponse in reponses {
+                let Some(key) = reponse.metadata.get("paire").map(|p| match p {
+                    serde_json::Value::String(key) => key.clone(),
+                    other => other.to_string(),
+                }) else {
+                    continue;
+                };
+                let entry = paires.entry(key).or_default();
+                match reponse.metadata.get("colonne").and_then(|c| c.as_str()) {
+                    Some("gauche") => entry.0 = Some(reponse),
+                    Some("droite") => entry.1 = Some(reponse),
+                    _ => {}
+                }
+            }
+            json!(
+                paires
+                    .into_values()
+                    .filter_map(|(gauche, droite)| Some(json!({
+                        "gauche": choix(gauche?),
+                        "droite": choix(droite?),
+                    })))
+                    .collect::<Vec<_>>()
+            )
+        }
+        _ => json!(
+            reponses
+                .iter()
+                .filter(|r| r.is_correct)
+                .map(choix)
+                .collect::<Vec<_>>()
+        ),
+    }
+}
+
+/// Réussite par catégorie (ordre alphabétique)
+pub fn category_accuracy(report: &[QuestionReport]) -> Vec<CategoryAccuracy> {
+    let mut counts: BTreeMap<&str, (usize, usize)> = BTreeMap::new();
+    for entry in report {
+        let category = entry.category.as_deref().unwrap_or(UNCATEGORIZED);
+        let (correct, total) = counts.entry(category).or_default();
+        *total += 1;
+        if entry.is_correct {
+            *correct += 1;
+        }
+    }
+
+    counts
+        .into_iter()
+        .map(|(category, (correct, total))| CategoryAccuracy {
+            category: category.to_string(),
+            correct,
+            total,
+            accuracy: correct as f64 / total as f64,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::fixtures::{self, reponse};
+    use crate::plugins::GeographyPlugin;
+    use chrono::{Duration, Utc};
+
+    fn question(category: &str, type_question: &str) -> Question {
+        Question {
+            question_data: json!({"text": "?", "expected_value": 4808, "unit": "m"}),
+            explanation: Some("Parce que.".to_string()),
+            ..fixtures::question(category, type_question)
+        }
+    }
+
+    fn answer(question_id: Uuid, is_correct: bool, seconds_ago: i64) -> ReponseUtilisateur {
+        ReponseUtilisateur {
+            question_id,
+            created_at: Utc::now() - Duration::seconds(seconds_ago),
+            ..fixtures::answer(is_correct, json!({}))
+        }
+    }
+
+    #[test]
+    fn test_build_report_and_categories() {
+        let fleuve_1 = question("fleuves", "qcm");
+        let fleuve_2 = question("fleuves", "qcm");
+        let relief = question("reliefs", "numerique");
+        let reponses = vec![reponse(fleuve_1.id, "Loire", true, 1)];
+        let questions = vec![
+            (fleuve_1.clone(), reponses),
+            (fleuve_2.clone(), vec![]),
+            (relief.clone(), vec![]),
+        ];
+        // Ordre chronologique : fleuve_2 (faux), puis fleuve_1 et relief (justes)
+        let answers = vec![
+            answer(fleuve_1.id, true, 20),
+            answer(fleuve_2.id, false, 30),
+            answer(relief.id, true, 10),
+        ];
+
+        let report = build_report(&GeographyPlugin, &questions, &answers);
+        assert_eq!(report.len(), 3);
+        assert_eq!(report[0].position, 1);
+        assert_eq!(report[0].correct_answers[0]["valeur"], "Loire");
+        assert_eq!(
+            report[0].speed_badge.as_deref(),
+            Some("🌍 Expert géographe !")
+        );
+        assert_eq!(report[1].speed_badge, None);
+        assert_eq!(report[2].correct_answers["expected_value"], 4808);
+        assert_eq!(
+            report.iter().map(|r| r.streak).collect::<Vec<_>>(),
+            vec![1, 0, 2]
+        );
+
+        let categories = category_accuracy(&report);
+        assert_eq!(categories[0].category, "fleuves");
+        assert_eq!((categories[0].correct, categories[0].total), (1, 2));
+        assert_eq!((categories[1].correct, categories[1].total), (1, 1));
+    }
+
+    #[test]
+    fn test_unanswered_question_counts_as_wrong() {
+        let q = question("fleuves", "qcm");
+        let report = build_report(&GeographyPlugin, &[(q, vec![])], &[]);
+        assert!(!report[0].answered && !report[0].is_correct);
+        assert_eq!(report[0].explanation.as_deref(), Some("Parce que."));
+        assert_eq!(category_accuracy(&report)[0].accuracy, 0.0);
+    }
+}
@@ -1,20 +1,59 @@
+use super::achievement_service::AchievementService;
+use super::answer_timing::{AnswerTiming, TIME_LIMIT_GRACE_SEC};
+use super::certificate::{CertificatePayload, CertificateSigner, ResultCertificate};
+use super::daily_challenge_service::DailyChallengeService;
+use super::elo;
+use super::exam;
+use super::leaderboard_service::LeaderboardService;
+use super::progress_service::ProgressService;
+use super::question_draw::{self, DrawnQuestion, Stratify};
+use super::question_service::QuestionService;
+use super::review_service::ReviewService;
+use super::session_report::{self, QuestionReport};
+use super::spaced_repetition;
 use crate::{
-    dto::{StartSessionRequest, SubmitAnswerRequest},
-    models::{ReponseUtilisateur, SessionQuiz},
-    plugins::PluginRegistry, // ✅ AJOUTER
-    repositories::{QuestionRepository, QuizRepository, ReponseRepository, SessionRepository},
+    dto::{
+        AnsweredQuestion, CertificateVerification, HintResponse, NextQuestionResponse,
+        QuestionPresentedResponse, RecordedAnswer, SessionReportResponse, SessionResumeResponse,
+        StartSessionRequest, SubmitAnswerRequest, SubmitAnswerResponse,
+    },
+    events::{AbandonReason, EventBus, SessionEvent},
+    models::{Question, ReponseUtilisateur, SessionQuiz},
+    plugins::PluginManager, // ✅ AJOUTER
+    plugins::{QuizPlugin, ValidationResult},
+    repositories::{
+        QuestionRepository, QuizRepository, ReponseRepository, SessionRepository, SkillRepository,
+        session_repo::NewUserAnswer,
+    },
 };
+use chrono::{DateTime, Utc};
+use rand::seq::SliceRandom;
+use serde_json::json;
 use shared::AppError;
 use sqlx::PgPool;
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::time::Duration;
 use uuid::Uuid;
 
+/// Statuts possibles d'une session (`sessions_quiz.status`)
+const SESSION_STATUSES: [&str; 3] = ["en_cours", "termine", "abandonne"];
+
+/// Mode sans correction avant la fin, avec verdict et certificat
+const EXAM_MODE: &str = "examen";
+
+/// Mode de quiz dont les questions s'adaptent au niveau du joueur
+const ADAPTIVE_MODE: &str = "entrainement";
+
+/// Seul mode où les indices peuvent être consultés
+const HINT_MODE: &str = "decouverte";
+
 pub struct SessionService;
 
 impl SessionService {
-    // ✅ INCHANGÉ
+    // ✅ MODIFIÉ : La session retient la version du registry de plugins
     pub async fn start_session(
         pool: &PgPool,
+        plugin_manager: &PluginManager,
         quiz_id: Uuid,
         request: StartSessionRequest,
     ) -> Result<SessionQuiz, AppError> {
@@ -27,29 +66,532 @@ impl SessionService {
             return Err(AppError::BadRequest("Ce quiz n'est plus actif".to_string()));
         }
 
-        // Calculer le score maximum
-        let score_max = SessionRepository::calculate_max_score(pool, quiz_id).await?;
+        // Le domaine doit être actif (plugin chargé)
+        let plugin_registry = plugin_manager.current();
+        if !plugin_registry.has_domain(&quiz.domain) {
+            return Err(AppError::BadRequest(format!(
+                "Le domaine {} est désactivé",
+                quiz.domain
+            )));
+        }
+
+        // Tirer les questions de la session (ordre des réponses compris)
+        let questions = QuestionRepository::find_by_quiz_id_with_reponses(pool, quiz_id).await?;
+        let total = (quiz.nb_questions.max(0) as usize).min(questions.len());
+
+        // Entraînement : questions choisies une à une selon le niveau du joueur
+        if quiz.mode == ADAPTIVE_MODE {
+            let session = SessionRepository::create(
+                pool,
+                request.user_id,
+                quiz_id,
+                0,
+                &json!({
+                    "plugin_version": plugin_registry.version(),
+                    "question_set": [],
+                    "adaptive": { "total": total },
+                }),
+            )
+            .await?;
+            return Ok(session);
+        }
+
+        let question_set = question_draw::draw_questions(
+            &mut rand::thread_rng(),
+            &questions,
+            total,
+            Stratify::from_quiz_metadata(&quiz.metadata),
+        );
+        let question_ids: Vec<Uuid> = question_set.iter().map(|q| q.question_id).collect();
+
+        // Calculer le score maximum (questions tirées uniquement)
+        let score_max = SessionRepository::calculate_max_score(pool, &question_ids).await?;
 
         // Créer la session
-        let session = SessionRepository::create(pool, request.user_id, quiz_id, score_max).await?;
+        let session = SessionRepository::create(
+            pool,
+            request.user_id,
+            quiz_id,
+            score_max,
+            &json!({
+                "plugin_version": plugin_registry.version(),
+                "question_set": question_set,
+            }),
+        )
+        .await?;
 
         Ok(session)
     }
 
     // ✅ INCHANGÉ
     pub async fn get_session(pool: &PgPool, session_id: Uuid) -> Result<SessionQuiz, AppError> {
-        SessionRepository::find_by_id(pool, session_id)
+        let session = SessionRepository::find_by_id(pool, session_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::NotFound(format!("Session with id {} not found", session_id))
+            })?;
+
+        // Examen en cours : le score trahirait les bonnes réponses
+        if session.status == "en_cours" {
+            let quiz = QuizRepository::find_by_id(pool, session.quiz_id).await?;
+            if let Some(quiz) = quiz {
+                return Ok(Self::mask_exam_progress(session, &quiz.mode));
+            }
+        }
+
+        Ok(session)
+    }
+
+    /// Succès évalués dès la réponse ?
+    /// En examen, un déblocage trahirait la correction : tout est évalué à la finalisation
+    fn unlocks_on_answer(quiz_mode: &str) -> bool {
+        quiz_mode != EXAM_MODE
+    }
+
+    /// Masquer la progression d'un examen en cours (score, pourcentage)
+    fn mask_exam_progress(mut session: SessionQuiz, quiz_mode: &str) -> SessionQuiz {
+        if quiz_mode == EXAM_MODE && session.status == "en_cours" {
+            session.score = 0;
+            session.pourcentage = None;
+        }
+        session
+    }
+
+    /// Sessions d'un joueur, filtrées par statut
+    pub async fn list_user_sessions(
+        pool: &PgPool,
+        user_id: Uuid,
+        status: Option<&str>,
+    ) -> Result<Vec<SessionQuiz>, AppError> {
+        if let Some(status) = status
+            && !SESSION_STATUSES.contains(&status)
+        {
+            return Err(AppError::BadRequest(format!(
+                "Statut inconnu: {} (attendu: {})",
+                status,
+                SESSION_STATUSES.join(", ")
+            )));
+        }
+
+        let sessions = SessionRepository::find_by_user(pool, user_id, status).await?;
+
+        // Examens en cours : progression masquée, comme pour une session seule
+        let mut modes: HashMap<Uuid, String> = HashMap::new();
+        let mut listed = Vec::with_capacity(sessions.len());
+        for session in sessions {
+            if session.status != "en_cours" {
+                listed.push(session);
+                continue;
+            }
+            let mode = match modes.get(&session.quiz_id) {
+                Some(mode) => mode.clone(),
+                None => {
+                    let mode = QuizRepository::find_by_id(pool, session.quiz_id)
+                        .await?
+                        .map(|q| q.mode)
+                        .unwrap_or_default();
+                    modes.insert(session.quiz_id, mode.clone());
+                    mode
+                }
+            };
+            listed.push(Self::mask_exam_progress(session, &mode));
+        }
+
+        Ok(listed)
+    }
+
+    /// Reprendre une session en cours : réponses données, questions restantes, chrono
+    pub async fn resume_session(
+        pool: &PgPool,
+        user_id: Uuid,
+        session_id: Uuid,
+    ) -> Result<SessionResumeResponse, AppError> {
+        // Session d'un autre joueur : même réponse qu'une session inexistante
+        let session = SessionRepository::find_by_id(pool, session_id)
+            .await?
+            .filter(|s| s.user_id == user_id)
+            .ok_or_else(|| {
+                AppError::NotFound(format!("Session with id {} not found", session_id))
+            })?;
+
+        if session.status != "en_cours" {
+            return Err(AppError::BadRequest(
+                "Session not found or already completed".to_string(),
+            ));
+        }
+
+        let quiz = QuizRepository::find_by_id(pool, session.quiz_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Quiz not found".to_string()))?;
+
+        let reponses = SessionRepository::find_reponses_by_session(pool, session_id).await?;
+        let streak = Self::calculate_streak(pool, session_id).await?;
+        let question_set = Self::question_set(&session).unwrap_or_default();
+
+        let mut remaining = Vec::new();
+        for drawn in question_set
+            .iter()
+            .filter(|drawn| !reponses.iter().any(|r| r.question_id == drawn.question_id))
+        {
+            let Some(question) = QuestionRepository::find_by_id(pool, drawn.question_id).await?
+            else {
+                continue;
+            };
+            let choix = ReponseRepository::find_by_question_id(pool, drawn.question_id).await?;
+            remaining.push(QuestionService::for_mode(
+                QuestionService::to_client_dto(question, choix, Some(&drawn.reponse_ids)),
+                &quiz.mode,
+            ));
+        }
+
+        let current_presented_at = remaining
+            .first()
+            .and_then(|question| Self::presented_at(&session, question.id));
+        let remaining_time_sec = quiz.temps_limite_sec.map(|limit| {
+            let elapsed = (Utc::now() - session.date_debut).num_seconds();
+            (i64::from(limit) - elapsed).max(0)
+        });
+
+        // Examen : réponses rendues sans correction, score masqué
+        let reveal = quiz.mode != EXAM_MODE;
+        let metadata_text = |r: &ReponseUtilisateur, key: &str| {
+            r.metadata
+                .get(key)
+                .and_then(|v| v.as_str())
+                .map(str::to_string)
+                .filter(|_| reveal)
+        };
+
+        let answered = reponses
+            .into_iter()
+            .map(|r| AnsweredQuestion {
+                question_id: r.question_id,
+                reponse_id: r.reponse_id,
+                feedback_message: metadata_text(&r, "feedback_message"),
+                explanation: metadata_text(&r, "explanation"),
+                reponse_ids: r.reponse_ids,
+                valeur_saisie: r.valeur_saisie,
+                is_correct: reveal.then_some(r.is_correct),
+                points_obtenus: reveal.then_some(r.points_obtenus),
+                temps_reponse_sec: r.temps_reponse_sec,
+            })
+            .collect();
+
+        let session = Self::mask_exam_progress(session, &quiz.mode);
+
+        Ok(SessionResumeResponse {
+            total: Self::adaptive_total(&session).unwrap_or(question_set.len()),
+            score: reveal.then_some(session.score),
+            streak: reveal.then_some(streak),
+            answered,
+            remaining,
+            current_presented_at,
+            remaining_time_sec,
+            session,
+        })
+    }
+
+    /// Présenter une question : démarre le chrono côté serveur
+    pub async fn present_question(
+        pool: &PgPool,
+        session_id: Uuid,
+        question_id: Uuid,
+    ) -> Result<QuestionPresentedResponse, AppError> {
+        let session = SessionRepository::find_active_by_id(pool, session_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::BadRequest("Session not found or already completed".to_string())
+            })?;
+
+        let question = QuestionRepository::find_by_id(pool, question_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Question not found".to_string()))?;
+
+        if !Self::belongs_to_quiz(&session, &question) {
+            return Err(AppError::BadRequest(
+                "Question does not belong to this quiz".to_string(),
+            ));
+        }
+
+        let session = SessionRepository::mark_question_presented(pool, session_id, question_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::BadRequest("Session not found or already completed".to_string())
+            })?;
+
+        let presented_at = Self::presented_at(&session, question_id).ok_or_else(|| {
+            AppError::InternalServerError("Heure de présentation non enregistrée".to_string())
+        })?;
+
+        Ok(QuestionPresentedResponse {
+            question_id,
+            presented_at,
+            temps_limite_sec: question.temps_limite_sec,
+        })
+    }
+
+    /// Consulter l'indice d'une question : la consultation est retenue sur la session
+    /// et pénalise le score de la réponse (pénalité propre à chaque plugin)
+    pub async fn use_hint(
+        pool: &PgPool,
+        session_id: Uuid,
+        question_id: Uuid,
+    ) -> Result<HintResponse, AppError> {
+        let session = SessionRepository::find_active_by_id(pool, session_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::BadRequest("Session not found or already completed".to_string())
+            })?;
+
+        let question = QuestionRepository::find_by_id(pool, question_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Question not found".to_string()))?;
+
+        if !Self::belongs_to_quiz(&session, &question) {
+            return Err(AppError::BadRequest(
+                "Question does not belong to this quiz".to_string(),
+            ));
+        }
+
+        let quiz = QuizRepository::find_by_id(pool, session.quiz_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Quiz not found".to_string()))?;
+
+        if quiz.mode != HINT_MODE {
+            return Err(AppError::BadRequest(format!(
+                "Indices indisponibles en mode '{}'",
+                quiz.mode
+            )));
+        }
+
+        let hint = question
+            .hint
+            .ok_or_else(|| AppError::NotFound("Pas d'indice pour cette question".to_string()))?;
+
+        let answers = SessionRepository::find_reponses_by_session(pool, session_id).await?;
+        if answers.iter().any(|a| a.question_id == question_id) {
+            return Err(AppError::BadRequest("Question déjà répondue".to_string()));
+        }
+
+        let session = SessionRepository::mark_hint_used(pool, session_id, question_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::BadRequest("Session not found or already completed".to_string())
+            })?;
+
+        let used_at = Self::hint_used_at(&session, question_id).ok_or_else(|| {
+            AppError::InternalServerError("Consultation de l'indice non enregistrée".to_string())
+        })?;
+
+        Ok(HintResponse {
+            question_id,
+            hint,
+            used_at,
+        })
+    }
+
+    /// Question suivante du tirage (présentée à l'appel : démarre le chrono)
+    pub async fn next_question(
+        pool: &PgPool,
+        session_id: Uuid,
+    ) -> Result<NextQuestionResponse, AppError> {
+        let session = SessionRepository::find_active_by_id(pool, session_id)
+            .await?
+            .ok_or_else(|| {
+                AppError::BadRequest("Session not found or already completed".to_string())
+            })?;
+
+        let question_set = Self::question_set(&session).ok_or_else(|| {
+            AppError::BadRequest("Aucun tirage de questions pour cette session".to_string())
+        })?;
+
+        let answered: Vec<Uuid> = SessionRepository::find_reponses_by_session(pool, session_id)
+            .await?
+            .into_iter()
+            .map(|r| r.question_id)
+            .collect();
+
+        let next = match question_set
+            .iter()
+            .find(|drawn| !answered.contains(&drawn.question_id))
+        {
+            Some(drawn) => Some(drawn.clone()),
+            None => Self::draw_adaptive(pool, &session, &question_set).await?,
+        };
+        let total = Self::adaptive_total(&session).unwrap_or(question_set.len());
+
+        let Some(drawn) = next else {
+            return Ok(NextQuestionResponse {
+                answered: answered.len(),
+                total,
+                question: None,
+                presented_at: None,
+            });
+        };
+
+        let question = QuestionRepository::find_by_id(pool, drawn.question_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Question not found".to_string()))?;
+        let reponses = ReponseRepository::find_by_question_id(pool, drawn.question_id).await?;
+        let quiz = QuizRepository::find_by_id(pool, session.quiz_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Quiz not found".to_string()))?;
+
+        let session =
+            SessionRepository::mark_question_presented(pool, session_id, drawn.question_id)
+                .await?
+                .ok_or_else(|| {
+                    AppError::BadRequest("Session not found or already completed".to_string())
+                })?;
+
+        Ok(NextQuestionResponse {
+            answered: answered.len(),
+            total,
+            presented_at: Self::presented_at(&session, drawn.question_id),
+            question: Some(QuestionService::for_mode(
+                QuestionService::to_client_dto(question, reponses, Some(&drawn.reponse_ids)),
+                &quiz.mode,
+            )),
+        })
+    }
+
+    /// Mode adaptatif : choisir la question suivante selon le niveau du joueur
+    /// (réussite attendue proche de `elo::TARGET_SUCCESS`) et l'ajouter au tirage
+    async fn draw_adaptive(
+        pool: &PgPool,
+        session: &SessionQuiz,
+        question_set: &[DrawnQuestion],
+    ) -> Result<Option<DrawnQuestion>, AppError> {
+        let Some(total) = Self::adaptive_total(session) else {
+            return Ok(None);
+        };
+        if question_set.len() >= total {
+            return Ok(None);
+        }
+
+        let quiz = QuizRepository::find_by_id(pool, session.quiz_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Quiz not found".to_string()))?;
+        let skill = SkillRepository::find(pool, session.user_id, &quiz.domain)
             .await?
-            .ok_or_else(|| AppError::NotFound(format!("Session with id {} not found", session_id)))
+            .map(|s| s.rating)
+            .unwrap_or(elo::INITIAL_RATING);
+
+        let mut questions =
+            QuestionRepository::find_by_quiz_id_with_reponses(pool, session.quiz_id).await?;
+        questions.retain(|(q, _)| !question_set.iter().any(|d| d.question_id == q.id));
+        let candidates: Vec<(Uuid, f64)> = questions
+            .iter()
+            .map(|(q, _)| (q.id, q.difficulty_rating))
+            .collect();
+
+        // ThreadRng n'est pas Send : tirage terminé avant tout await
+        let picked = {
+            let mut rng = rand::thread_rng();
+            elo::pick_next(&mut rng, skill, &candidates).and_then(|question_id| {
+                let (question, reponses) = questions.iter().find(|(q, _)| q.id == question_id)?;
+                let mut reponse_ids: Vec<Uuid> = reponses.iter().map(|r| r.id).collect();
+                reponse_ids.shuffle(&mut rng);
+                Some((
+                    question,
+                    DrawnQuestion {
+                        question_id,
+                        reponse_ids,
+                    },
+                ))
+            })
+        };
+        let Some((question, drawn)) = picked else {
+            return Ok(None);
+        };
+
+        tracing::debug!(
+            session_id = %session.id,
+            question_id = %drawn.question_id,
+            skill,
+            difficulty = question.difficulty_rating,
+            "Adaptive question drawn"
+        );
+
+        SessionRepository::append_drawn_question(
+            pool,
+            session.id,
+            &json!(drawn),
+            question.points,
+            question_set.len(),
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::BadRequest("Question suivante déjà en cours de tirage".to_string())
+        })?;
+
+        Ok(Some(drawn))
     }
 
-    // ✅ MODIFIÉ : Ajouter plugin_registry
+    /// Nombre de questions d'une session adaptative (`metadata.adaptive.total`)
+    fn adaptive_total(session: &SessionQuiz) -> Option<usize> {
+        session
+            .metadata
+            .get("adaptive")?
+            .get("total")?
+            .as_u64()
+            .map(|total| total as usize)
+    }
+
+    /// Nombre de questions de la session : tirage (ou total prévu en mode adaptatif)
+    fn drawn_count(session: &SessionQuiz) -> Option<usize> {
+        Self::adaptive_total(session).or_else(|| {
+            Self::question_set(session)
+                .map(|set| set.len())
+                .filter(|len| *len > 0)
+        })
+    }
+
+    /// La question appartient au quiz de la session
+    /// Révision et défi du jour : questions de plusieurs quiz, seul le tirage fait foi
+    fn belongs_to_quiz(session: &SessionQuiz, question: &Question) -> bool {
+        if ReviewService::is_review_session(session)
+            || DailyChallengeService::is_daily_challenge_session(session)
+        {
+            return Self::question_set(session)
+                .is_some_and(|set| set.iter().any(|q| q.question_id == question.id));
+        }
+        question.quiz_id == session.quiz_id
+    }
+
+    /// Questions tirées au démarrage de la session (`metadata.question_set`)
+    fn question_set(session: &SessionQuiz) -> Option<Vec<DrawnQuestion>> {
+        serde_json::from_value(session.metadata.get("question_set")?.clone()).ok()
+    }
+
+    /// Heure de présentation d'une question (`metadata.presented_at`)
+    fn presented_at(session: &SessionQuiz, question_id: Uuid) -> Option<DateTime<Utc>> {
+        session
+            .metadata
+            .get("presented_at")?
+            .get(question_id.to_string())
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+    }
+
+    /// Heure de consultation de l'indice d'une question (`metadata.hints_used`)
+    fn hint_used_at(session: &SessionQuiz, question_id: Uuid) -> Option<DateTime<Utc>> {
+        session
+            .metadata
+            .get("hints_used")?
+            .get(question_id.to_string())
+            .and_then(|v| serde_json::from_value(v.clone()).ok())
+    }
+
+    // ✅ MODIFIÉ : Ajouter plugin_manager
     pub async fn submit_answer(
         pool: &PgPool,
-        plugin_registry: &PluginRegistry, // ✅ NOUVEAU PARAMÈTRE
+        plugin_manager: &PluginManager, // ✅ NOUVEAU PARAMÈTRE
+        events: &EventBus,
         session_id: Uuid,
-        request: SubmitAnswerRequest,
-    ) -> Result<ReponseUtilisateur, AppError> {
+        mut request: SubmitAnswerRequest,
+    ) -> Result<SubmitAnswerResponse, AppError> {
+        let answered_at = Utc::now();
+
         // Vérifier que la session existe et est en cours
         let session = SessionRepository::find_active_by_id(pool, session_id)
             .await?
@@ -63,23 +605,59 @@ impl SessionService {
             .ok_or_else(|| AppError::NotFound("Question not found".to_string()))?;
 
         // Vérifier que la question appartient au quiz de la session
-        if question.quiz_id != session.quiz_id {
+        if !Self::belongs_to_quiz(&session, &question) {
             return Err(AppError::BadRequest(
                 "Question does not belong to this quiz".to_string(),
             ));
         }
 
+        // ... et au tirage de la session
+        if let Some(question_set) = Self::question_set(&session)
+            && !question_set.iter().any(|q| q.question_id == question.id)
+        {
+            return Err(AppError::BadRequest(
+                "Question does not belong to this session".to_string(),
+            ));
+        }
+
         // ✅ NOUVEAU : Récupérer le quiz pour le domaine
         let quiz = QuizRepository::find_by_id(pool, session.quiz_id)
             .await?
             .ok_or_else(|| AppError::NotFound("Quiz not found".to_string()))?;
 
-        // ✅ NOUVEAU : Utiliser le plugin pour valider
+        // Temps du quiz écoulé : la session est abandonnée, la réponse refusée
+        if let Some(limit) = quiz.temps_limite_sec
+            && answered_at
+                > session.date_debut
+                    + chrono::Duration::seconds(i64::from(limit) + TIME_LIMIT_GRACE_SEC)
+        {
+            Self::abandon_session(pool, events, session_id, AbandonReason::TimeLimit).await?;
+            return Err(AppError::BadRequest(
+                "Temps du quiz écoulé - session abandonnée".to_string(),
+            ));
+        }
+
+        // ✅ NOUVEAU : Utiliser le plugin pour valider (version du démarrage de la session)
+        let plugin_registry = plugin_manager.for_session(&session);
         let plugin = plugin_registry.get(&quiz.domain).ok_or_else(|| {
             AppError::NotFound(format!("No plugin found for domain: {}", quiz.domain))
         })?;
 
-        let validation = plugin.validate_answer(pool, &question, &request).await?;
+        // Temps mesuré par le serveur : le temps déclaré par le client est ignoré
+        let presented_at = Self::presented_at(&session, question.id).ok_or_else(|| {
+            AppError::BadRequest("La question n'a pas été présentée dans cette session".to_string())
+        })?;
+        let timing = AnswerTiming::compute(presented_at, answered_at, question.temps_limite_sec);
+        let client_temps_reponse_sec = request.temps_reponse_sec;
+        request.temps_reponse_sec = timing.elapsed_sec;
+
+        // Hors délai : compté comme un timeout, quelle que soit la réponse
+        let validation = if timing.timed_out {
+            ValidationResult::incorrect("Temps écoulé - Réponse hors délai")
+                .with_explanation(question.explanation.clone().unwrap_or_default())
+        } else {
+            plugin.validate_answer(pool, &question, &request).await?
+        };
 
         tracing::debug!(
             question_id = %request.question_id,
@@ -90,53 +668,391 @@ impl SessionService {
         // ✅ NOUVEAU : Calculer le streak
         let streak_count = Self::calculate_streak(pool, session_id).await?;
 
+        // 🆕 Indice consulté avant la réponse : pénalité appliquée par le plugin
+        let hint_used = Self::hint_used_at(&session, question.id).is_some();
+
         // ✅ NOUVEAU : Calculer le score avec le plugin
-        let points_obtenus = if validation.is_correct {
-            plugin.calculate_score(
+        // Les réponses partielles (carte, ordre...) rapportent aussi des points
+        let points_obtenus = plugin
+            .calculate_score(
                 question.points,
                 &validation,
                 request.temps_reponse_sec,
                 question.temps_limite_sec,
                 streak_count,
+                hint_used,
             )
-        } else {
-            0
-        };
+            .await;
 
         tracing::debug!(
             base_points = question.points,
             final_points = points_obtenus,
             streak = streak_count,
+            hint_used,
             "Score calculated"
         );
 
+        // Feedback et explication conservés avec la réponse
+        let mut metadata = validation.metadata.clone();
+        metadata["feedback_message"] = json!(validation.feedback_message);
+        if let Some(explanation) = &validation.explanation {
+            metadata["explanation"] = json!(explanation);
+        }
+        if let Some(partial_score) = validation.partial_score {
+            metadata["partial_score"] = json!(partial_score);
+        }
+        metadata["timing"] = timing.to_metadata(client_temps_reponse_sec);
+        if hint_used {
+            metadata["hint_used"] = json!(true);
+        }
+
+        // Toutes les réponses sélectionnées (choix unique → une seule)
+        let reponse_ids: Vec<Uuid> = match &request.reponse_ids {
+            Some(ids) => ids.clone(),
+            None => request.reponse_id.into_iter().collect(),
+        };
+
+        // Réponse, score, succès, statistiques Elo et carte de révision : une seule
+        // transaction (un échec n'enregistre rien, la réponse peut être renvoyée)
+        let mut tx = pool.begin().await?;
+
         // Enregistrer la réponse utilisateur
         let reponse_user = SessionRepository::create_user_answer(
-            pool,
-            session_id,
-            request.question_id,
-            request.reponse_id,
-            request.valeur_saisie.as_deref(),
-            validation.is_correct, // ✅ Utiliser validation du plugin
-            points_obtenus,
-            request.temps_reponse_sec,
+            &mut *tx,
+            &NewUserAnswer {
+                session_id,
+                question_id: request.question_id,
+                reponse_id: request.reponse_id,
+                reponse_ids: &reponse_ids,
+                valeur_saisie: request.valeur_saisie.as_deref(),
+                coordinates_cliquees: request.coordinates_cliquees.as_ref(),
+                is_correct: validation.is_correct, // ✅ Utiliser validation du plugin
+                points_obtenus,
+                temps_reponse_sec: request.temps_reponse_sec,
+                metadata: &metadata,
+            },
         )
         .await?;
 
         // Mettre à jour le score de la session
-        SessionRepository::update_score(pool, session_id, points_obtenus).await?;
+        SessionRepository::update_score(&mut *tx, session_id, points_obtenus).await?;
+
+        // 🆕 Succès de série et de rapidité (visibles dans le rapport de session)
+        if Self::unlocks_on_answer(&quiz.mode) {
+            let streak = if validation.is_correct {
+                streak_count + 1
+            } else {
+                0
+            };
+            AchievementService::on_answer(&mut tx, &session, &quiz.domain, streak).await?;
+        }
+
+        // Statistiques de la question et classements Elo (joueur / question)
+        let skill = SkillRepository::find(&mut *tx, session.user_id, &quiz.domain).await?;
+        let (rating, nb_answers) = skill
+            .map(|s| (s.rating, s.nb_answers))
+            .unwrap_or((elo::INITIAL_RATING, 0));
+        let outcome = validation
+            .partial_score
+            .map(f64::from)
+            .unwrap_or(if validation.is_correct { 1.0 } else { 0.0 });
+        let delta = elo::rating_delta(rating, nb_answers, question.difficulty_rating, outcome);
+
+        QuestionRepository::record_attempt(
+            &mut *tx,
+            question.id,
+            validation.is_correct,
+            delta.difficulty,
+        )
+        .await?;
+        SkillRepository::apply_delta(
+            &mut *tx,
+            session.user_id,
+            &quiz.domain,
+            elo::INITIAL_RATING,
+            delta.skill,
+        )
+        .await?;
+
+        // Entraînement (et révisions) : planifier la prochaine révision de la question
+        if quiz.mode == ADAPTIVE_MODE {
+            let quality = spaced_repetition::quality_from_answer(
+                validation.is_correct,
+                validation.partial_score,
+                request.temps_reponse_sec,
+                question.temps_limite_sec,
+            );
+            ReviewService::record_answer(
+                &mut tx,
+                session.user_id,
+                question.id,
+                quality,
+                answered_at,
+            )
+            .await?;
+        }
 
-        Ok(reponse_user)
+        tx.commit().await?;
+
+        // Examen : réponse enregistrée, correction révélée à la fin seulement
+        if quiz.mode == EXAM_MODE {
+            return Ok(SubmitAnswerResponse::Recorded(RecordedAnswer {
+                id: reponse_user.id,
+                session_id: reponse_user.session_id,
+                question_id: reponse_user.question_id,
+                temps_reponse_sec: reponse_user.temps_reponse_sec,
+                created_at: reponse_user.created_at,
+                recorded: true,
+            }));
+        }
+
+        Ok(SubmitAnswerResponse::Feedback(reponse_user))
     }
 
-    // ✅ INCHANGÉ
+    /// Abandonner une session en cours (score partiel conservé, événement émis)
+    pub async fn abandon_session(
+        pool: &PgPool,
+        events: &EventBus,
+        session_id: Uuid,
+        reason: AbandonReason,
+    ) -> Result<SessionQuiz, AppError> {
+        let session = SessionRepository::abandon(pool, session_id, reason.as_str())
+            .await?
+            .ok_or_else(|| {
+                AppError::BadRequest("Session not found or already completed".to_string())
+            })?;
+
+        Self::publish_abandoned(events, &session, reason);
+        Ok(session)
+    }
+
+    /// Abandonner les sessions inactives ou dont le temps de quiz est écoulé
+    pub async fn expire_sessions(
+        pool: &PgPool,
+        events: &EventBus,
+        inactivity_timeout: Duration,
+    ) -> Result<usize, AppError> {
+        let sessions = SessionRepository::abandon_expired(
+            pool,
+            inactivity_timeout.as_secs_f64(),
+            TIME_LIMIT_GRACE_SEC as f64,
+        )
+        .await?;
+
+        for session in &sessions {
+            let reason = session
+                .metadata
+                .get("abandon_reason")
+                .and_then(|r| r.as_str())
+                .and_then(AbandonReason::parse)
+                .unwrap_or(AbandonReason::Inactivity);
+            Self::publish_abandoned(events, session, reason);
+        }
+
+        Ok(sessions.len())
+    }
+
+    fn publish_abandoned(events: &EventBus, session: &SessionQuiz, reason: AbandonReason) {
+        events.publish(SessionEvent::SessionAbandoned {
+            session_id: session.id,
+            user_id: session.user_id,
+            quiz_id: session.quiz_id,
+            score: session.score,
+            score_max: session.score_max,
+            reason,
+        });
+    }
+
+    // ✅ MODIFIÉ : Verdict du plugin (ex: examen code de la route)
     pub async fn finalize_session(
         pool: &PgPool,
+        plugin_manager: &PluginManager,
+        certificates: &CertificateSigner,
         session_id: Uuid,
     ) -> Result<SessionQuiz, AppError> {
-        SessionRepository::finalize(pool, session_id)
+        // Fin de session, verdict, rapport, classements, succès et progression : une
+        // seule transaction (une session n'est jamais terminée sans ses effets)
+        let mut tx = pool.begin().await?;
+
+        let session = SessionRepository::finalize(&mut *tx, session_id)
             .await?
-            .ok_or_else(|| AppError::NotFound("Session not found or already finalized".to_string()))
+            .ok_or_else(|| {
+                AppError::NotFound("Session not found or already finalized".to_string())
+            })?;
+
+        let quiz = QuizRepository::find_by_id(pool, session.quiz_id)
+            .await?
+            .ok_or_else(|| AppError::NotFound("Quiz not found".to_string()))?;
+
+        let plugin_registry = plugin_manager.for_session(&session);
+        let plugin = plugin_registry.get(&quiz.domain).ok_or_else(|| {
+            AppError::NotFound(format!("No plugin found for domain: {}", quiz.domain))
+        })?;
+
+        // Moyenne des scores du quiz
+        QuizRepository::record_session_score(&mut *tx, quiz.id, session.pourcentage.unwrap_or(0.0))
+            .await?;
+
+        let answers = SessionRepository::find_reponses_by_session(&mut *tx, session_id).await?;
+
+        // Examen : verdict selon score_minimum_success (+ règles du plugin) et certificat signé
+        let nb_questions = Self::drawn_count(&session).unwrap_or(quiz.nb_questions.max(0) as usize);
+        let plugin_verdict = plugin.session_verdict(&quiz, nb_questions, &answers);
+        if quiz.mode == EXAM_MODE {
+            // Seuil comparé au taux de bonnes réponses : les bonus ne font pas réussir
+            let pourcentage = exam::correctness_percentage(&answers, nb_questions);
+            let verdict = exam::exam_verdict(&quiz, pourcentage, plugin_verdict);
+            let certificate = certificates.sign(CertificatePayload {
+                session_id,
+                user_id: session.user_id,
+                quiz_id: quiz.id,
+                quiz_titre: quiz.titre.clone(),
+                score: session.score,
+                score_max: session.score_max,
+                pourcentage,
+                score_minimum_success: quiz.score_minimum_success,
+                passed: verdict.passed,
+                issued_at: Utc::now(),
+            });
+            tracing::debug!(
+                session_id = %session_id,
+                passed = verdict.passed,
+                "Exam verdict computed"
+            );
+            SessionRepository::merge_metadata(
+                &mut *tx,
+                session_id,
+                &json!({ "verdict": verdict, "certificate": certificate }),
+            )
+            .await?;
+        } else if let Some(verdict) = plugin_verdict {
+            tracing::debug!(
+                session_id = %session_id,
+                passed = verdict.passed,
+                "Session verdict computed by plugin"
+            );
+            SessionRepository::merge_metadata(&mut *tx, session_id, &json!({ "verdict": verdict }))
+                .await?;
+        }
+
+        // Rapport détaillé, question par question
+        let report = Self::build_report(pool, plugin.as_ref(), &session, &answers).await?;
+        let session =
+            SessionRepository::set_reponses_detaillees(&mut *tx, session_id, &json!(report))
+                .await?;
+
+        // Classements du quiz et du domaine
+        LeaderboardService::record_session(&mut tx, &session, &quiz.domain).await?;
+
+        // Succès débloqués par la session (série et rapidité comprises pour un examen)
+        AchievementService::on_session_finished(&mut tx, &session, &quiz.domain, &answers).await?;
+
+        // 🆕 XP, maîtrise du domaine et série quotidienne
+        ProgressService::record_session(&mut tx, &session, &quiz, &answers).await?;
+
+        tx.commit().await?;
+        Ok(session)
+    }
+
+    /// Certificat de résultat d'une session d'examen terminée
+    pub async fn get_certificate(
+        pool: &PgPool,
+        session_id: Uuid,
+    ) -> Result<ResultCertificate, AppError> {
+        let session = Self::get_session(pool, session_id).await?;
+        session
+            .metadata
+            .get("certificate")
+            .and_then(|c| serde_json::from_value(c.clone()).ok())
+            .ok_or_else(|| {
+                AppError::NotFound(format!("Aucun certificat pour la session {}", session_id))
+            })
+    }
+
+    /// Vérifier un certificat présenté par un tiers (signature et session existante)
+    pub async fn verify_certificate(
+        pool: &PgPool,
+        certificates: &CertificateSigner,
+        certificate: ResultCertificate,
+    ) -> Result<CertificateVerification, AppError> {
+        let valid = certificates.verify(&certificate)
+            && SessionRepository::find_by_id(pool, certificate.payload.session_id)
+                .await?
+                .is_some_and(|s| s.status == "termine" && s.user_id == certificate.payload.user_id);
+
+        Ok(CertificateVerification {
+            valid,
+            payload: certificate.payload,
+        })
+    }
+
+    /// Rapport de session (construit à la volée si absent : session abandonnée...)
+    pub async fn get_report(
+        pool: &PgPool,
+        plugin_manager: &PluginManager,
+        session_id: Uuid,
+    ) -> Result<SessionReportResponse, AppError> {
+        let session = Self::get_session(pool, session_id).await?;
+        if session.status == "en_cours" {
+            return Err(AppError::BadRequest(
+                "Le rapport est disponible à la fin de la session".to_string(),
+            ));
+        }
+
+        let stored: Vec<QuestionReport> =
+            serde_json::from_value(session.reponses_detaillees.clone()).unwrap_or_default();
+        let questions = if stored.is_empty() {
+            let quiz = QuizRepository::find_by_id(pool, session.quiz_id)
+                .await?
+                .ok_or_else(|| AppError::NotFound("Quiz not found".to_string()))?;
+            let plugin_registry = plugin_manager.for_session(&session);
+            let plugin = plugin_registry.get(&quiz.domain).ok_or_else(|| {
+                AppError::NotFound(format!("No plugin found for domain: {}", quiz.domain))
+            })?;
+            let answers = SessionRepository::find_reponses_by_session(pool, session_id).await?;
+            Self::build_report(pool, plugin.as_ref(), &session, &answers).await?
+        } else {
+            stored
+        };
+
+        let achievements = AchievementService::session_unlocks(pool, session_id).await?;
+
+        Ok(SessionReportResponse {
+            session_id: session.id,
+            quiz_id: session.quiz_id,
+            categories: session_report::category_accuracy(&questions),
+            verdict: session.metadata.get("verdict").cloned(),
+            status: session.status,
+            score: session.score,
+            score_max: session.score_max,
+            pourcentage: session.pourcentage,
+            temps_total_sec: session.temps_total_sec,
+            questions,
+            achievements,
+        })
+    }
+
+    /// Questions du tirage (ou à défaut celles répondues) avec leurs réponses possibles
+    async fn build_report(
+        pool: &PgPool,
+        plugin: &dyn QuizPlugin,
+        session: &SessionQuiz,
+        answers: &[ReponseUtilisateur],
+    ) -> Result<Vec<QuestionReport>, AppError> {
+        let question_ids: Vec<Uuid> = match Self::question_set(session) {
+            Some(set) if !set.is_empty() => set.into_iter().map(|q| q.question_id).collect(),
+            _ => answers.iter().map(|a| a.question_id).collect(),
+        };
+
+        let mut questions = Vec::with_capacity(question_ids.len());
+        for question_id in question_ids {
+            if let Some(question) = QuestionRepository::find_by_id(pool, question_id).await? {
+                let reponses = ReponseRepository::find_by_question_id(pool, question_id).await?;
+                questions.push((question, reponses));
+            }
+        }
+
+        Ok(session_report::build_report(plugin, &questions, answers))
     }
 
     // ✅ NOUVEAU : Calculer le streak
@@ -156,3 +1072,46 @@ impl SessionService {
         Ok(streak)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(status: &str) -> SessionQuiz {
+        SessionQuiz {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            quiz_id: Uuid::new_v4(),
+            score: 120,
+            score_max: 200,
+            pourcentage: Some(60.0),
+            temps_total_sec: None,
+            date_debut: Utc::now(),
+            date_fin: None,
+            status: status.to_string(),
+            reponses_detaillees: json!([]),
+            metadata: json!({}),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_exam_progress_masked_while_in_progress() {
+        let masked = SessionService::mask_exam_progress(session("en_cours"), EXAM_MODE);
+        assert_eq!(masked.score, 0);
+        assert_eq!(masked.pourcentage, None);
+
+        // Examen terminé ou autre mode : score visible
+        let finished = SessionService::mask_exam_progress(session("termine"), EXAM_MODE);
+        assert_eq!(finished.score, 120);
+        let training = SessionService::mask_exam_progress(session("en_cours"), ADAPTIVE_MODE);
+        assert_eq!(training.pourcentage, Some(60.0));
+    }
+
+    #[test]
+    fn test_no_achievement_unlocked_while_answering_exam() {
+        assert!(!SessionService::unlocks_on_answer(EXAM_MODE));
+        assert!(SessionService::unlocks_on_answer(ADAPTIVE_MODE));
+        assert!(SessionService::unlocks_on_answer("decouverte"));
+    }
+}
@@ -0,0 +1,137 @@
+use chrono::{DateTime, Duration, Utc};
+
+/// Facteur de facilité initial et minimal (SM-2)
+pub const INITIAL_EASE_FACTOR: f64 = 2.5;
+const MIN_EASE_FACTOR: f64 = 1.3;
+
+/// Qualité minimale d'un rappel réussi (échelle SM-2 de 0 à 5)
+const PASSING_QUALITY: u8 = 3;
+
+/// Part du temps limite sous laquelle une bonne réponse est jugée "facile"
+const FAST_ANSWER_RATIO: f64 = 0.5;
+
+/// État de planification d'une carte (joueur × question)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CardSchedule {
+    pub ease_factor: f64,
+    pub interval_days: i32,
+    pub repetitions: i32,
+    pub lapses: i32,
+}
+
+impl Default for CardSchedule {
+    fn default() -> Self {
+        Self {
+            ease_factor: INITIAL_EASE_FACTOR,
+            interval_days: 0,
+            repetitions: 0,
+            lapses: 0,
+        }
+    }
+}
+
+impl CardSchedule {
+    /// Appliquer une révision de qualité `quality` (0 à 5)
+    /// Échec : la carte repart à 1 jour ; réussite : 1 jour, 6 jours, puis intervalle × facilité
+    pub fn review(self, quality: u8) -> Self {
+        let quality = quality.min(5);
+        let penalty = f64::from(5 - quality);
+        let ease_factor =
+            (self.ease_factor + 0.1 - penalty * (0.08 + penalty * 0.02)).max(MIN_EASE_FACTOR);
+
+        if quality < PASSING_QUALITY {
+            return Self {
+                ease_factor,
+                interval_days: 1,
+                repetitions: 0,
+                lapses: self.lapses + 1,
+            };
+        }
+
+        let interval_days = match self.repetitions {
+            0 => 1,
+            1 => 6,
+            _ => (f64::from(self.interval_days) * self.ease_factor).round() as i32,
+        };
+
+        Self {
+            ease_factor,
+            interval_days,
+            repetitions: self.repetitions + 1,
+            lapses: self.lapses,
+        }
+    }
+
+    /// Prochaine échéance après une révision faite à `reviewed_at`
+    pub fn due_at(&self, reviewed_at: DateTime<Utc>) -> DateTime<Utc> {
+        reviewed_at + Duration::days(i64::from(self.interval_days))
+    }
+}
+
+/// Qualité SM-2 déduite d'une réponse
+/// - 5 : correcte et rapide, 4 : correcte
+/// - 3 : partiellement correcte (score ≥ 0.5)
+/// - 2 : partiellement fausse, 1 : fausse
+pub fn quality_from_answer(
+    is_correct: bool,
+    partial_score: Option<f32>,
+    elapsed_sec: i32,
+    temps_limite_sec: Option<i32>,
+) -> u8 {
+    if is_correct {
+        let fast = temps_limite_sec
+            .is_some_and(|limit| f64::from(elapsed_sec) <= f64::from(limit) * FAST_ANSWER_RATIO);
+        return if fast { 5 } else { 4 };
+    }
+
+    match partial_score {
+        Some(score) if score >= 0.5 => 3,
+        Some(score) if score > 0.0 => 2,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_successful_reviews_grow_interval() {
+        let card = CardSchedule::default().review(4);
+        assert_eq!((card.interval_days, card.repetitions), (1, 1));
+        let card = card.review(4);
+        assert_eq!(card.interval_days, 6);
+        let card = card.review(5);
+        // 6 jours × 2.5
+        assert_eq!(card.interval_days, 15);
+        assert!(card.ease_factor > INITIAL_EASE_FACTOR);
+    }
+
+    #[test]
+    fn test_lapse_resets_card() {
+        let card = CardSchedule::default().review(5).review(5).review(1);
+        assert_eq!(card.interval_days, 1);
+        assert_eq!(card.repetitions, 0);
+        assert_eq!(card.lapses, 1);
+        assert!(card.ease_factor < INITIAL_EASE_FACTOR + 0.2);
+    }
+
+    #[test]
+    fn test_ease_factor_floor() {
+        let mut card = CardSchedule::default();
+        for _ in 0..20 {
+            card = card.review(0);
+        }
+        assert_eq!(card.ease_factor, MIN_EASE_FACTOR);
+    }
+
+    #[test]
+    fn test_quality_from_answer() {
+        assert_eq!(quality_from_answer(true, None, 3, Some(15)), 5);
+        assert_eq!(quality_from_answer(true, None, 12, Some(15)), 4);
+        assert_eq!(quality_from_answer(true, None, 3, None), 4);
+        assert_eq!(quality_from_answer(false, Some(0.6), 5, Some(15)), 3);
+        assert_eq!(quality_from_answer(false, Some(0.2), 5, Some(15)), 2);
+        assert_eq!(quality_from_answer(false, None, 5, Some(15)), 1);
+    }
+}
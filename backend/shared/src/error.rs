@@ -3,10 +3,18 @@ use axum::{
     http::StatusCode,
     response::{IntoResponse, Response},
 };
+use serde::Serialize;
 use serde_json::json;
 
 pub type AppResult<T> = Result<T, AppError>;
 
+/// Erreur de validation sur un champ précis (ex: "question_data/text")
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum AppError {
     #[error("Database error: {0}")]
@@ -18,11 +26,17 @@ pub enum AppError {
     #[error("Bad request: {0}")]
     BadRequest(String),
 
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+
     #[error("Internal server error: {0}")]
     InternalServerError(String),
 
     #[error("Plugin not found for domain")]
     PluginNotFound,
+
+    #[error("Validation failed: {} invalid field(s)", .0.len())]
+    Validation(Vec<FieldError>),
 }
 
 impl IntoResponse for AppError {
@@ -34,17 +48,24 @@ impl IntoResponse for AppError {
             }
             AppError::NotFound(_) => (StatusCode::NOT_FOUND, "Resource not found"),
             AppError::BadRequest(_) => (StatusCode::BAD_REQUEST, "Bad request"),
+            AppError::Unauthorized(_) => (StatusCode::UNAUTHORIZED, "Unauthorized"),
             AppError::InternalServerError(_) => {
                 (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error")
             }
             AppError::PluginNotFound => (StatusCode::INTERNAL_SERVER_ERROR, "Plugin not found"),
+            AppError::Validation(_) => (StatusCode::UNPROCESSABLE_ENTITY, "Validation failed"),
         };
 
-        let body = Json(json!({
+        let mut body = json!({
             "error": error_message,
             "details": self.to_string(),
-        }));
+        });
+
+        // Erreurs par champ (formulaires)
+        if let AppError::Validation(fields) = &self {
+            body["fields"] = json!(fields);
+        }
 
-        (status, body).into_response()
+        (status, Json(body)).into_response()
     }
 }
@@ -46,6 +46,8 @@ async fn test_submit_correct_answer() {
         "user_answer": "Paris"
     });
 
+    present_question(app.clone(), session_id, question_id).await;
+
     let uri = format!("/api/v1/sessions/{}/answers", session_id);
     let (status, body) = post(app, &uri, payload.to_string()).await;
 
@@ -88,6 +90,8 @@ async fn test_submit_incorrect_answer() {
         "user_answer": "Lyon"  // Mauvaise réponse
     });
 
+    present_question(app.clone(), session_id, question_id).await;
+
     let uri = format!("/api/v1/sessions/{}/answers", session_id);
     let (status, body) = post(app, &uri, payload.to_string()).await;
 
@@ -113,17 +117,29 @@ async fn test_submit_answer_timeout() {
     let question_id = create_test_question(&pool, quiz_id).await;
     let session_id = create_test_session(&pool, quiz_id).await;
 
-    // Simuler un temps écoulé (modifier started_at dans le passé)
+    let app = create_test_app(pool.clone()).await;
+
+    present_question(app.clone(), session_id, question_id).await;
+
+    // Simuler un temps écoulé (présentation de la question 1h dans le passé)
+    // Le temps de réponse est mesuré côté serveur, `time_taken` est ignoré
     sqlx::query(
-        "UPDATE sessions SET started_at = NOW() - INTERVAL '1 hour' WHERE id = $1"
+        r#"
+        UPDATE sessions
+        SET metadata = jsonb_set(
+            metadata,
+            ARRAY['presented_at', $2::text],
+            to_jsonb(NOW() - INTERVAL '1 hour')
+        )
+        WHERE id = $1
+        "#
     )
         .bind(session_id)
+        .bind(question_id)
         .execute(&pool)
         .await
         .unwrap();
 
-    let app = create_test_app(pool.clone()).await;
-
     let payload = json!({
         "question_id": question_id.to_string(),
         "user_answer": "Paris",
@@ -166,6 +182,8 @@ async fn test_submit_answer_fast_bonus() {
         "time_taken": 3  // Très rapide (3 secondes)
     });
 
+    present_question(app.clone(), session_id, question_id).await;
+
     let uri = format!("/api/v1/sessions/{}/answers", session_id);
     let (status, body) = post(app, &uri, payload.to_string()).await;
 
@@ -233,6 +251,8 @@ async fn test_submit_answer_duplicate() {
         "user_answer": "Paris"
     });
 
+    present_question(app.clone(), session_id, question_id).await;
+
     let uri = format!("/api/v1/sessions/{}/answers", session_id);
 
     // Première réponse : OK
@@ -261,6 +281,8 @@ async fn test_submit_empty_answer() {
         "user_answer": ""  // Vide
     });
 
+    present_question(app.clone(), session_id, question_id).await;
+
     let uri = format!("/api/v1/sessions/{}/answers", session_id);
     let (status, _body) = post(app, &uri, payload.to_string()).await;
 
@@ -310,6 +332,8 @@ async fn test_score_calculation() {
             "user_answer": answer
         });
 
+        present_question(app.clone(), session_id, qid).await;
+
         let uri = format!("/api/v1/sessions/{}/answers", session_id);
         post(app.clone(), &uri, payload.to_string()).await;
     }
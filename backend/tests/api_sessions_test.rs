@@ -154,6 +154,8 @@ async fn test_complete_session_workflow() {
         "user_answer": "Paris"
     });
 
+    present_question(app.clone(), session_id, question1_id).await;
+
     let answer_uri = format!("/api/v1/sessions/{}/answers", session_id);
     let (status, answer_body) = post(app.clone(), &answer_uri, answer_payload.to_string()).await;
 
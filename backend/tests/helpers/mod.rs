@@ -208,4 +208,19 @@ pub async fn create_test_session(pool: &PgPool, quiz_id: uuid::Uuid) -> uuid::Uu
         .expect("Failed to create test session");
 
     session_id
-}
\ No newline at end of file
+}
+/// Présenter une question avant d'y répondre
+/// `POST /answers` refuse (400) une question jamais servie par `/next` ou `/present` :
+/// le temps de réponse est mesuré côté serveur depuis cette présentation
+pub async fn present_question(
+    app: Router,
+    session_id: impl std::fmt::Display,
+    question_id: impl std::fmt::Display,
+) {
+    let uri = format!(
+        "/api/v1/sessions/{}/questions/{}/present",
+        session_id, question_id
+    );
+    let (status, body) = post(app, &uri, "{}".to_string()).await;
+    assert_eq!(status, StatusCode::OK, "present failed: {}", body);
+}
@@ -8,6 +8,7 @@ import '../../features/quiz/domain/usecases/get_quiz_by_id.dart';
 import '../../features/quiz/domain/usecases/get_quiz_list.dart';
 import '../../features/quiz/domain/usecases/get_quiz_questions.dart';
 import '../../features/quiz/domain/usecases/get_session.dart';
+import '../../features/quiz/domain/usecases/present_question.dart';
 import '../../features/quiz/domain/usecases/start_quiz_session.dart';
 import '../../features/quiz/domain/usecases/submit_answer.dart';
 import '../../features/quiz/presentation/bloc/quiz_list/quiz_list_bloc.dart';
@@ -66,6 +67,7 @@ Future<void> initializeDependencies() async {
   sl.registerLazySingleton(() => GetQuizById(sl()));
   sl.registerLazySingleton(() => GetQuizQuestions(sl()));
   sl.registerLazySingleton(() => StartQuizSession(sl()));
+  sl.registerLazySingleton(() => PresentQuestion(sl()));
   sl.registerLazySingleton(() => SubmitAnswer(sl()));
   sl.registerLazySingleton(() => FinalizeSession(sl()));
   sl.registerLazySingleton(() => GetSession(sl()));
@@ -82,6 +84,7 @@ Future<void> initializeDependencies() async {
     () => QuizSessionBloc(
       getQuizQuestions: sl(),
       startQuizSession: sl(),
+      presentQuestion: sl(),
       submitAnswer: sl(),
       finalizeSession: sl(),
       getSession: sl(),
@@ -24,6 +24,14 @@ abstract class QuizRemoteDataSource {
     required String userId,
   });
 
+  /// POST /api/v1/sessions/:sessionId/questions/:questionId/present
+  /// À appeler à l'affichage de chaque question : le serveur mesure
+  /// le temps de réponse depuis ce moment et refuse une réponse sans présentation
+  Future<void> presentQuestion({
+    required String sessionId,
+    required String questionId,
+  });
+
   /// POST /api/v1/sessions/:sessionId/answers
   Future<UserAnswerModel> submitAnswer({
     required String sessionId,
@@ -147,6 +155,34 @@ class QuizRemoteDataSourceImpl implements QuizRemoteDataSource {
     }
   }
 
+  @override
+  Future<void> presentQuestion({
+    required String sessionId,
+    required String questionId,
+  }) async {
+    try {
+      final response = await dio.post(
+        '/sessions/$sessionId/questions/$questionId/present',
+      );
+
+      if (response.statusCode == 200) {
+        return;
+      } else if (response.statusCode == 404) {
+        throw NotFoundException(message: 'Session ou question non trouvée');
+      } else {
+        throw ServerException(
+          message: 'Erreur lors de la présentation de la question',
+          statusCode: response.statusCode,
+        );
+      }
+    } on DioException catch (e) {
+      throw _handleDioError(e);
+    } catch (e) {
+      if (e is AppException) rethrow;
+      throw ServerException(message: 'Erreur inattendue: $e');
+    }
+  }
+
   @override
   Future<UserAnswerModel> submitAnswer({
     required String sessionId,
@@ -96,6 +96,30 @@ class QuizRepositoryImpl implements QuizRepository {
     }
   }
 
+  @override
+  Future<Either<Failure, Unit>> presentQuestion({
+    required String sessionId,
+    required String questionId,
+  }) async {
+    try {
+      await remoteDataSource.presentQuestion(
+        sessionId: sessionId,
+        questionId: questionId,
+      );
+      return const Right(unit);
+    } on NotFoundException catch (e) {
+      return Left(NotFoundFailure(e.message));
+    } on ValidationException catch (e) {
+      return Left(ValidationFailure(e.message));
+    } on ServerException catch (e) {
+      return Left(ServerFailure(e.message));
+    } on ConnectionException catch (e) {
+      return Left(ConnectionFailure(e.message));
+    } catch (e) {
+      return Left(ServerFailure('Erreur inattendue: $e'));
+    }
+  }
+
   @override
   Future<Either<Failure, AnswerEntity>> submitAnswer({
     required String sessionId,
@@ -49,6 +49,21 @@ abstract class QuizRepository {
     required String userId,
   });
 
+  /// Signale au serveur l'affichage d'une question
+  /// Obligatoire avant [submitAnswer] : le temps de réponse est mesuré côté serveur
+  ///
+  /// Parameters:
+  /// - [sessionId] : ID de la session en cours
+  /// - [questionId] : ID de la question affichée
+  ///
+  /// Returns:
+  /// - Right(unit) en cas de succès
+  /// - Left(Failure) en cas d'erreur
+  Future<Either<Failure, Unit>> presentQuestion({
+    required String sessionId,
+    required String questionId,
+  });
+
   /// Soumet une réponse à une question
   ///
   /// Parameters:
@@ -0,0 +1,35 @@
+import 'package:dartz/dartz.dart';
+import 'package:equatable/equatable.dart';
+import '../../../../core/error/failures.dart';
+import '../repositories/quiz_repository.dart';
+import 'usecase.dart';
+
+/// Use Case : Signaler l'affichage d'une question
+/// Le serveur refuse une réponse à une question qui n'a pas été présentée
+class PresentQuestion implements UseCase<Unit, PresentQuestionParams> {
+  final QuizRepository repository;
+
+  PresentQuestion(this.repository);
+
+  @override
+  Future<Either<Failure, Unit>> call(PresentQuestionParams params) async {
+    return await repository.presentQuestion(
+      sessionId: params.sessionId,
+      questionId: params.questionId,
+    );
+  }
+}
+
+/// Paramètres pour PresentQuestion
+class PresentQuestionParams extends Equatable {
+  final String sessionId;
+  final String questionId;
+
+  const PresentQuestionParams({
+    required this.sessionId,
+    required this.questionId,
+  });
+
+  @override
+  List<Object> get props => [sessionId, questionId];
+}
@@ -4,6 +4,7 @@ import '../../../domain/entities/question_entity.dart';
 import '../../../domain/usecases/finalize_session.dart';
 import '../../../domain/usecases/get_quiz_questions.dart';
 import '../../../domain/usecases/get_session.dart';
+import '../../../domain/usecases/present_question.dart';
 import '../../../domain/usecases/start_quiz_session.dart';
 import '../../../domain/usecases/submit_answer.dart';
 import 'quiz_session_event.dart';
@@ -12,6 +13,7 @@ import 'quiz_session_state.dart';
 class QuizSessionBloc extends Bloc<QuizSessionEvent, QuizSessionState> {
   final GetQuizQuestions getQuizQuestions;
   final StartQuizSession startQuizSession;
+  final PresentQuestion presentQuestion;
   final SubmitAnswer submitAnswer;
   final FinalizeSession finalizeSession;
   final GetSession getSession;
@@ -19,6 +21,7 @@ class QuizSessionBloc extends Bloc<QuizSessionEvent, QuizSessionState> {
   QuizSessionBloc({
     required this.getQuizQuestions,
     required this.startQuizSession,
+    required this.presentQuestion,
     required this.submitAnswer,
     required this.finalizeSession,
     required this.getSession,
@@ -58,16 +61,29 @@ class QuizSessionBloc extends Bloc<QuizSessionEvent, QuizSessionState> {
           ),
         );
 
-        sessionResult.fold(
-          (failure) => emit(QuizSessionError(failure.message)),
-          (session) => emit(
-            QuizSessionInProgress(
-              session: session,
-              questions: questions,
-              currentQuestionIndex: 0,
-              submittedAnswers: const [],
-            ),
-          ),
+        await sessionResult.fold(
+          (failure) async => emit(QuizSessionError(failure.message)),
+          (session) async {
+            // 3. Présenter la première question (départ du chrono serveur)
+            final presented = await presentQuestion(
+              PresentQuestionParams(
+                sessionId: session.id,
+                questionId: questions.first.id,
+              ),
+            );
+
+            presented.fold(
+              (failure) => emit(QuizSessionError(failure.message)),
+              (_) => emit(
+                QuizSessionInProgress(
+                  session: session,
+                  questions: questions,
+                  currentQuestionIndex: 0,
+                  submittedAnswers: const [],
+                ),
+              ),
+            );
+          },
         );
       },
     );
@@ -126,10 +142,10 @@ class QuizSessionBloc extends Bloc<QuizSessionEvent, QuizSessionState> {
   }
 
   /// Passe à la question suivante
-  void _onNextQuestion(
+  Future<void> _onNextQuestion(
     NextQuestionEvent event,
     Emitter<QuizSessionState> emit,
-  ) {
+  ) async {
     if (state is! QuizAnswerSubmitted) return;
 
     final currentState = state as QuizAnswerSubmitted;
@@ -139,13 +155,24 @@ class QuizSessionBloc extends Bloc<QuizSessionEvent, QuizSessionState> {
         currentState.questions.length - 1) {
       add(const FinalizeQuizSessionEvent());
     } else {
-      // Sinon, passer à la question suivante
-      emit(QuizSessionInProgress(
-        session: currentState.session,
-        questions: currentState.questions,
-        currentQuestionIndex: currentState.currentQuestionIndex + 1,
-        submittedAnswers: currentState.submittedAnswers,
-      ));
+      // Sinon, présenter puis afficher la question suivante
+      final nextIndex = currentState.currentQuestionIndex + 1;
+      final presented = await presentQuestion(
+        PresentQuestionParams(
+          sessionId: currentState.session.id,
+          questionId: currentState.questions[nextIndex].id,
+        ),
+      );
+
+      presented.fold(
+        (failure) => emit(QuizSessionError(failure.message)),
+        (_) => emit(QuizSessionInProgress(
+          session: currentState.session,
+          questions: currentState.questions,
+          currentQuestionIndex: nextIndex,
+          submittedAnswers: currentState.submittedAnswers,
+        )),
+      );
     }
   }
 